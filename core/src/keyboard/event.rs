@@ -6,7 +6,8 @@ use super::{KeyCode, ModifiersState};
 /// additional events, feel free to [open an issue] and share your use case!_
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard key was pressed.
     KeyPressed {
@@ -31,4 +32,21 @@ pub enum Event {
 
     /// The keyboard modifiers have changed.
     ModifiersChanged(ModifiersState),
+
+    /// An input method editor (IME) is composing text.
+    ///
+    /// The preedit text is not committed yet and should be displayed in place
+    /// of the cursor. An empty preedit ends the composition.
+    ///
+    /// _**Note:** `iced_winit` cannot produce this event until it upgrades to
+    /// a `winit` release that reports compositions._
+    ImePreedit(String),
+
+    /// An input method editor (IME) has committed its composed text.
+    ///
+    /// _**Note:** `iced_winit` cannot produce this event yet either. Composed
+    /// text is received as [`CharacterReceived`] events instead._
+    ///
+    /// [`CharacterReceived`]: #variant.CharacterReceived
+    ImeCommit(String),
 }
@@ -1,5 +1,6 @@
 /// The current state of the keyboard modifiers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModifiersState {
     /// Whether a shift key is pressed
     pub shift: bool,
@@ -13,7 +13,10 @@
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
-#![forbid(rust_2018_idioms)]
+// Derives of `serde` allow `unused_extern_crates` on the code they generate,
+// so the lints of `rust_2018_idioms` can only be denied when it is enabled.
+#![cfg_attr(not(feature = "serde"), forbid(rust_2018_idioms))]
+#![cfg_attr(feature = "serde", deny(rust_2018_idioms))]
 pub mod keyboard;
 pub mod mouse;
 
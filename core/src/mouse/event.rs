@@ -7,6 +7,7 @@ use super::Button;
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// The mouse cursor entered the window.
     CursorEntered,
@@ -38,6 +39,7 @@ pub enum Event {
 
 /// A scroll movement.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollDelta {
     /// A line-based scroll movement
     Lines {
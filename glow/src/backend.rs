@@ -7,7 +7,9 @@ use iced_graphics::font;
 use iced_graphics::Layer;
 use iced_graphics::Primitive;
 use iced_native::mouse;
-use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
+use iced_native::{
+    Font, HorizontalAlignment, Rectangle, Size, VerticalAlignment,
+};
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -208,6 +210,17 @@ impl backend::Text for Backend {
     ) -> (f32, f32) {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
+
+    fn character_bounds(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Vec<(usize, Rectangle)> {
+        self.text_pipeline
+            .character_bounds(contents, size, font, bounds)
+    }
 }
 
 #[cfg(feature = "image")]
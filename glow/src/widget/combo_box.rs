@@ -0,0 +1,16 @@
+//! Let your users search and pick a value from a list of options.
+//!
+//! A [`ComboBox`] has some local [`State`].
+//!
+//! [`ComboBox`]: struct.ComboBox.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::combo_box::{Filter, State};
+
+/// A field that filters a list of options as the user types in it.
+///
+/// This is an alias of an `iced_native` combo box with an
+/// `iced_glow::Renderer`.
+pub type ComboBox<'a, T, Message> =
+    iced_native::ComboBox<'a, T, Message, Renderer>;
@@ -0,0 +1,6 @@
+//! Pick a date from a calendar.
+pub use iced_graphics::date_picker::{Date, State, Style, StyleSheet};
+
+/// A field that opens a calendar to pick a date.
+pub type DatePicker<'a, Message> =
+    iced_native::DatePicker<'a, Message, crate::Renderer>;
@@ -0,0 +1,9 @@
+//! Display a dropdown list of options to pick a set of values from.
+pub use iced_native::multi_pick_list::{Chip, State};
+
+pub use iced_graphics::multi_pick_list::{Style, StyleSheet};
+pub use iced_graphics::overlay::menu::Style as Menu;
+
+/// A widget allowing the selection of a set of values from a list of options.
+pub type MultiPickList<'a, T, Message> =
+    iced_native::MultiPickList<'a, T, Message, crate::Renderer>;
@@ -0,0 +1,16 @@
+//! Let your users type or step through numeric values.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_native::number_input::State;
+
+/// A field that holds a number, with buttons to increment and decrement it.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_glow::Renderer`.
+pub type NumberInput<'a, T, Message> =
+    iced_native::NumberInput<'a, T, Message, Renderer>;
@@ -0,0 +1,20 @@
+//! Display an interactive selector of a range of values, with two handles.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+//!
+//! [`RangeSlider`]: struct.RangeSlider.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::range_slider::{
+    Bound, Handle, HandleShape, Orientation, Style, StyleSheet,
+};
+pub use iced_native::range_slider::State;
+
+/// A bar with two handles that select a minimum and a maximum value from a
+/// range of values.
+///
+/// This is an alias of an `iced_native` range slider with an
+/// `iced_glow::Renderer`.
+pub type RangeSlider<'a, T, Message> =
+    iced_native::RangeSlider<'a, T, Message, Renderer>;
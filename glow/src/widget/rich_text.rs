@@ -0,0 +1,10 @@
+//! Write a paragraph of text made of differently styled spans.
+use crate::Renderer;
+
+pub use iced_native::rich_text::Span;
+
+/// A paragraph of text made of spans with their own style.
+///
+/// This is an alias of an `iced_native` rich text with an
+/// `iced_glow::Renderer`.
+pub type RichText = iced_native::RichText<Renderer>;
@@ -0,0 +1,16 @@
+//! Display a multi-line area that can be filled with text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::text_editor::{Content, Position, Style, StyleSheet};
+pub use iced_native::text_editor::State;
+
+/// A multi-line area that can be filled with text.
+///
+/// This is an alias of an `iced_native` text editor with an `iced_glow::Renderer`.
+pub type TextEditor<'a, Message> =
+    iced_native::TextEditor<'a, Message, Renderer>;
@@ -0,0 +1,6 @@
+//! Pick a time of the day.
+pub use iced_graphics::time_picker::{State, Style, StyleSheet, Time};
+
+/// A field that opens a grid of hours and minutes to pick a time.
+pub type TimePicker<'a, Message> =
+    iced_native::TimePicker<'a, Message, crate::Renderer>;
@@ -0,0 +1,18 @@
+//! Display hierarchical data with expandable nodes.
+use crate::Renderer;
+
+pub use iced_graphics::tree_view::{State, Style, StyleSheet};
+
+/// A widget displaying a hierarchy of nodes that can be expanded and
+/// collapsed.
+///
+/// This is an alias of an `iced_native` tree view with a default
+/// `Renderer`.
+pub type TreeView<'a, Id, Message> =
+    iced_native::TreeView<'a, Id, Message, Renderer>;
+
+/// A node of a [`TreeView`].
+///
+/// [`TreeView`]: type.TreeView.html
+pub type Node<'a, Id, Message> =
+    iced_native::tree_view::Node<'a, Id, Message, Renderer>;
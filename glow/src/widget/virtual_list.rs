@@ -0,0 +1,12 @@
+//! Display a very large list of rows, building only the visible ones.
+use crate::Renderer;
+
+pub use iced_graphics::scrollable::{Scrollbar, Scroller, StyleSheet};
+pub use iced_native::virtual_list::{RowHeight, State};
+
+/// A vertical list that only builds the rows that intersect its viewport.
+///
+/// This is an alias of an `iced_native` virtual list with a default
+/// `Renderer`.
+pub type VirtualList<'a, Message> =
+    iced_native::VirtualList<'a, Message, Renderer>;
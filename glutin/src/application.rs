@@ -41,6 +41,7 @@ where
 
     let flags = settings.flags;
     let (application, init_command) = runtime.enter(|| A::new(flags));
+    let (init_command, init_actions) = init_command.split();
     runtime.spawn(init_command);
 
     let subscription = application.subscription();
@@ -80,8 +81,12 @@ where
     };
 
     let clipboard = Clipboard::new(&context.window());
+
+    application::perform_actions(init_actions, clipboard.as_ref());
+
     let mut cursor_position = glutin::dpi::PhysicalPosition::new(-1.0, -1.0);
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut ime_caret = None;
     let mut modifiers = glutin::event::ModifiersState::default();
 
     let physical_size = context.window().inner_size();
@@ -98,6 +103,11 @@ where
         })?
     };
 
+    iced_native::Renderer::set_scale_factor(
+        &mut renderer,
+        viewport.scale_factor(),
+    );
+
     let mut state = program::State::new(
         application,
         viewport.logical_size(),
@@ -105,6 +115,10 @@ where
         &mut renderer,
         &mut debug,
     );
+    if let Some((position, total)) = state.program().replay_position() {
+        debug.replay_stepped(position, total);
+    }
+
     debug.startup_finished();
 
     event_loop.run(move |event, _, control_flow| match event {
@@ -113,6 +127,11 @@ where
                 return;
             }
 
+            iced_native::Renderer::set_scale_factor(
+                &mut renderer,
+                viewport.scale_factor(),
+            );
+
             let command = runtime.enter(|| {
                 state.update(
                     viewport.logical_size(),
@@ -128,6 +147,9 @@ where
 
             // If the application was updated
             if let Some(command) = command {
+                let (command, actions) = command.split();
+
+                application::perform_actions(actions, clipboard.as_ref());
                 runtime.spawn(command);
 
                 let program = state.program();
@@ -136,6 +158,11 @@ where
                 let subscription = program.subscription();
                 runtime.track(subscription);
 
+                // Update replay position
+                if let Some((position, total)) = program.replay_position() {
+                    debug.replay_stepped(position, total);
+                }
+
                 // Update window title
                 let new_title = program.title();
 
@@ -171,6 +198,11 @@ where
                         context.window().scale_factor() * new_scale_factor,
                     );
 
+                    iced_native::Renderer::set_scale_factor(
+                        &mut renderer,
+                        viewport.scale_factor(),
+                    );
+
                     // We relayout the UI with the new logical size.
                     // The queue is empty, therefore this will never produce
                     // a `Command`.
@@ -232,8 +264,27 @@ where
                 mouse_interaction = new_mouse_interaction;
             }
 
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
+            let new_ime_caret = iced_native::Renderer::ime_caret(&renderer);
+
+            if new_ime_caret != ime_caret {
+                if let Some(caret) = new_ime_caret {
+                    context.window().set_ime_position(
+                        conversion::ime_position(
+                            caret,
+                            viewport.scale_factor(),
+                        ),
+                    );
+                }
+
+                ime_caret = new_ime_caret;
+            }
+
+            // Animated widgets keep requesting redraws until they settle
+            if iced_native::Renderer::is_redraw_requested(&renderer) {
+                state.queue_event(iced_native::Event::Window(
+                    iced_native::window::Event::RedrawRequested,
+                ));
+            }
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -261,7 +312,11 @@ where
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = if state.is_queue_empty() {
+                ControlFlow::Wait
+            } else {
+                ControlFlow::Poll
+            };
         }
     })
 }
@@ -1,7 +1,7 @@
 //! Write a graphics backend.
 use iced_native::image;
 use iced_native::svg;
-use iced_native::{Font, Size};
+use iced_native::{Font, Rectangle, Size};
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -43,6 +43,17 @@ pub trait Text {
         font: Font,
         bounds: Size,
     ) -> (f32, f32);
+
+    /// Lays out the text contents with the given size and font in the
+    /// provided bounds, returning the bounds of every laid out character
+    /// along with its index in the contents.
+    fn character_bounds(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Vec<(usize, Rectangle)>;
 }
 
 /// A graphics backend that supports image rendering.
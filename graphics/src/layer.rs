@@ -216,12 +216,17 @@ impl<'a> Layer<'a> {
             Primitive::Cached { cache } => {
                 Self::process_primitive(layers, translation, &cache);
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                bounds,
+                filter_method,
+            } => {
                 let layer = layers.last_mut().unwrap();
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
                     bounds: *bounds + translation,
+                    filter_method: *filter_method,
                 });
             }
             Primitive::Svg { handle, bounds } => {
@@ -341,6 +346,9 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The filtering method used to sample the image.
+        filter_method: image::FilterMethod,
     },
     /// A vector image.
     Vector {
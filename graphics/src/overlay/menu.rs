@@ -44,6 +44,7 @@ where
         cursor_position: Point,
         options: &[T],
         hovered_option: Option<usize>,
+        checked: Option<&[bool]>,
         padding: u16,
         text_size: u16,
         font: Font,
@@ -55,6 +56,13 @@ where
 
         let mut primitives = Vec::new();
 
+        // Checkmarks take a column of their own, so that labels stay aligned
+        let indent = if checked.is_some() {
+            f32::from(text_size)
+        } else {
+            0.0
+        };
+
         for (i, option) in options.iter().enumerate() {
             let is_selected = hovered_option == Some(i);
 
@@ -76,21 +84,41 @@ where
                 });
             }
 
+            let color = if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            if matches!(checked, Some(checked) if checked.get(i) == Some(&true))
+            {
+                primitives.push(Primitive::Text {
+                    content: B::CHECKMARK_ICON.to_string(),
+                    font: B::ICON_FONT,
+                    size: f32::from(text_size),
+                    bounds: Rectangle {
+                        x: bounds.x + f32::from(padding),
+                        y: bounds.center_y(),
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    color,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
             primitives.push(Primitive::Text {
                 content: option.to_string(),
                 bounds: Rectangle {
-                    x: bounds.x + f32::from(padding),
+                    x: bounds.x + f32::from(padding) + indent,
                     y: bounds.center_y(),
                     width: f32::INFINITY,
                     ..bounds
                 },
                 size: f32::from(text_size),
                 font,
-                color: if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
-                },
+                color,
                 horizontal_alignment: HorizontalAlignment::Left,
                 vertical_alignment: VerticalAlignment::Center,
             });
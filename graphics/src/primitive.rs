@@ -52,6 +52,8 @@ pub enum Primitive {
         handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The filtering method used to sample the image
+        filter_method: image::FilterMethod,
     },
     /// An SVG primitive
     Svg {
@@ -9,6 +9,9 @@ use iced_native::{
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    ime_caret: Option<Rectangle>,
+    is_redraw_requested: bool,
+    scale_factor: f64,
 }
 
 impl<B: Backend> Renderer<B> {
@@ -17,7 +20,12 @@ impl<B: Backend> Renderer<B> {
     /// [`Renderer`]: struct.Renderer.html
     /// [`Backend`]: backend/trait.Backend.html
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            ime_caret: None,
+            is_redraw_requested: false,
+            scale_factor: 1.0,
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -84,6 +92,30 @@ where
             },
         )
     }
+
+    fn set_ime_caret(&mut self, caret: Option<Rectangle>) {
+        self.ime_caret = caret;
+    }
+
+    fn ime_caret(&self) -> Option<Rectangle> {
+        self.ime_caret
+    }
+
+    fn set_redraw_requested(&mut self, is_requested: bool) {
+        self.is_redraw_requested = is_requested;
+    }
+
+    fn is_redraw_requested(&self) -> bool {
+        self.is_redraw_requested
+    }
+
+    fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
 }
 
 impl<B> layout::Debugger for Renderer<B>
@@ -4,7 +4,7 @@ use iced_native::mouse;
 /// A [`Canvas`] event.
 ///
 /// [`Canvas`]: struct.Event.html
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     /// A mouse event.
     Mouse(mouse::Event),
@@ -0,0 +1,279 @@
+//! Pick a color with a saturation and value area, sliders and text inputs.
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Backend, Primitive, Renderer};
+use iced_native::color_picker::{Controls, Hsva};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Vector};
+
+pub use iced_native::color_picker::State;
+pub use iced_style::color_picker::{Style, StyleSheet};
+
+/// A swatch that opens an overlay to pick a color.
+pub type ColorPicker<'a, Message, Backend> =
+    iced_native::ColorPicker<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::color_picker::Renderer for Renderer<B>
+where
+    B: Backend + crate::backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        color: Color,
+        padding: u16,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style.hovered()
+        } else {
+            style.active()
+        };
+
+        let swatch = Rectangle {
+            x: bounds.x + f32::from(padding),
+            y: bounds.y + f32::from(padding),
+            width: bounds.width - f32::from(padding) * 2.0,
+            height: bounds.height - f32::from(padding) * 2.0,
+        };
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.popup_background,
+            border_color: style.border_color,
+            border_width: style.border_width,
+            border_radius: style.border_radius,
+        }];
+
+        primitives.extend(checkerboard(swatch, style.checkerboard_color));
+        primitives.push(Primitive::Quad {
+            bounds: swatch,
+            background: Background::Color(color),
+            border_color: Color::TRANSPARENT,
+            border_width: 0,
+            border_radius: 0,
+        });
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+
+    fn draw_picker(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        controls: &Controls,
+        fields: Vec<Self::Output>,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.active();
+        let hsva = controls.hsva;
+        let opaque = Hsva { alpha: 1.0, ..hsva };
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.popup_background,
+            border_color: style.popup_border_color,
+            border_width: style.popup_border_width,
+            border_radius: style.border_radius,
+        }];
+
+        // Colors are interpolated linearly between the vertices of a mesh, so
+        // the saturation and value area needs a few of them to look right
+        primitives.push(gradient(controls.saturation_value, 16, 16, |x, y| {
+            Hsva {
+                saturation: x,
+                value: 1.0 - y,
+                ..opaque
+            }
+            .into_color()
+        }));
+
+        primitives.push(gradient(controls.hue, 12, 1, |x, _| {
+            Hsva {
+                hue: x * 360.0,
+                saturation: 1.0,
+                value: 1.0,
+                alpha: 1.0,
+            }
+            .into_color()
+        }));
+
+        primitives
+            .extend(checkerboard(controls.alpha, style.checkerboard_color));
+        primitives.push(gradient(controls.alpha, 8, 1, |x, _| {
+            Hsva { alpha: x, ..opaque }.into_color()
+        }));
+
+        let sv = controls.saturation_value;
+
+        primitives.push(marker(
+            Point::new(
+                sv.x + hsva.saturation * sv.width,
+                sv.y + (1.0 - hsva.value) * sv.height,
+            ),
+            &style,
+        ));
+        primitives.push(handle(controls.hue, hsva.hue / 360.0, &style));
+        primitives.push(handle(controls.alpha, hsva.alpha, &style));
+
+        for (swatch, color) in &controls.swatches {
+            primitives.extend(checkerboard(*swatch, style.checkerboard_color));
+            primitives.push(Primitive::Quad {
+                bounds: *swatch,
+                background: Background::Color(*color),
+                border_color: style.popup_border_color,
+                border_width: 1,
+                border_radius: 0,
+            });
+        }
+
+        let mut mouse_interaction = if [sv, controls.hue, controls.alpha]
+            .iter()
+            .chain(controls.swatches.iter().map(|(swatch, _)| swatch))
+            .any(|bounds| bounds.contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        for (primitive, field_interaction) in fields {
+            primitives.push(primitive);
+
+            if field_interaction > mouse_interaction {
+                mouse_interaction = field_interaction;
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Builds a mesh covering the given bounds, where the color of each vertex
+/// depends on its relative position.
+fn gradient(
+    bounds: Rectangle,
+    columns: u32,
+    rows: u32,
+    color_at: impl Fn(f32, f32) -> Color,
+) -> Primitive {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for row in 0..=rows {
+        for column in 0..=columns {
+            let x = column as f32 / columns as f32;
+            let y = row as f32 / rows as f32;
+
+            vertices.push(Vertex2D {
+                position: [x * bounds.width, y * bounds.height],
+                color: color_at(x, y).into_linear(),
+            });
+        }
+    }
+
+    for row in 0..rows {
+        for column in 0..columns {
+            let top_left = row * (columns + 1) + column;
+            let bottom_left = top_left + columns + 1;
+
+            indices.extend_from_slice(&[
+                top_left,
+                top_left + 1,
+                bottom_left + 1,
+                top_left,
+                bottom_left + 1,
+                bottom_left,
+            ]);
+        }
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D { vertices, indices },
+            size: bounds.size(),
+        }),
+    }
+}
+
+/// Draws the checkered pattern that shows through translucent colors.
+fn checkerboard(bounds: Rectangle, color: Color) -> Vec<Primitive> {
+    let cell = (bounds.height / 2.0).clamp(1.0, 6.0);
+    let columns = (bounds.width / cell).ceil() as usize;
+    let rows = (bounds.height / cell).ceil() as usize;
+
+    let mut primitives = vec![Primitive::Quad {
+        bounds,
+        background: Background::Color(Color::WHITE),
+        border_color: Color::TRANSPARENT,
+        border_width: 0,
+        border_radius: 0,
+    }];
+
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let x = column as f32 * cell;
+            let y = row as f32 * cell;
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + x,
+                    y: bounds.y + y,
+                    width: cell.min(bounds.width - x),
+                    height: cell.min(bounds.height - y),
+                },
+                background: Background::Color(color),
+                border_color: Color::TRANSPARENT,
+                border_width: 0,
+                border_radius: 0,
+            });
+        }
+    }
+
+    primitives
+}
+
+/// Draws the ring marking the saturation and value of the picked color.
+fn marker(center: Point, style: &Style) -> Primitive {
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: center.x - 5.0,
+            y: center.y - 5.0,
+            width: 10.0,
+            height: 10.0,
+        },
+        background: Background::Color(Color::TRANSPARENT),
+        border_color: style.marker_color,
+        border_width: 2,
+        border_radius: 5,
+    }
+}
+
+/// Draws the handle of a slider of the overlay at the given percent.
+fn handle(bar: Rectangle, percent: f32, style: &Style) -> Primitive {
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: bar.x + percent * bar.width - 2.0,
+            y: bar.y - 2.0,
+            width: 4.0,
+            height: bar.height + 4.0,
+        },
+        background: Background::Color(Color::TRANSPARENT),
+        border_color: style.marker_color,
+        border_width: 2,
+        border_radius: 2,
+    }
+}
@@ -0,0 +1,29 @@
+//! Let your users search and pick a value from a list of options.
+//!
+//! A [`ComboBox`] has some local [`State`].
+//!
+//! [`ComboBox`]: struct.ComboBox.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::Renderer;
+use iced_native::combo_box;
+
+pub use iced_native::combo_box::{Filter, State};
+
+/// A field that filters a list of options as the user types in it.
+///
+/// This is an alias of an `iced_native` combo box with an
+/// `iced_graphics::Renderer`.
+pub type ComboBox<'a, T, Message, Backend> =
+    iced_native::ComboBox<'a, T, Message, Renderer<Backend>>;
+
+impl<B> combo_box::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(&mut self, text_input: Self::Output) -> Self::Output {
+        text_input
+    }
+}
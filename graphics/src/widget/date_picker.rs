@@ -0,0 +1,225 @@
+//! Pick a date from a calendar.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    date_picker::Cell, mouse, Background, Color, Font, HorizontalAlignment,
+    Point, Rectangle, VerticalAlignment,
+};
+
+pub use iced_native::date_picker::{Date, State};
+pub use iced_style::date_picker::{Style, StyleSheet};
+
+/// A field that opens a calendar to pick a date.
+pub type DatePicker<'a, Message, Backend> =
+    iced_native::DatePicker<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::date_picker::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        value: Option<String>,
+        placeholder: Option<&str>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style.hovered()
+        } else {
+            style.active()
+        };
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_color: style.border_color,
+            border_width: style.border_width,
+            border_radius: style.border_radius,
+        };
+
+        let (content, color) = match (value, placeholder) {
+            (Some(value), _) => (value, style.text_color),
+            (None, Some(placeholder)) => {
+                (placeholder.to_string(), style.placeholder_color)
+            }
+            (None, None) => (String::new(), style.text_color),
+        };
+
+        let label = Primitive::Text {
+            content,
+            size: f32::from(text_size),
+            font,
+            color,
+            bounds: Rectangle {
+                x: bounds.x + f32::from(padding),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        let arrow_down = Primitive::Text {
+            content: B::ARROW_DOWN_ICON.to_string(),
+            font: B::ICON_FONT,
+            size: bounds.height * style.icon_size,
+            bounds: Rectangle {
+                x: bounds.x + bounds.width - f32::from(padding) * 2.0,
+                y: bounds.center_y(),
+                ..bounds
+            },
+            color: style.text_color,
+            horizontal_alignment: HorizontalAlignment::Right,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![background, label, arrow_down],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+
+    fn draw_calendar(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        title: &str,
+        navigation: &[Cell],
+        weekdays: &[Cell],
+        days: &[Cell],
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.active();
+        let mut primitives = vec![popup(bounds, &style)];
+
+        if let Some(header) = navigation.first() {
+            primitives.push(Primitive::Text {
+                content: title.to_string(),
+                size: f32::from(text_size),
+                font,
+                color: style.text_color,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: header.bounds.center_y(),
+                    ..bounds
+                },
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        for weekday in weekdays {
+            primitives.push(label(
+                weekday,
+                style.secondary_text_color,
+                text_size,
+                font,
+            ));
+        }
+
+        for cell in navigation.iter().chain(days) {
+            primitives.extend(self::cell(
+                cell,
+                cursor_position,
+                text_size,
+                font,
+                &style,
+            ));
+        }
+
+        (
+            Primitive::Group { primitives },
+            if bounds.contains(cursor_position) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
+/// Draws the background of the popup of a picker.
+pub(crate) fn popup(bounds: Rectangle, style: &Style) -> Primitive {
+    Primitive::Quad {
+        bounds,
+        background: style.popup_background,
+        border_color: style.popup_border_color,
+        border_width: style.popup_border_width,
+        border_radius: style.border_radius,
+    }
+}
+
+/// Draws a cell of a picker grid that can be hovered and picked.
+pub(crate) fn cell(
+    cell: &Cell,
+    cursor_position: Point,
+    text_size: u16,
+    font: Font,
+    style: &Style,
+) -> [Primitive; 2] {
+    let is_hovered = !cell.is_disabled && cell.bounds.contains(cursor_position);
+
+    let background = if cell.is_selected {
+        style.selected_background
+    } else if is_hovered {
+        style.hovered_background
+    } else {
+        Background::Color(Color::TRANSPARENT)
+    };
+
+    let color = if cell.is_selected {
+        style.selected_text_color
+    } else if cell.is_disabled {
+        style.disabled_text_color
+    } else if cell.is_secondary {
+        style.secondary_text_color
+    } else {
+        style.text_color
+    };
+
+    [
+        Primitive::Quad {
+            bounds: cell.bounds,
+            background,
+            border_color: style.focused_border_color,
+            border_width: if cell.is_focused { 1 } else { 0 },
+            border_radius: style.border_radius,
+        },
+        label(cell, color, text_size, font),
+    ]
+}
+
+fn label(cell: &Cell, color: Color, text_size: u16, font: Font) -> Primitive {
+    Primitive::Text {
+        content: cell.label.clone(),
+        size: f32::from(text_size),
+        font,
+        color,
+        bounds: Rectangle {
+            x: cell.bounds.center_x(),
+            y: cell.bounds.center_y(),
+            ..cell.bounds
+        },
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    }
+}
@@ -5,7 +5,7 @@ use iced_native::image;
 use iced_native::mouse;
 use iced_native::Layout;
 
-pub use iced_native::image::{Handle, Image};
+pub use iced_native::image::{FilterMethod, Handle, Image};
 
 impl<B> image::Renderer for Renderer<B>
 where
@@ -24,6 +24,7 @@ where
             Primitive::Image {
                 handle,
                 bounds: layout.bounds(),
+                filter_method: image::FilterMethod::default(),
             },
             mouse::Interaction::default(),
         )
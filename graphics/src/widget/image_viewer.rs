@@ -0,0 +1,45 @@
+//! Inspect an image by zooming and panning it.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::image::{FilterMethod, Handle};
+use iced_native::image_viewer;
+use iced_native::mouse;
+use iced_native::{Point, Rectangle, Vector};
+
+pub use iced_native::image_viewer::{ImageViewer, State};
+
+impl<B> image_viewer::Renderer for Renderer<B>
+where
+    B: Backend + backend::Image,
+{
+    fn draw(
+        &mut self,
+        state: &State,
+        handle: Handle,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
+        filter_method: FilterMethod,
+        cursor_position: Point,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Image {
+                    handle,
+                    bounds: image_bounds,
+                    filter_method,
+                }),
+            },
+            if state.is_grabbed() {
+                mouse::Interaction::Grabbing
+            } else if is_mouse_over {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
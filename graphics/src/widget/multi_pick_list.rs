@@ -0,0 +1,140 @@
+//! Display a dropdown list of options to pick a set of values from.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, multi_pick_list::Chip, Color, Font, HorizontalAlignment, Point,
+    Rectangle, VerticalAlignment,
+};
+use iced_style::menu;
+
+pub use iced_native::multi_pick_list::State;
+pub use iced_style::multi_pick_list::{Style, StyleSheet};
+
+/// A widget allowing the selection of a set of values from a list of options.
+pub type MultiPickList<'a, T, Message, Backend> =
+    iced_native::MultiPickList<'a, T, Message, Renderer<Backend>>;
+
+impl<B> iced_native::multi_pick_list::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn menu_style(style: &Box<dyn StyleSheet>) -> menu::Style {
+        style.menu()
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        chips: &[Chip<'_>],
+        placeholder: Option<&str>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style.hovered()
+        } else {
+            style.active()
+        };
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_color: style.border_color,
+            border_width: style.border_width,
+            border_radius: style.border_radius,
+        }];
+
+        if chips.is_empty() {
+            if let Some(placeholder) = placeholder {
+                primitives.push(Primitive::Text {
+                    content: placeholder.to_string(),
+                    size: f32::from(text_size),
+                    font,
+                    color: style.placeholder_color,
+                    bounds: Rectangle {
+                        x: bounds.x + f32::from(padding),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
+        for chip in chips {
+            primitives.push(Primitive::Quad {
+                bounds: chip.bounds,
+                background: style.chip_background,
+                border_color: Color::TRANSPARENT,
+                border_width: 0,
+                border_radius: style.chip_border_radius,
+            });
+
+            primitives.push(Primitive::Text {
+                content: chip.label.to_string(),
+                size: f32::from(text_size),
+                font,
+                color: style.chip_text_color,
+                bounds: Rectangle {
+                    x: chip.bounds.x + f32::from(padding),
+                    y: chip.bounds.center_y(),
+                    ..chip.bounds
+                },
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            primitives.push(Primitive::Text {
+                content: String::from("×"),
+                size: f32::from(text_size),
+                font,
+                color: if chip.remove_bounds.contains(cursor_position) {
+                    style.text_color
+                } else {
+                    style.placeholder_color
+                },
+                bounds: Rectangle {
+                    x: chip.remove_bounds.center_x(),
+                    y: chip.remove_bounds.center_y(),
+                    ..chip.remove_bounds
+                },
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        // The arrow keeps the size it has in a single-row pick list
+        primitives.push(Primitive::Text {
+            content: B::ARROW_DOWN_ICON.to_string(),
+            font: B::ICON_FONT,
+            size: f32::from(text_size + padding * 2) * style.icon_size,
+            bounds: Rectangle {
+                x: bounds.x + bounds.width - f32::from(padding) * 2.0,
+                y: bounds.center_y(),
+                ..bounds
+            },
+            color: style.text_color,
+            horizontal_alignment: HorizontalAlignment::Right,
+            vertical_alignment: VerticalAlignment::Center,
+        });
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -0,0 +1,39 @@
+//! Let your users type or step through numeric values.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::number_input;
+
+pub use iced_native::number_input::State;
+
+/// A field that holds a number, with buttons to increment and decrement it.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_graphics::Renderer`.
+pub type NumberInput<'a, T, Message, Backend> =
+    iced_native::NumberInput<'a, T, Message, Renderer<Backend>>;
+
+impl<B> number_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw(
+        &mut self,
+        (text_input, text_input_interaction): Self::Output,
+        (increment, increment_interaction): Self::Output,
+        (decrement, decrement_interaction): Self::Output,
+    ) -> Self::Output {
+        (
+            Primitive::Group {
+                primitives: vec![text_input, increment, decrement],
+            },
+            text_input_interaction
+                .max(increment_interaction)
+                .max(decrement_interaction),
+        )
+    }
+}
@@ -0,0 +1,97 @@
+//! Display an interactive selector of a range of values, with two handles.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+//!
+//! [`RangeSlider`]: struct.RangeSlider.html
+//! [`State`]: struct.State.html
+use crate::slider::{handle, percent, rail};
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::range_slider;
+use iced_native::{Color, Point, Rectangle};
+
+pub use iced_native::range_slider::{Bound, State};
+pub use iced_native::slider::Orientation;
+pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
+
+/// A bar with two handles that select a minimum and a maximum value from a
+/// range of values.
+///
+/// This is an alias of an `iced_native` range slider with an
+/// `iced_graphics::Renderer`.
+pub type RangeSlider<'a, T, Message, Backend> =
+    iced_native::RangeSlider<'a, T, Message, Renderer<Backend>>;
+
+impl<B> range_slider::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_HEIGHT: u16 = 22;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: std::ops::RangeInclusive<f32>,
+        (lower, upper): (f32, f32),
+        dragging: Option<Bound>,
+        orientation: Orientation,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if dragging.is_some() {
+            style_sheet.dragging()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let lower = percent(range.clone(), lower);
+        let upper = percent(range, upper);
+
+        // The rail outside of the selected range is faded out
+        let (top, bottom) = style.rail_colors;
+        let faded = (fade(top), fade(bottom));
+
+        let mut primitives = Vec::with_capacity(8);
+
+        let segments = [
+            (0.0, lower, faded),
+            (lower, upper, style.rail_colors),
+            (upper, 1.0, faded),
+        ];
+
+        for &(from, to, colors) in segments.iter() {
+            let [rail_top, rail_bottom] =
+                rail(bounds, orientation, from, to, colors);
+
+            primitives.push(rail_top);
+            primitives.push(rail_bottom);
+        }
+
+        primitives.push(handle(bounds, orientation, lower, style.handle));
+        primitives.push(handle(bounds, orientation, upper, style.handle));
+
+        (
+            Primitive::Group { primitives },
+            if dragging.is_some() {
+                mouse::Interaction::Grabbing
+            } else if is_mouse_over {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
+fn fade(color: Color) -> Color {
+    Color {
+        a: color.a * 0.5,
+        ..color
+    }
+}
@@ -0,0 +1,86 @@
+//! Write a paragraph of text made of differently styled spans.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::rich_text::{self, Run};
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Rectangle, VerticalAlignment,
+};
+
+pub use iced_native::rich_text::Span;
+
+/// A paragraph of text made of spans with their own style.
+///
+/// This is an alias of an `iced_native` rich text with an
+/// `iced_graphics::Renderer`.
+pub type RichText<Backend> = iced_native::RichText<Renderer<Backend>>;
+
+impl<B> rich_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        runs: &[Run<Font>],
+    ) -> Self::Output {
+        let mut primitives = Vec::with_capacity(runs.len());
+
+        for run in runs {
+            let color = run.color.unwrap_or(defaults.text.color);
+            let thickness = (f32::from(run.size) / 14.0).max(1.0).round();
+
+            if let Some(highlight) = run.highlight {
+                primitives.push(line(run.bounds, highlight));
+            }
+
+            primitives.push(Primitive::Text {
+                content: run.content.clone(),
+                size: f32::from(run.size),
+                bounds: run.bounds,
+                color,
+                font: run.font,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+
+            if run.underline {
+                primitives.push(line(
+                    Rectangle {
+                        y: run.bounds.y + run.bounds.height - thickness,
+                        height: thickness,
+                        ..run.bounds
+                    },
+                    color,
+                ));
+            }
+
+            if run.strikethrough {
+                primitives.push(line(
+                    Rectangle {
+                        y: (run.bounds.center_y() - thickness / 2.0).round(),
+                        height: thickness,
+                        ..run.bounds
+                    },
+                    color,
+                ));
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn line(bounds: Rectangle, color: Color) -> Primitive {
+    Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border_radius: 0,
+        border_width: 0,
+        border_color: Color::TRANSPARENT,
+    }
+}
@@ -4,11 +4,11 @@ use iced_native::mouse;
 use iced_native::scrollable;
 use iced_native::{Background, Color, Rectangle, Vector};
 
-pub use iced_native::scrollable::State;
+pub use iced_native::scrollable::{Direction, RelativeOffset, State};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content
-/// with a scrollbar.
+/// A widget that can display an infinite amount of content with
+/// scrollbars.
 ///
 /// This is an alias of an `iced_native` scrollable with a default
 /// `Renderer`.
@@ -24,40 +24,83 @@ where
 {
     type Style = Box<dyn iced_style::scrollable::StyleSheet>;
 
-    fn scrollbar(
+    fn scrollbars(
         &self,
+        direction: scrollable::Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
-        offset: u32,
-    ) -> Option<scrollable::Scrollbar> {
-        if content_bounds.height > bounds.height {
+        offset: Vector<u32>,
+    ) -> scrollable::Scrollbars {
+        let thickness = f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN);
+
+        let is_vertical =
+            direction.is_vertical() && content_bounds.height > bounds.height;
+        let is_horizontal =
+            direction.is_horizontal() && content_bounds.width > bounds.width;
+
+        // Scrollbars leave room for each other in the corner
+        let vertical = if is_vertical {
             let scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                x: bounds.x + bounds.width - thickness,
                 y: bounds.y,
-                width: f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
-                height: bounds.height,
+                width: thickness,
+                height: bounds.height
+                    - if is_horizontal { thickness } else { 0.0 },
             };
 
             let ratio = bounds.height / content_bounds.height;
-            let scrollbar_height = bounds.height * ratio;
-            let y_offset = offset as f32 * ratio;
-
-            let scroller_bounds = Rectangle {
-                x: scrollbar_bounds.x + f32::from(SCROLLBAR_MARGIN),
-                y: scrollbar_bounds.y + y_offset,
-                width: scrollbar_bounds.width - f32::from(2 * SCROLLBAR_MARGIN),
-                height: scrollbar_height,
+            let scroller_height = scrollbar_bounds.height * ratio;
+            let y_offset = offset.y as f32 * ratio * scrollbar_bounds.height
+                / bounds.height;
+
+            Some(scrollable::Scrollbar {
+                bounds: scrollbar_bounds,
+                scroller: scrollable::Scroller {
+                    bounds: Rectangle {
+                        x: scrollbar_bounds.x + f32::from(SCROLLBAR_MARGIN),
+                        y: scrollbar_bounds.y + y_offset,
+                        width: scrollbar_bounds.width
+                            - f32::from(2 * SCROLLBAR_MARGIN),
+                        height: scroller_height,
+                    },
+                },
+            })
+        } else {
+            None
+        };
+
+        let horizontal = if is_horizontal {
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - thickness,
+                width: bounds.width - if is_vertical { thickness } else { 0.0 },
+                height: thickness,
             };
 
+            let ratio = bounds.width / content_bounds.width;
+            let scroller_width = scrollbar_bounds.width * ratio;
+            let x_offset =
+                offset.x as f32 * ratio * scrollbar_bounds.width / bounds.width;
+
             Some(scrollable::Scrollbar {
                 bounds: scrollbar_bounds,
                 scroller: scrollable::Scroller {
-                    bounds: scroller_bounds,
+                    bounds: Rectangle {
+                        x: scrollbar_bounds.x + x_offset,
+                        y: scrollbar_bounds.y + f32::from(SCROLLBAR_MARGIN),
+                        width: scroller_width,
+                        height: scrollbar_bounds.height
+                            - f32::from(2 * SCROLLBAR_MARGIN),
+                    },
                 },
             })
         } else {
             None
+        };
+
+        scrollable::Scrollbars {
+            vertical,
+            horizontal,
         }
     }
 
@@ -68,16 +111,19 @@ where
         _content_bounds: Rectangle,
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
-        scrollbar: Option<scrollable::Scrollbar>,
-        offset: u32,
+        scrollbars: scrollable::Scrollbars,
+        offset: Vector<u32>,
         style_sheet: &Self::Style,
         (content, mouse_interaction): Self::Output,
     ) -> Self::Output {
+        let is_scrollable =
+            scrollbars.vertical.is_some() || scrollbars.horizontal.is_some();
+
         (
-            if let Some(scrollbar) = scrollbar {
+            if is_scrollable {
                 let clip = Primitive::Clip {
                     bounds,
-                    offset: Vector::new(0, offset),
+                    offset,
                     content: Box::new(content),
                 };
 
@@ -92,43 +138,63 @@ where
                 let is_scrollbar_visible =
                     style.background.is_some() || style.border_width > 0;
 
-                let scroller = if is_mouse_over
+                let is_scroller_visible = is_mouse_over
                     || state.is_scroller_grabbed()
-                    || is_scrollbar_visible
+                    || is_scrollbar_visible;
+
+                let mut primitives = vec![clip];
+
+                for (scrollbar, is_vertical) in scrollbars
+                    .vertical
+                    .iter()
+                    .map(|scrollbar| (scrollbar, true))
+                    .chain(
+                        scrollbars
+                            .horizontal
+                            .iter()
+                            .map(|scrollbar| (scrollbar, false)),
+                    )
                 {
-                    Primitive::Quad {
-                        bounds: scrollbar.scroller.bounds,
-                        background: Background::Color(style.scroller.color),
-                        border_radius: style.scroller.border_radius,
-                        border_width: style.scroller.border_width,
-                        border_color: style.scroller.border_color,
-                    }
-                } else {
-                    Primitive::None
-                };
+                    if is_scrollbar_visible {
+                        let margin = f32::from(SCROLLBAR_MARGIN);
+
+                        let bounds = if is_vertical {
+                            Rectangle {
+                                x: scrollbar.bounds.x + margin,
+                                width: scrollbar.bounds.width - 2.0 * margin,
+                                ..scrollbar.bounds
+                            }
+                        } else {
+                            Rectangle {
+                                y: scrollbar.bounds.y + margin,
+                                height: scrollbar.bounds.height - 2.0 * margin,
+                                ..scrollbar.bounds
+                            }
+                        };
 
-                let scrollbar = if is_scrollbar_visible {
-                    Primitive::Quad {
-                        bounds: Rectangle {
-                            x: scrollbar.bounds.x + f32::from(SCROLLBAR_MARGIN),
-                            width: scrollbar.bounds.width
-                                - f32::from(2 * SCROLLBAR_MARGIN),
-                            ..scrollbar.bounds
-                        },
-                        background: style
-                            .background
-                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                        border_radius: style.border_radius,
-                        border_width: style.border_width,
-                        border_color: style.border_color,
+                        primitives.push(Primitive::Quad {
+                            bounds,
+                            background: style.background.unwrap_or(
+                                Background::Color(Color::TRANSPARENT),
+                            ),
+                            border_radius: style.border_radius,
+                            border_width: style.border_width,
+                            border_color: style.border_color,
+                        });
                     }
-                } else {
-                    Primitive::None
-                };
 
-                Primitive::Group {
-                    primitives: vec![clip, scrollbar, scroller],
+                    if is_scroller_visible {
+                        primitives.push(Primitive::Quad {
+                            bounds: scrollbar.scroller.bounds,
+                            background: Background::Color(style.scroller.color),
+                            border_radius: style.scroller.border_radius,
+                            border_width: style.scroller.border_width,
+                            border_color: style.scroller.border_color,
+                        });
+                    }
                 }
+
+                Primitive::Group { primitives }
             } else {
                 content
             },
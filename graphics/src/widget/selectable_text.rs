@@ -0,0 +1,76 @@
+//! Let your users select and copy some text.
+//!
+//! A [`SelectableText`] has some local [`State`].
+//!
+//! [`SelectableText`]: type.SelectableText.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::selectable_text;
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Point, Rectangle,
+    VerticalAlignment,
+};
+
+pub use iced_native::selectable_text::State;
+pub use iced_style::selectable_text::{Style, StyleSheet};
+
+/// A paragraph of text that can be selected and copied.
+///
+/// This is an alias of an `iced_native` selectable text with an
+/// `iced_graphics::Renderer`.
+pub type SelectableText<'a, Backend> =
+    iced_native::SelectableText<'a, Renderer<Backend>>;
+
+impl<B> selectable_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Font,
+        color: Option<Color>,
+        selection: &[Rectangle],
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let mut primitives: Vec<Primitive> = selection
+            .iter()
+            .map(|bounds| Primitive::Quad {
+                bounds: *bounds,
+                background: Background::Color(style.selection),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            })
+            .collect();
+
+        primitives.push(Primitive::Text {
+            content: content.to_string(),
+            size: f32::from(size),
+            bounds,
+            color: color.unwrap_or(defaults.text.color),
+            font,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        });
+
+        (
+            Primitive::Group { primitives },
+            if bounds.contains(cursor_position) {
+                mouse::Interaction::Text
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -9,11 +9,10 @@ use iced_native::mouse;
 use iced_native::slider;
 use iced_native::{Background, Color, Point, Rectangle};
 
-pub use iced_native::slider::State;
+pub use iced_native::slider::{Orientation, State};
 pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+/// A bar and a handle that selects a single value from a range of values.
 ///
 /// This is an alias of an `iced_native` slider with an `iced_wgpu::Renderer`.
 pub type Slider<'a, T, Message, Backend> =
@@ -34,6 +33,7 @@ where
         range: std::ops::RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
+        orientation: Orientation,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
@@ -46,65 +46,11 @@ where
             style_sheet.active()
         };
 
-        let rail_y = bounds.y + (bounds.height / 2.0).round();
+        let [rail_top, rail_bottom] =
+            rail(bounds, orientation, 0.0, 1.0, style.rail_colors);
 
-        let (rail_top, rail_bottom) = (
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                background: Background::Color(style.rail_colors.0),
-                border_radius: 0,
-                border_width: 0,
-                border_color: Color::TRANSPARENT,
-            },
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y + 2.0,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                background: Background::Color(style.rail_colors.1),
-                border_radius: 0,
-                border_width: 0,
-                border_color: Color::TRANSPARENT,
-            },
-        );
-
-        let (range_start, range_end) = range.into_inner();
-
-        let (handle_width, handle_height, handle_border_radius) = match style
-            .handle
-            .shape
-        {
-            HandleShape::Circle { radius } => {
-                (f32::from(radius * 2), f32::from(radius * 2), radius)
-            }
-            HandleShape::Rectangle {
-                width,
-                border_radius,
-            } => (f32::from(width), f32::from(bounds.height), border_radius),
-        };
-
-        let handle_offset = (bounds.width - handle_width)
-            * ((value - range_start) / (range_end - range_start).max(1.0));
-
-        let handle = Primitive::Quad {
-            bounds: Rectangle {
-                x: bounds.x + handle_offset.round(),
-                y: rail_y - handle_height / 2.0,
-                width: handle_width,
-                height: handle_height,
-            },
-            background: Background::Color(style.handle.color),
-            border_radius: handle_border_radius,
-            border_width: style.handle.border_width,
-            border_color: style.handle.border_color,
-        };
+        let handle =
+            handle(bounds, orientation, percent(range, value), style.handle);
 
         (
             Primitive::Group {
@@ -120,3 +66,143 @@ where
         )
     }
 }
+
+/// Returns how far along a range of values the given value is.
+pub(crate) fn percent(range: std::ops::RangeInclusive<f32>, value: f32) -> f32 {
+    let (range_start, range_end) = range.into_inner();
+
+    (value - range_start) / (range_end - range_start).max(1.0)
+}
+
+/// Draws the segment of the rail of a slider that lies between the given
+/// percents of its length.
+pub(crate) fn rail(
+    bounds: Rectangle,
+    orientation: Orientation,
+    from: f32,
+    to: f32,
+    colors: (Color, Color),
+) -> [Primitive; 2] {
+    let quad = |bounds, color| Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border_radius: 0,
+        border_width: 0,
+        border_color: Color::TRANSPARENT,
+    };
+
+    match orientation {
+        Orientation::Horizontal => {
+            let rail_y = bounds.y + (bounds.height / 2.0).round();
+            let x = bounds.x + bounds.width * from;
+            let width = bounds.width * (to - from);
+
+            [
+                quad(
+                    Rectangle {
+                        x,
+                        y: rail_y,
+                        width,
+                        height: 2.0,
+                    },
+                    colors.0,
+                ),
+                quad(
+                    Rectangle {
+                        x,
+                        y: rail_y + 2.0,
+                        width,
+                        height: 2.0,
+                    },
+                    colors.1,
+                ),
+            ]
+        }
+        Orientation::Vertical => {
+            let rail_x = bounds.x + (bounds.width / 2.0).round();
+            let y = bounds.y + bounds.height * (1.0 - to);
+            let height = bounds.height * (to - from);
+
+            [
+                quad(
+                    Rectangle {
+                        x: rail_x,
+                        y,
+                        width: 2.0,
+                        height,
+                    },
+                    colors.0,
+                ),
+                quad(
+                    Rectangle {
+                        x: rail_x + 2.0,
+                        y,
+                        width: 2.0,
+                        height,
+                    },
+                    colors.1,
+                ),
+            ]
+        }
+    }
+}
+
+/// Draws the handle of a slider at the given percent of its rail.
+pub(crate) fn handle(
+    bounds: Rectangle,
+    orientation: Orientation,
+    percent: f32,
+    handle: Handle,
+) -> Primitive {
+    let (handle_width, handle_height, handle_border_radius) =
+        match (handle.shape, orientation) {
+            (HandleShape::Circle { radius }, _) => {
+                (f32::from(radius * 2), f32::from(radius * 2), radius)
+            }
+            (
+                HandleShape::Rectangle {
+                    width,
+                    border_radius,
+                },
+                Orientation::Horizontal,
+            ) => (f32::from(width), bounds.height, border_radius),
+            (
+                HandleShape::Rectangle {
+                    width,
+                    border_radius,
+                },
+                Orientation::Vertical,
+            ) => (bounds.width, f32::from(width), border_radius),
+        };
+
+    let (x, y) = match orientation {
+        Orientation::Horizontal => {
+            let rail_y = bounds.y + (bounds.height / 2.0).round();
+            let offset = (bounds.width - handle_width) * percent;
+
+            (bounds.x + offset.round(), rail_y - handle_height / 2.0)
+        }
+        Orientation::Vertical => {
+            let rail_x = bounds.x + (bounds.width / 2.0).round();
+            let offset = (bounds.height - handle_height) * percent;
+
+            (
+                rail_x - handle_width / 2.0,
+                bounds.y + bounds.height - handle_height - offset.round(),
+            )
+        }
+    };
+
+    Primitive::Quad {
+        bounds: Rectangle {
+            x,
+            y,
+            width: handle_width,
+            height: handle_height,
+        },
+        background: Background::Color(handle.color),
+        border_radius: handle_border_radius,
+        border_width: handle.border_width,
+        border_color: handle.border_color,
+    }
+}
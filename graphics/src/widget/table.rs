@@ -0,0 +1,246 @@
+//! Display data in rows and columns, with sortable and resizable columns.
+use crate::backend::{self, Backend};
+use crate::defaults::{self, Defaults};
+use crate::triangle;
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::table::{self, Header};
+use iced_native::{
+    Background, Color, Element, Font, HorizontalAlignment, Layout, Point,
+    Rectangle, Size, Vector, VerticalAlignment,
+};
+
+pub use iced_native::table::{
+    Column, ResizeEvent, Selection, SortOrder, State,
+};
+pub use iced_style::table::{Style, StyleSheet};
+
+/// A grid of rows under a header of titled columns.
+///
+/// This is an alias of an `iced_native` table with an
+/// `iced_graphics::Renderer`.
+pub type Table<'a, Message, Backend> =
+    iced_native::Table<'a, Message, Renderer<Backend>>;
+
+impl<B> table::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw_rows<Message>(
+        &mut self,
+        defaults: &Defaults,
+        rows: &[Vec<Element<'_, Message, Self>>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        selection: &[usize],
+        hovered_row: Option<usize>,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let mut mouse_interaction = mouse::Interaction::default();
+        let mut primitives = Vec::new();
+
+        for (index, (row, layout)) in
+            rows.iter().zip(layout.children()).enumerate()
+        {
+            let is_selected = selection.binary_search(&index).is_ok();
+
+            let background = if is_selected {
+                Some(style.selected_row_background)
+            } else if hovered_row == Some(index) {
+                style.hovered_row_background
+            } else if index % 2 == 1 {
+                style.alternate_row_background
+            } else {
+                style.row_background
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds: layout.bounds(),
+                    background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            let defaults = if is_selected {
+                Defaults {
+                    text: defaults::Text {
+                        color: style.selected_text_color,
+                    },
+                }
+            } else {
+                *defaults
+            };
+
+            for (cell, layout) in row.iter().zip(layout.children()) {
+                let (primitive, new_mouse_interaction) =
+                    cell.draw(self, &defaults, layout, cursor_position);
+
+                if new_mouse_interaction > mouse_interaction {
+                    mouse_interaction = new_mouse_interaction;
+                }
+
+                primitives.push(primitive);
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        header_bounds: Rectangle,
+        cursor_position: Point,
+        headers: &[Header<'_>],
+        resizing: Option<usize>,
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        (body, body_mouse_interaction): Self::Output,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let padding = f32::from(padding);
+        let size = f32::from(text_size);
+
+        let mut primitives = vec![
+            body,
+            Primitive::Quad {
+                bounds: header_bounds,
+                background: style.header_background,
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            },
+        ];
+
+        let mut is_mouse_over_sortable = false;
+
+        for header in headers {
+            let bounds = header.bounds;
+            let is_mouse_over = bounds.contains(cursor_position);
+
+            if header.is_sortable && is_mouse_over {
+                is_mouse_over_sortable = true;
+
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background: style.hovered_header_background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            primitives.push(Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Text {
+                    content: header.title.to_string(),
+                    size,
+                    font,
+                    color: style.header_text_color,
+                    bounds: Rectangle {
+                        x: bounds.x + padding,
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                }),
+            });
+
+            if let Some(order) = header.sort_order {
+                primitives.push(sort_indicator(
+                    Point::new(
+                        bounds.x + bounds.width - padding - size / 2.0,
+                        bounds.center_y(),
+                    ),
+                    size * 0.4,
+                    order,
+                    style.sort_indicator_color,
+                ));
+            }
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - 1.0,
+                    width: 1.0,
+                    ..bounds
+                },
+                background: Background::Color(style.separator_color),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                y: header_bounds.y + header_bounds.height - 1.0,
+                height: 1.0,
+                ..header_bounds
+            },
+            background: Background::Color(style.separator_color),
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        (
+            Primitive::Group { primitives },
+            if resizing.is_some() {
+                mouse::Interaction::ResizingHorizontally
+            } else if is_mouse_over_sortable {
+                mouse::Interaction::Pointer
+            } else {
+                body_mouse_interaction
+            },
+        )
+    }
+}
+
+/// Draws a triangle pointing up or down, centered at the given point.
+fn sort_indicator(
+    center: Point,
+    size: f32,
+    order: SortOrder,
+    color: Color,
+) -> Primitive {
+    let half = size / 2.0;
+
+    let (tip, base) = match order {
+        SortOrder::Ascending => (-half, half),
+        SortOrder::Descending => (half, -half),
+    };
+
+    let color = color.into_linear();
+
+    let vertex = |x: f32, y: f32| triangle::Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(center.x - size, center.y - size),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: triangle::Mesh2D {
+                vertices: vec![
+                    vertex(size, size + tip),
+                    vertex(0.0, size + base),
+                    vertex(size * 2.0, size + base),
+                ],
+                indices: vec![0, 1, 2],
+            },
+            size: Size::new(size * 2.0, size * 2.0),
+        }),
+    }
+}
@@ -0,0 +1,190 @@
+//! Switch between views with a bar of tabs.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::tabs::{self, Item};
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Rectangle, Vector,
+    VerticalAlignment,
+};
+
+pub use iced_native::tabs::{ReorderEvent, State, Tab};
+pub use iced_style::tabs::{Style, StyleSheet};
+
+/// A bar of tabs above the content of the active one.
+///
+/// This is an alias of an `iced_native` tabs widget with an
+/// `iced_graphics::Renderer`.
+pub type Tabs<'a, Message, Backend> =
+    iced_native::Tabs<'a, Message, Renderer<Backend>>;
+
+impl<B> tabs::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: u16 = 8;
+
+    fn draw(
+        &mut self,
+        bar_bounds: Rectangle,
+        tabs: &[Item<'_>],
+        padding: u16,
+        text_size: u16,
+        font: Font,
+        icon_font: Font,
+        (content, content_mouse_interaction): Self::Output,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let padding = f32::from(padding);
+        let size = f32::from(text_size);
+
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = content_mouse_interaction;
+
+        // The dragged tab is drawn last, on top of the others
+        let ordered = tabs
+            .iter()
+            .filter(|tab| !tab.is_dragged)
+            .chain(tabs.iter().filter(|tab| tab.is_dragged));
+
+        for tab in ordered {
+            let style = if tab.is_active {
+                style_sheet.active()
+            } else if tab.is_hovered {
+                style_sheet.hovered()
+            } else {
+                style_sheet.inactive()
+            };
+
+            let bounds = tab.bounds;
+
+            if tab.is_dragged {
+                mouse_interaction = mouse::Interaction::Grabbing;
+            } else if tab.is_hovered {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            if style.background.is_some() || style.border_width > 0 {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background: style
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                    border_radius: style.border_radius,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                });
+            }
+
+            if let Some(indicator_color) = style.indicator_color {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - 2.0,
+                        height: 2.0,
+                        ..bounds
+                    },
+                    background: Background::Color(indicator_color),
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            let mut x = bounds.x + padding;
+
+            if let Some(icon) = tab.icon {
+                primitives.push(Primitive::Text {
+                    content: icon.to_string(),
+                    size,
+                    font: icon_font,
+                    color: style.text_color,
+                    bounds: Rectangle {
+                        x: x + size / 2.0,
+                        y: bounds.center_y(),
+                        width: size,
+                        ..bounds
+                    },
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+
+                x += size + padding;
+            }
+
+            primitives.push(Primitive::Text {
+                content: tab.label.to_string(),
+                size,
+                font,
+                color: style.text_color,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            if let Some(close_bounds) = tab.close_bounds {
+                if tab.is_close_hovered {
+                    primitives.push(Primitive::Quad {
+                        bounds: close_bounds,
+                        background: Background::Color(Color {
+                            a: 0.15,
+                            ..style.text_color
+                        }),
+                        border_radius: (close_bounds.width / 2.0) as u16,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    });
+                }
+
+                primitives.push(Primitive::Text {
+                    content: CLOSE_ICON.to_string(),
+                    size,
+                    font: Font::Default,
+                    color: style.text_color,
+                    bounds: Rectangle {
+                        x: close_bounds.center_x(),
+                        y: close_bounds.center_y(),
+                        ..close_bounds
+                    },
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
+        let border = style_sheet.inactive().border_color;
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            y: bar_bounds.y + bar_bounds.height - 1.0,
+                            height: 1.0,
+                            ..bar_bounds
+                        },
+                        background: Background::Color(border),
+                        border_radius: 0,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Primitive::Clip {
+                        bounds: bar_bounds,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(Primitive::Group { primitives }),
+                    },
+                    content,
+                ],
+            },
+            mouse_interaction,
+        )
+    }
+}
+
+/// The multiplication sign, used as the icon of close buttons.
+const CLOSE_ICON: char = '\u{00D7}';
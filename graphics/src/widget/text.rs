@@ -4,7 +4,7 @@ use crate::{Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::text;
 use iced_native::{
-    Color, Font, HorizontalAlignment, Rectangle, Size, VerticalAlignment,
+    Color, Font, HorizontalAlignment, Point, Rectangle, Size, VerticalAlignment,
 };
 
 /// A paragraph of text.
@@ -35,6 +35,43 @@ where
             .measure(content, f32::from(size), font, bounds)
     }
 
+    fn hit_test(
+        &self,
+        content: &str,
+        size: u16,
+        font: Font,
+        bounds: Size,
+        point: Point,
+    ) -> Option<usize> {
+        let characters = self.backend().character_bounds(
+            content,
+            f32::from(size),
+            font,
+            bounds,
+        );
+
+        hit_test(&characters, point)
+    }
+
+    fn selection_bounds(
+        &self,
+        content: &str,
+        size: u16,
+        font: Font,
+        bounds: Size,
+        start: usize,
+        end: usize,
+    ) -> Vec<Rectangle> {
+        let characters = self.backend().character_bounds(
+            content,
+            f32::from(size),
+            font,
+            bounds,
+        );
+
+        selection_bounds(&characters, start, end)
+    }
+
     fn draw(
         &mut self,
         defaults: &Self::Defaults,
@@ -72,3 +109,219 @@ where
         )
     }
 }
+
+/// Lays out the text contents with the given size and font in the provided
+/// bounds, returning the bounds of every laid out character along with its
+/// index in the contents.
+///
+/// Backends can use this to implement [`backend::Text::character_bounds`]
+/// with the brush they use to measure text.
+///
+/// [`backend::Text::character_bounds`]: ../../backend/trait.Text.html#tymethod.character_bounds
+pub fn character_bounds(
+    brush: &mut glyph_brush::GlyphBrush<()>,
+    font_id: glyph_brush::FontId,
+    content: &str,
+    size: f32,
+    bounds: Size,
+) -> Vec<(usize, Rectangle)> {
+    use glyph_brush::ab_glyph::{Font as _, ScaleFont as _};
+    use glyph_brush::GlyphCruncher;
+
+    let section = glyph_brush::Section {
+        bounds: (bounds.width, bounds.height),
+        text: vec![glyph_brush::Text {
+            text: content,
+            scale: size.into(),
+            font_id,
+            extra: glyph_brush::Extra::default(),
+        }],
+        ..Default::default()
+    };
+
+    let glyphs: Vec<_> = brush.glyphs(section).cloned().collect();
+    let font = brush.fonts()[font_id.0].as_scaled(size);
+
+    let ascent = font.ascent();
+    let height = ascent - font.descent();
+
+    // Glyphs are mostly laid out in order, so we count characters
+    // incrementally to find their index
+    let mut last = (0, 0);
+
+    glyphs
+        .iter()
+        .map(|section_glyph| {
+            let byte_index = section_glyph.byte_index;
+            let (last_byte, last_index) =
+                if byte_index >= last.0 { last } else { (0, 0) };
+
+            let index =
+                last_index + content[last_byte..byte_index].chars().count();
+
+            last = (byte_index, index);
+
+            let glyph = &section_glyph.glyph;
+
+            (
+                index,
+                Rectangle {
+                    x: glyph.position.x,
+                    y: glyph.position.y - ascent,
+                    width: font.h_advance(glyph.id),
+                    height,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Returns the index of the character closest to the given point, given the
+/// bounds of the characters of some laid out text.
+fn hit_test(characters: &[(usize, Rectangle)], point: Point) -> Option<usize> {
+    let distance = |bounds: &Rectangle| {
+        if point.y < bounds.y {
+            bounds.y - point.y
+        } else {
+            (point.y - bounds.y - bounds.height).max(0.0)
+        }
+    };
+
+    // Find the line closest to the point first
+    let (_, closest) = characters.iter().min_by(|(_, a), (_, b)| {
+        distance(a)
+            .partial_cmp(&distance(b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    })?;
+
+    let line = characters
+        .iter()
+        .filter(|(_, bounds)| bounds.y == closest.y);
+
+    let mut position = None;
+
+    for (index, bounds) in line {
+        if point.x < bounds.center_x() {
+            return Some(*index);
+        }
+
+        position = Some(index + 1);
+    }
+
+    position
+}
+
+/// Returns the bounds covering the characters between `start` and `end` in
+/// every line, given the bounds of the characters of some laid out text.
+fn selection_bounds(
+    characters: &[(usize, Rectangle)],
+    start: usize,
+    end: usize,
+) -> Vec<Rectangle> {
+    let mut selection: Vec<Rectangle> = Vec::new();
+
+    for (_, bounds) in characters
+        .iter()
+        .filter(|(index, _)| *index >= start && *index < end)
+    {
+        match selection.last_mut() {
+            Some(line) if line.y == bounds.y => {
+                let right = (line.x + line.width).max(bounds.x + bounds.width);
+
+                line.x = line.x.min(bounds.x);
+                line.width = right - line.x;
+            }
+            _ => selection.push(*bounds),
+        }
+    }
+
+    selection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glyph_brush::ab_glyph::FontArc;
+
+    fn layout(content: &str, width: f32) -> Vec<(usize, Rectangle)> {
+        let font = FontArc::try_from_slice(include_bytes!(
+            "../../fonts/Lato-Regular.ttf"
+        ))
+        .expect("Load font");
+
+        let mut brush =
+            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        character_bounds(
+            &mut brush,
+            glyph_brush::FontId(0),
+            content,
+            20.0,
+            Size::new(width, f32::INFINITY),
+        )
+    }
+
+    fn inside(bounds: Rectangle, fraction: f32) -> Point {
+        Point::new(bounds.x + bounds.width * fraction, bounds.center_y())
+    }
+
+    #[test]
+    fn characters_are_indexed_by_char_in_multi_byte_text() {
+        let characters = layout("añb€c", f32::INFINITY);
+
+        assert_eq!(
+            characters
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+
+        let (_, n) = characters[1];
+        let (_, euro) = characters[3];
+        let (_, last) = characters[4];
+
+        assert_eq!(hit_test(&characters, inside(n, 0.25)), Some(1));
+        assert_eq!(hit_test(&characters, inside(n, 0.75)), Some(2));
+        assert_eq!(hit_test(&characters, inside(euro, 0.25)), Some(3));
+        assert_eq!(
+            hit_test(&characters, Point::new(last.x + 100.0, last.y)),
+            Some(5)
+        );
+        assert_eq!(hit_test(&[], Point::ORIGIN), None);
+    }
+
+    #[test]
+    fn wrapped_lines_are_hit_by_the_closest_line() {
+        let (_, space) = layout("hello world", f32::INFINITY)[5];
+        let characters = layout("hello world", space.x + space.width + 1.0);
+
+        let (_, h) = characters[0];
+        let (_, w) = characters[6];
+
+        assert!(w.y > h.y);
+        assert_eq!(w.x, h.x);
+
+        assert_eq!(hit_test(&characters, inside(w, 0.25)), Some(6));
+        assert_eq!(
+            hit_test(&characters, Point::new(w.x, w.y + w.height * 5.0)),
+            Some(6)
+        );
+        assert_eq!(
+            hit_test(&characters, Point::new(h.x + 1000.0, h.center_y())),
+            Some(6)
+        );
+
+        let selection = selection_bounds(&characters, 3, 8);
+
+        assert_eq!(selection.len(), 2);
+        assert_eq!(selection[0].x, characters[3].1.x);
+        assert_eq!(selection[0].y, h.y);
+        assert_eq!(selection[1].x, w.x);
+        assert_eq!(selection[1].y, w.y);
+        assert_eq!(
+            selection[1].width,
+            characters[7].1.x + characters[7].1.width - w.x
+        );
+    }
+}
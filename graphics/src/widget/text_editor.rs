@@ -0,0 +1,143 @@
+//! Display a multi-line area that can be filled with text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::text_editor::{self, Line};
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
+};
+use std::f32;
+
+pub use iced_native::text_editor::{Content, Position, State};
+pub use iced_style::text_editor::{Style, StyleSheet};
+
+/// A multi-line area that can be filled with text.
+///
+/// This is an alias of an `iced_native` text editor with an
+/// `iced_graphics::Renderer`.
+pub type TextEditor<'a, Message, Backend> =
+    iced_native::TextEditor<'a, Message, Renderer<Backend>>;
+
+impl<B> text_editor::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
+        let backend = self.backend();
+
+        let (width, _) =
+            backend.measure(value, f32::from(size), font, Size::INFINITY);
+
+        width
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Font,
+        size: u16,
+        placeholder: Option<&str>,
+        lines: &[Line],
+        selection: &[Rectangle],
+        cursor: Option<Rectangle>,
+        is_focused: bool,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_focused {
+            style_sheet.focused()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let editor = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let text = |content: String, bounds: Rectangle, color: Color| {
+            Primitive::Text {
+                content,
+                color,
+                font,
+                bounds: Rectangle {
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: f32::from(size),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            }
+        };
+
+        let highlight = |bounds: Rectangle, color: Color| Primitive::Quad {
+            bounds,
+            background: Background::Color(color),
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let mut primitives: Vec<Primitive> = selection
+            .iter()
+            .map(|bounds| highlight(*bounds, style_sheet.selection_color()))
+            .collect();
+
+        match (placeholder, lines.first()) {
+            (Some(placeholder), Some(line)) => {
+                primitives.push(text(
+                    placeholder.to_string(),
+                    line.bounds,
+                    style_sheet.placeholder_color(),
+                ));
+            }
+            _ => {
+                primitives.extend(lines.iter().map(|line| {
+                    text(
+                        line.content.clone(),
+                        line.bounds,
+                        style_sheet.value_color(),
+                    )
+                }));
+            }
+        }
+
+        if let Some(cursor) = cursor {
+            primitives.push(highlight(cursor, style_sheet.value_color()));
+        }
+
+        let contents = Primitive::Clip {
+            bounds: text_bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Group { primitives }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![editor, contents],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Text
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
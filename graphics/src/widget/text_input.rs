@@ -14,7 +14,7 @@ use iced_native::{
 };
 use std::f32;
 
-pub use iced_native::text_input::State;
+pub use iced_native::text_input::{Mask, State};
 pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
@@ -46,27 +46,12 @@ where
         value: &text_input::Value,
         state: &text_input::State,
     ) -> f32 {
-        if state.is_focused() {
-            let cursor = state.cursor();
+        // The value may have changed since the offset was last updated
+        let text_width = self.measure_value(&value.to_string(), size, font);
 
-            let focus_position = match cursor.state(value) {
-                cursor::State::Index(i) => i,
-                cursor::State::Selection { end, .. } => end,
-            };
-
-            let (_, offset) = measure_cursor_and_scroll_offset(
-                self,
-                text_bounds,
-                value,
-                size,
-                focus_position,
-                font,
-            );
-
-            offset
-        } else {
-            0.0
-        }
+        state
+            .offset()
+            .min((text_width + 5.0 - text_bounds.width).max(0.0))
     }
 
     fn draw(
@@ -99,7 +84,28 @@ where
             border_color: style.border_color,
         };
 
-        let text = value.to_string();
+        let cursor = state.cursor();
+
+        let (text, preedit) = match state.preedit() {
+            Some(preedit) if state.is_focused() => {
+                let index = match cursor.state(value) {
+                    cursor::State::Index(index) => index,
+                    cursor::State::Selection { start, end } => start.min(end),
+                };
+
+                let before = value.until(index).to_string();
+                let after = value.select(index, value.len()).to_string();
+
+                let start = self.measure_value(&before, size, font);
+                let end = start + self.measure_value(preedit, size, font);
+
+                (
+                    format!("{}{}{}", before, preedit, after),
+                    Some((start, end)),
+                )
+            }
+            _ => (value.to_string(), None),
+        };
 
         let text_value = Primitive::Text {
             content: if text.is_empty() {
@@ -123,97 +129,119 @@ where
             vertical_alignment: VerticalAlignment::Center,
         };
 
-        let (contents_primitive, offset) = if state.is_focused() {
-            let cursor = state.cursor();
-
-            let (cursor_primitive, offset) = match cursor.state(value) {
-                cursor::State::Index(position) => {
-                    let (text_value_width, offset) =
-                        measure_cursor_and_scroll_offset(
-                            self,
-                            text_bounds,
-                            value,
-                            size,
-                            position,
-                            font,
-                        );
+        let offset = self.offset(text_bounds, font, size, value, state);
 
-                    (
-                        Primitive::Quad {
-                            bounds: Rectangle {
-                                x: text_bounds.x + text_value_width,
-                                y: text_bounds.y,
-                                width: 1.0,
-                                height: text_bounds.height,
-                            },
-                            background: Background::Color(
-                                style_sheet.value_color(),
-                            ),
-                            border_radius: 0,
-                            border_width: 0,
-                            border_color: Color::TRANSPARENT,
+        let contents_primitive = if state.is_focused() {
+            let (cursor_primitive, caret_x) =
+                match (preedit, cursor.state(value)) {
+                    (Some((start, end)), _) => (
+                        Primitive::Group {
+                            primitives: vec![
+                                Primitive::Quad {
+                                    bounds: Rectangle {
+                                        x: text_bounds.x + start,
+                                        y: text_bounds.y + text_bounds.height
+                                            - 1.0,
+                                        width: end - start,
+                                        height: 1.0,
+                                    },
+                                    background: Background::Color(
+                                        style_sheet.value_color(),
+                                    ),
+                                    border_radius: 0,
+                                    border_width: 0,
+                                    border_color: Color::TRANSPARENT,
+                                },
+                                Primitive::Quad {
+                                    bounds: Rectangle {
+                                        x: text_bounds.x + end,
+                                        y: text_bounds.y,
+                                        width: 1.0,
+                                        height: text_bounds.height,
+                                    },
+                                    background: Background::Color(
+                                        style_sheet.value_color(),
+                                    ),
+                                    border_radius: 0,
+                                    border_width: 0,
+                                    border_color: Color::TRANSPARENT,
+                                },
+                            ],
                         },
-                        offset,
-                    )
-                }
-                cursor::State::Selection { start, end } => {
-                    let left = start.min(end);
-                    let right = end.max(start);
+                        end,
+                    ),
+                    (None, cursor::State::Index(position)) => {
+                        let text_value_width =
+                            measure_cursor(self, value, size, position, font);
 
-                    let (left_position, left_offset) =
-                        measure_cursor_and_scroll_offset(
-                            self,
-                            text_bounds,
-                            value,
-                            size,
-                            left,
-                            font,
-                        );
+                        (
+                            Primitive::Quad {
+                                bounds: Rectangle {
+                                    x: text_bounds.x + text_value_width,
+                                    y: text_bounds.y,
+                                    width: 1.0,
+                                    height: text_bounds.height,
+                                },
+                                background: Background::Color(
+                                    style_sheet.value_color(),
+                                ),
+                                border_radius: 0,
+                                border_width: 0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            text_value_width,
+                        )
+                    }
+                    (None, cursor::State::Selection { start, end }) => {
+                        let left = start.min(end);
+                        let right = end.max(start);
 
-                    let (right_position, right_offset) =
-                        measure_cursor_and_scroll_offset(
-                            self,
-                            text_bounds,
-                            value,
-                            size,
-                            right,
-                            font,
-                        );
+                        let left_position =
+                            measure_cursor(self, value, size, left, font);
+                        let right_position =
+                            measure_cursor(self, value, size, right, font);
 
-                    let width = right_position - left_position;
+                        let width = right_position - left_position;
 
-                    (
-                        Primitive::Quad {
-                            bounds: Rectangle {
-                                x: text_bounds.x + left_position,
-                                y: text_bounds.y,
-                                width,
-                                height: text_bounds.height,
+                        (
+                            Primitive::Quad {
+                                bounds: Rectangle {
+                                    x: text_bounds.x + left_position,
+                                    y: text_bounds.y,
+                                    width,
+                                    height: text_bounds.height,
+                                },
+                                background: Background::Color(
+                                    style_sheet.selection_color(),
+                                ),
+                                border_radius: 0,
+                                border_width: 0,
+                                border_color: Color::TRANSPARENT,
                             },
-                            background: Background::Color(
-                                style_sheet.selection_color(),
-                            ),
-                            border_radius: 0,
-                            border_width: 0,
-                            border_color: Color::TRANSPARENT,
-                        },
-                        if end == right {
-                            right_offset
-                        } else {
-                            left_offset
-                        },
-                    )
-                }
-            };
+                            if end == right {
+                                right_position
+                            } else {
+                                left_position
+                            },
+                        )
+                    }
+                };
 
-            (
-                Primitive::Group {
-                    primitives: vec![cursor_primitive, text_value],
-                },
-                Vector::new(offset as u32, 0),
-            )
+            iced_native::Renderer::set_ime_caret(
+                self,
+                Some(Rectangle {
+                    x: text_bounds.x + caret_x - offset,
+                    y: text_bounds.y,
+                    width: 1.0,
+                    height: text_bounds.height,
+                }),
+            );
+
+            Primitive::Group {
+                primitives: vec![cursor_primitive, text_value],
+            }
         } else {
-            (text_value, Vector::new(0, 0))
+            text_value
         };
 
         let text_width = self.measure_value(
@@ -225,7 +253,7 @@ where
         let contents = if text_width > text_bounds.width {
             Primitive::Clip {
                 bounds: text_bounds,
-                offset,
+                offset: Vector::new(offset as u32, 0),
                 content: Box::new(contents_primitive),
             }
         } else {
@@ -245,14 +273,13 @@ where
     }
 }
 
-fn measure_cursor_and_scroll_offset<B>(
+fn measure_cursor<B>(
     renderer: &Renderer<B>,
-    text_bounds: Rectangle,
     value: &text_input::Value,
     size: u16,
     cursor_index: usize,
     font: Font,
-) -> (f32, f32)
+) -> f32
 where
     B: Backend + backend::Text,
 {
@@ -260,9 +287,5 @@ where
 
     let text_before_cursor = value.until(cursor_index).to_string();
 
-    let text_value_width =
-        renderer.measure_value(&text_before_cursor, size, font);
-    let offset = ((text_value_width + 5.0) - text_bounds.width).max(0.0);
-
-    (text_value_width, offset)
+    renderer.measure_value(&text_before_cursor, size, font)
 }
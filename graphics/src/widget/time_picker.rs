@@ -0,0 +1,50 @@
+//! Pick a time of the day.
+use crate::backend::{self, Backend};
+use crate::widget::date_picker;
+use crate::{Primitive, Renderer};
+use iced_native::{date_picker::Cell, mouse, Font, Point, Rectangle};
+
+pub use iced_native::time_picker::{State, Time};
+pub use iced_style::date_picker::{Style, StyleSheet};
+
+/// A field that opens a grid of hours and minutes to pick a time.
+pub type TimePicker<'a, Message, Backend> =
+    iced_native::TimePicker<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::time_picker::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw_clock(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        hours: &[Cell],
+        minutes: &[Cell],
+        text_size: u16,
+        font: Font,
+        style: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style.active();
+        let mut primitives = vec![date_picker::popup(bounds, &style)];
+
+        for cell in hours.iter().chain(minutes) {
+            primitives.extend(date_picker::cell(
+                cell,
+                cursor_position,
+                text_size,
+                font,
+                &style,
+            ));
+        }
+
+        (
+            Primitive::Group { primitives },
+            if bounds.contains(cursor_position) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -0,0 +1,145 @@
+//! Display hierarchical data with expandable nodes.
+use crate::triangle;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::tree_view::{self, Item};
+use iced_native::{Background, Color, Point, Rectangle, Size, Vector};
+
+pub use iced_native::tree_view::{Node, State};
+pub use iced_style::tree_view::{Style, StyleSheet};
+
+/// A widget displaying a hierarchy of nodes that can be expanded and
+/// collapsed.
+///
+/// This is an alias of an `iced_native` tree view with an
+/// `iced_graphics::Renderer`.
+pub type TreeView<'a, Id, Message, Backend> =
+    iced_native::TreeView<'a, Id, Message, Renderer<Backend>>;
+
+impl<B> tree_view::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_INDENT: u16 = 20;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        cursor_position: Point,
+        items: &[Item],
+        contents: Vec<Self::Output>,
+        indent: u16,
+        is_focused: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = if is_focused {
+            style_sheet.focused()
+        } else {
+            style_sheet.active()
+        };
+
+        let indent = f32::from(indent);
+        let mut mouse_interaction = mouse::Interaction::default();
+        let mut primitives = Vec::new();
+
+        for (item, (content, new_mouse_interaction)) in
+            items.iter().zip(contents)
+        {
+            let bounds = item.bounds;
+            let is_mouse_over = bounds.contains(cursor_position);
+
+            let background = if item.is_selected {
+                Some(style.selected_background)
+            } else if is_mouse_over {
+                style.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            for level in 0..item.depth {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: (bounds.x + (level as f32 + 0.5) * indent).floor(),
+                        width: 1.0,
+                        ..bounds
+                    },
+                    background: Background::Color(style.guide_color),
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            if let Some(is_expanded) = item.is_expanded {
+                primitives.push(toggle(
+                    Point::new(
+                        bounds.x + (item.depth as f32 + 0.5) * indent,
+                        bounds.center_y(),
+                    ),
+                    indent * 0.2,
+                    is_expanded,
+                    style.toggle_color,
+                ));
+            }
+
+            primitives.push(content);
+
+            let new_mouse_interaction = if is_mouse_over {
+                new_mouse_interaction.max(mouse::Interaction::Pointer)
+            } else {
+                new_mouse_interaction
+            };
+
+            if new_mouse_interaction > mouse_interaction {
+                mouse_interaction = new_mouse_interaction;
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Draws a triangle centered at the given point, pointing down when expanded
+/// and right otherwise.
+fn toggle(
+    center: Point,
+    radius: f32,
+    is_expanded: bool,
+    color: Color,
+) -> Primitive {
+    let color = color.into_linear();
+
+    let vertex = |x: f32, y: f32| triangle::Vertex2D {
+        position: [radius + x * radius, radius + y * radius],
+        color,
+    };
+
+    let vertices = if is_expanded {
+        vec![vertex(-1.0, -0.5), vertex(1.0, -0.5), vertex(0.0, 0.5)]
+    } else {
+        vec![vertex(-0.5, -1.0), vertex(0.5, 0.0), vertex(-0.5, 1.0)]
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(center.x - radius, center.y - radius),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: triangle::Mesh2D {
+                vertices,
+                indices: vec![0, 1, 2],
+            },
+            size: Size::new(radius * 2.0, radius * 2.0),
+        }),
+    }
+}
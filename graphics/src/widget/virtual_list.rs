@@ -0,0 +1,12 @@
+//! Display a very large list of rows, building only the visible ones.
+use crate::Renderer;
+
+pub use iced_native::virtual_list::{RowHeight, State};
+pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
+
+/// A vertical list that only builds the rows that intersect its viewport.
+///
+/// This is an alias of an `iced_native` virtual list with an
+/// `iced_graphics::Renderer`.
+pub type VirtualList<'a, Message, Backend> =
+    iced_native::VirtualList<'a, Message, Renderer<Backend>>;
@@ -5,4 +5,11 @@ pub trait Clipboard {
     ///
     /// [`Clipboard`]: trait.Clipboard.html
     fn content(&self) -> Option<String>;
+
+    /// Writes the given text contents to the [`Clipboard`].
+    ///
+    /// Returns whether the contents were written successfully.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    fn write(&self, contents: String) -> bool;
 }
@@ -29,6 +29,8 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    replay_position: Option<(usize, usize)>,
 }
 
 impl Debug {
@@ -63,6 +65,8 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            replay_position: None,
         }
     }
 
@@ -142,6 +146,10 @@ impl Debug {
         self.message_count += 1;
     }
 
+    pub fn replay_stepped(&mut self, position: usize, total: usize) {
+        self.replay_position = Some((position, total));
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         if !self.is_enabled {
             return Vec::new();
@@ -173,6 +181,13 @@ impl Debug {
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
         lines.push(key_value("Message count:", self.message_count));
+
+        if let Some((position, total)) = self.replay_position {
+            lines.push(format!("Replay: {}/{}", position, total));
+            lines.push(String::from("    F9: step back"));
+            lines.push(String::from("    F10: step forward"));
+        }
+
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
             if msg.len() <= 100 {
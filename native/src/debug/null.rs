@@ -41,6 +41,8 @@ impl Debug {
     ) {
     }
 
+    pub fn replay_stepped(&mut self, _position: usize, _total: usize) {}
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
@@ -7,6 +7,7 @@ use crate::{keyboard, mouse, window};
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "replay", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
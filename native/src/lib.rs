@@ -34,13 +34,18 @@
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
-#![forbid(rust_2018_idioms)]
+// See `iced_core` for why `rust_2018_idioms` is only denied with `serde`.
+#![cfg_attr(not(feature = "serde"), forbid(rust_2018_idioms))]
+#![cfg_attr(feature = "serde", deny(rust_2018_idioms))]
+pub mod command;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
 pub mod overlay;
 pub mod program;
 pub mod renderer;
+#[cfg(feature = "replay")]
+pub mod replay;
 pub mod subscription;
 pub mod widget;
 pub mod window;
@@ -65,12 +70,13 @@ pub use iced_core::{
     Align, Background, Color, Font, HorizontalAlignment, Length, Point,
     Rectangle, Size, Vector, VerticalAlignment,
 };
-pub use iced_futures::{executor, futures, Command};
+pub use iced_futures::{executor, futures};
 
 #[doc(no_inline)]
 pub use executor::Executor;
 
 pub use clipboard::Clipboard;
+pub use command::Command;
 pub use debug::Debug;
 pub use element::Element;
 pub use event::Event;
@@ -1,18 +1,34 @@
 //! Build and show dropdown menus.
 use crate::{
-    container, layout, mouse, overlay, scrollable, text, Clipboard, Container,
-    Element, Event, Hasher, Layout, Length, Point, Rectangle, Scrollable, Size,
-    Vector, Widget,
+    container, keyboard, layout, mouse, overlay, scrollable, text, Clipboard,
+    Event, Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
 };
 
+use std::time::{Duration, Instant};
+
+/// The time after which typing starts a new search in a [`Menu`].
+///
+/// [`Menu`]: struct.Menu.html
+pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
 /// A list of selectable options.
+///
+/// A [`Menu`] can be browsed with the keyboard: the arrow keys, `Home` and
+/// `End` move the highlighted option, `Enter` selects it, and typing the first
+/// letters of an option jumps to it. When its options do not fit, a [`Menu`]
+/// can be scrolled.
+///
+/// [`Menu`]: struct.Menu.html
 #[allow(missing_debug_implementations)]
 pub struct Menu<'a, T, Renderer: self::Renderer> {
     state: &'a mut State,
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    checked: Option<&'a [bool]>,
     width: u16,
+    max_height: Option<u32>,
+    is_type_ahead: bool,
     padding: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
@@ -40,7 +56,10 @@ where
             options,
             hovered_option,
             last_selection,
+            checked: None,
             width: 0,
+            max_height: None,
+            is_type_ahead: true,
             padding: 0,
             text_size: None,
             font: Default::default(),
@@ -56,6 +75,39 @@ where
         self
     }
 
+    /// Sets the maximum height of the [`Menu`].
+    ///
+    /// The options that do not fit can be scrolled. A [`Menu`] is always
+    /// limited by the space available on the screen.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets whether typing in the [`Menu`] jumps to the options starting with
+    /// the typed text, and whether `Home` and `End` jump to its first and last
+    /// options.
+    ///
+    /// This is enabled by default. Disable it when the [`Menu`] belongs to a
+    /// widget that uses these keys to edit text.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn type_ahead(mut self, is_type_ahead: bool) -> Self {
+        self.is_type_ahead = is_type_ahead;
+        self
+    }
+
+    /// Shows a checkmark next to the options of the [`Menu`] that are
+    /// checked, given whether each option is checked or not.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn checked(mut self, checked: &'a [bool]) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
     /// Sets the padding of the [`Menu`].
     ///
     /// [`Menu`]: struct.Menu.html
@@ -95,8 +147,8 @@ where
     /// position.
     ///
     /// The `target_height` will be used to display the menu either on top
-    /// of the target or under it, depending on the screen position and the
-    /// dimensions of the [`Menu`].
+    /// of the target or under it. A [`Menu`] opens under its target, unless
+    /// it does not fit there and there is more space on top.
     ///
     /// [`Menu`]: struct.Menu.html
     pub fn overlay<Message: 'a>(
@@ -117,6 +169,8 @@ where
 #[derive(Debug, Clone, Default)]
 pub struct State {
     scrollable: scrollable::State,
+    type_ahead: String,
+    last_typed: Option<Instant>,
 }
 
 impl State {
@@ -129,58 +183,177 @@ impl State {
     }
 }
 
-struct Overlay<'a, Message, Renderer: self::Renderer> {
-    container: Container<'a, Message, Renderer>,
+struct Overlay<'a, T, Renderer: self::Renderer> {
+    scrollable: &'a mut scrollable::State,
+    type_ahead: &'a mut String,
+    last_typed: &'a mut Option<Instant>,
+    list: List<'a, T, Renderer>,
     width: u16,
+    max_height: Option<u32>,
+    is_type_ahead: bool,
     target_height: f32,
     style: <Renderer as self::Renderer>::Style,
 }
 
-impl<'a, Message, Renderer: self::Renderer> Overlay<'a, Message, Renderer>
+impl<'a, T, Renderer: self::Renderer> Overlay<'a, T, Renderer>
 where
-    Message: 'a,
-    Renderer: 'a,
+    T: Clone + ToString,
 {
-    pub fn new<T>(menu: Menu<'a, T, Renderer>, target_height: f32) -> Self
-    where
-        T: Clone + ToString,
-    {
+    pub fn new(menu: Menu<'a, T, Renderer>, target_height: f32) -> Self {
         let Menu {
             state,
             options,
             hovered_option,
             last_selection,
+            checked,
             width,
+            max_height,
+            is_type_ahead,
             padding,
             font,
             text_size,
             style,
         } = menu;
 
-        let container =
-            Container::new(Scrollable::new(&mut state.scrollable).push(List {
+        let State {
+            scrollable,
+            type_ahead,
+            last_typed,
+        } = state;
+
+        Self {
+            scrollable,
+            type_ahead,
+            last_typed,
+            list: List {
                 options,
                 hovered_option,
                 last_selection,
+                checked,
                 font,
                 text_size,
                 padding,
                 style: style.clone(),
-            }))
-            .padding(1);
-
-        Self {
-            container,
-            width: width,
+            },
+            width,
+            max_height,
+            is_type_ahead,
             target_height,
-            style: style,
+            style,
         }
     }
+
+    /// Handles the keys that browse the options, returning the option to
+    /// highlight, if any.
+    fn navigate(&mut self, event: &Event) -> Option<usize> {
+        let count = self.list.options.len();
+        let hovered = *self.list.hovered_option;
+
+        if count == 0 {
+            return None;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => match key_code {
+                keyboard::KeyCode::Up => Some(
+                    hovered.map(|i| i.saturating_sub(1)).unwrap_or(count - 1),
+                ),
+                keyboard::KeyCode::Down => {
+                    Some(hovered.map(|i| (i + 1).min(count - 1)).unwrap_or(0))
+                }
+                keyboard::KeyCode::Home if self.is_type_ahead => Some(0),
+                keyboard::KeyCode::End if self.is_type_ahead => Some(count - 1),
+                keyboard::KeyCode::Enter => {
+                    if let Some(option) =
+                        hovered.and_then(|i| self.list.options.get(i))
+                    {
+                        *self.list.last_selection = Some(option.clone());
+                    }
+
+                    None
+                }
+                _ => None,
+            },
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.is_type_ahead && !c.is_control() =>
+            {
+                let now = Instant::now();
+
+                match *self.last_typed {
+                    Some(last_typed)
+                        if now.duration_since(last_typed)
+                            < TYPE_AHEAD_TIMEOUT => {}
+                    _ => self.type_ahead.clear(),
+                }
+
+                self.type_ahead.extend(c.to_lowercase());
+                *self.last_typed = Some(now);
+
+                // A single letter moves to the next option starting with it,
+                // while longer searches may stay on the current option
+                let start = match hovered {
+                    Some(index) if self.type_ahead.chars().count() == 1 => {
+                        index + 1
+                    }
+                    Some(index) => index,
+                    None => 0,
+                };
+
+                (0..count).map(|i| (start + i) % count).find(|&i| {
+                    self.list.options[i]
+                        .to_string()
+                        .to_lowercase()
+                        .starts_with(self.type_ahead.as_str())
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Scrolls the options, if needed, so the given one becomes visible.
+    fn scroll_into_view(
+        &mut self,
+        index: usize,
+        option_height: f32,
+        viewport: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let offset = self.scrollable.offset(viewport, content_bounds).y as f32;
+        let top = index as f32 * option_height;
+        let bottom = top + option_height;
+
+        if top < offset {
+            self.scrollable.scroll_to(
+                Vector::new(0.0, top),
+                viewport,
+                content_bounds,
+            );
+        } else if bottom > offset + viewport.height {
+            self.scrollable.scroll_to(
+                Vector::new(0.0, bottom - viewport.height),
+                viewport,
+                content_bounds,
+            );
+        }
+    }
+}
+
+/// Returns the bounds of the scrollable area inside the border of a menu.
+fn viewport(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x + 1.0,
+        y: bounds.y + 1.0,
+        width: (bounds.width - 2.0).max(0.0),
+        height: (bounds.height - 2.0).max(0.0),
+    }
 }
 
-impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
-    for Overlay<'a, Message, Renderer>
+impl<'a, T, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, T, Renderer>
 where
+    T: Clone + ToString,
     Renderer: self::Renderer,
 {
     fn layout(
@@ -192,26 +365,42 @@ where
         let space_below = bounds.height - (position.y + self.target_height);
         let space_above = position.y;
 
+        let max_height = self
+            .max_height
+            .map(|max_height| max_height as f32)
+            .unwrap_or(f32::INFINITY)
+            .min(space_below.max(space_above));
+
         let limits = layout::Limits::new(
             Size::ZERO,
-            Size::new(
-                bounds.width - position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
+            Size::new(bounds.width - position.x, max_height),
         )
-        .width(Length::Units(self.width));
+        .width(Length::Units(self.width))
+        .height(Length::Shrink);
 
-        let mut node = self.container.layout(renderer, &limits);
+        let max_width = limits.max().width;
 
-        node.move_to(if space_below > space_above {
-            position + Vector::new(0.0, self.target_height)
-        } else {
-            position - Vector::new(0.0, node.size().height)
-        });
+        let mut list = Widget::<Message, Renderer>::layout(
+            &self.list,
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new((max_width - 2.0).max(0.0), f32::INFINITY),
+            ),
+        );
+
+        list.move_to(Point::new(1.0, 1.0));
+
+        let size = limits.resolve(Size::new(0.0, list.size().height + 2.0));
+        let mut node = layout::Node::with_children(size, vec![list]);
+
+        node.move_to(
+            if size.height <= space_below || space_below >= space_above {
+                position + Vector::new(0.0, self.target_height)
+            } else {
+                position - Vector::new(0.0, size.height)
+            },
+        );
 
         node
     }
@@ -224,7 +413,9 @@ where
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
-        self.container.hash_layout(state);
+        self.width.hash(state);
+        self.max_height.hash(state);
+        Widget::<Message, Renderer>::hash_layout(&self.list, state);
     }
 
     fn on_event(
@@ -236,9 +427,49 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
-        self.container.on_event(
-            event.clone(),
-            layout,
+        let list_layout = layout.children().next().unwrap();
+        let viewport = viewport(layout.bounds());
+        let content_bounds = list_layout.bounds();
+
+        let option_height = f32::from(
+            self.list.text_size.unwrap_or(renderer.default_size())
+                + self.list.padding * 2,
+        );
+
+        if let Some(index) = self.navigate(&event) {
+            *self.list.hovered_option = Some(index);
+
+            self.scroll_into_view(
+                index,
+                option_height,
+                viewport,
+                content_bounds,
+            );
+        }
+
+        let is_mouse_over = viewport.contains(cursor_position);
+        let is_mouse_over_scrollbar = self.scrollable.update(
+            &event,
+            viewport,
+            content_bounds,
+            cursor_position,
+            scrollable::Direction::Vertical,
+            option_height,
+            false,
+            renderer,
+        );
+
+        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+            let offset = self.scrollable.offset(viewport, content_bounds);
+
+            Point::new(cursor_position.x, cursor_position.y + offset.y as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        self.list.on_event(
+            event,
+            list_layout,
             cursor_position,
             messages,
             renderer,
@@ -253,16 +484,55 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
-        let primitives =
-            self.container
-                .draw(renderer, defaults, layout, cursor_position);
+        let bounds = layout.bounds();
+        let list_layout = layout.children().next().unwrap();
+        let viewport = viewport(bounds);
+        let content_bounds = list_layout.bounds();
+
+        let offset = self.scrollable.offset(viewport, content_bounds);
+        let scrollbars = renderer.scrollbars(
+            scrollable::Direction::Vertical,
+            viewport,
+            content_bounds,
+            offset,
+        );
 
-        renderer.decorate(
-            layout.bounds(),
-            cursor_position,
-            &self.style,
-            primitives,
-        )
+        let is_mouse_over = viewport.contains(cursor_position);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        let content = {
+            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+                Point::new(
+                    cursor_position.x,
+                    cursor_position.y + offset.y as f32,
+                )
+            } else {
+                Point::new(cursor_position.x, -1.0)
+            };
+
+            Widget::<Message, Renderer>::draw(
+                &self.list,
+                renderer,
+                defaults,
+                list_layout,
+                cursor_position,
+            )
+        };
+
+        let primitives = scrollable::Renderer::draw(
+            renderer,
+            self.scrollable,
+            viewport,
+            content_bounds,
+            is_mouse_over,
+            is_mouse_over_scrollbar,
+            scrollbars,
+            offset,
+            &Default::default(),
+            content,
+        );
+
+        renderer.decorate(bounds, cursor_position, &self.style, primitives)
     }
 }
 
@@ -270,6 +540,7 @@ struct List<'a, T, Renderer: self::Renderer> {
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    checked: Option<&'a [bool]>,
     padding: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
@@ -375,6 +646,7 @@ where
             cursor_position,
             self.options,
             *self.hovered_option,
+            self.checked,
             self.padding,
             self.text_size.unwrap_or(renderer.default_size()),
             self.font,
@@ -413,13 +685,18 @@ pub trait Renderer:
 
     /// Draws the list of options of a [`Menu`].
     ///
+    /// It receives whether each option is checked or not, if the [`Menu`]
+    /// shows checkmarks.
+    ///
     /// [`Menu`]: struct.Menu.html
+    #[allow(clippy::too_many_arguments)]
     fn draw<T: ToString>(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         options: &[T],
         hovered_option: Option<usize>,
+        checked: Option<&[bool]>,
         padding: u16,
         text_size: u16,
         font: Self::Font,
@@ -427,14 +704,153 @@ pub trait Renderer:
     ) -> Self::Output;
 }
 
-impl<'a, T, Message, Renderer> Into<Element<'a, Message, Renderer>>
-    for List<'a, T, Renderer>
-where
-    T: ToString + Clone,
-    Message: 'a,
-    Renderer: 'a + self::Renderer,
-{
-    fn into(self) -> Element<'a, Message, Renderer> {
-        Element::new(self)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    const OPTIONS: [&str; 5] =
+        ["Apple", "Avocado", "Banana", "Blueberry", "Cherry"];
+
+    fn key(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: Default::default(),
+        })
+    }
+
+    fn character(c: char) -> Event {
+        Event::Keyboard(keyboard::Event::CharacterReceived(c))
+    }
+
+    /// Sends the given events to a menu with the given option hovered,
+    /// returning the hovered and the selected option afterwards.
+    fn navigate(
+        state: &mut State,
+        hovered: Option<usize>,
+        events: &[Event],
+    ) -> (Option<usize>, Option<&'static str>) {
+        let mut hovered_option = hovered;
+        let mut last_selection = None;
+
+        {
+            let menu = Menu::<_, Null>::new(
+                state,
+                &OPTIONS,
+                &mut hovered_option,
+                &mut last_selection,
+            );
+
+            let mut overlay = Overlay::new(menu, 0.0);
+
+            for event in events {
+                if let Some(index) = overlay.navigate(event) {
+                    *overlay.list.hovered_option = Some(index);
+                }
+            }
+        }
+
+        (hovered_option, last_selection)
+    }
+
+    #[test]
+    fn arrows_move_within_the_options() {
+        let mut state = State::new();
+        let up = || key(keyboard::KeyCode::Up);
+        let down = || key(keyboard::KeyCode::Down);
+
+        assert_eq!(navigate(&mut state, None, &[down()]).0, Some(0));
+        assert_eq!(navigate(&mut state, None, &[up()]).0, Some(4));
+        assert_eq!(navigate(&mut state, Some(0), &[up()]).0, Some(0));
+        assert_eq!(navigate(&mut state, Some(3), &[down(), down()]).0, Some(4));
+    }
+
+    #[test]
+    fn home_and_end_jump_to_the_ends() {
+        let mut state = State::new();
+
+        assert_eq!(
+            navigate(&mut state, Some(2), &[key(keyboard::KeyCode::Home)]).0,
+            Some(0)
+        );
+        assert_eq!(
+            navigate(&mut state, Some(2), &[key(keyboard::KeyCode::End)]).0,
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn enter_selects_the_hovered_option() {
+        let mut state = State::new();
+        let enter = || key(keyboard::KeyCode::Enter);
+
+        assert_eq!(
+            navigate(&mut state, Some(2), &[enter()]),
+            (Some(2), Some("Banana"))
+        );
+        assert_eq!(navigate(&mut state, None, &[enter()]), (None, None));
+    }
+
+    #[test]
+    fn typing_accumulates_until_the_timeout() {
+        let mut state = State::new();
+
+        assert_eq!(navigate(&mut state, None, &[character('b')]).0, Some(2));
+        assert_eq!(navigate(&mut state, Some(2), &[character('l')]).0, Some(3));
+        assert_eq!(state.type_ahead, "bl");
+
+        // A single letter cycles through the options starting with it
+        state.last_typed = None;
+        assert_eq!(navigate(&mut state, Some(0), &[character('A')]).0, Some(1));
+
+        state.last_typed = Instant::now().checked_sub(TYPE_AHEAD_TIMEOUT);
+        assert_eq!(navigate(&mut state, Some(1), &[character('c')]).0, Some(4));
+        assert_eq!(state.type_ahead, "c");
+    }
+
+    #[test]
+    fn opens_upward_when_there_is_more_space_on_top() {
+        let mut state = State::new();
+        let mut hovered_option = None;
+        let mut last_selection = None;
+
+        let menu = Menu::<_, Null>::new(
+            &mut state,
+            &OPTIONS,
+            &mut hovered_option,
+            &mut last_selection,
+        )
+        .width(100);
+
+        let overlay = Overlay::new(menu, 20.0);
+
+        let layout = |bounds: Size, y: f32| {
+            crate::Overlay::<(), Null>::layout(
+                &overlay,
+                &Null,
+                bounds,
+                Point::new(10.0, y),
+            )
+            .bounds()
+        };
+
+        let bounds = Size::new(400.0, 300.0);
+        let size = Size::new(100.0, 102.0);
+
+        // Five options of 20 pixels, plus the border
+        assert_eq!(
+            layout(bounds, 50.0),
+            Rectangle::new(Point::new(10.0, 70.0), size)
+        );
+        assert_eq!(
+            layout(bounds, 250.0),
+            Rectangle::new(Point::new(10.0, 148.0), size)
+        );
+
+        // Menus that do not fit are limited by the largest space
+        assert_eq!(
+            layout(Size::new(400.0, 150.0), 80.0),
+            Rectangle::new(Point::new(10.0, 0.0), Size::new(100.0, 80.0))
+        );
     }
 }
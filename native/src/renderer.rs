@@ -65,4 +65,42 @@ pub trait Renderer: Sized {
         overlay: Self::Output,
         overlay_bounds: Rectangle,
     ) -> Self::Output;
+
+    /// Records the bounds of the caret of the focused text input, if any.
+    ///
+    /// Shells use these bounds to place the candidate window of an input
+    /// method editor (IME) next to the text being composed.
+    fn set_ime_caret(&mut self, _caret: Option<Rectangle>) {}
+
+    /// Returns the bounds of the caret of the focused text input, as recorded
+    /// during the last draw.
+    fn ime_caret(&self) -> Option<Rectangle> {
+        None
+    }
+
+    /// Records whether a widget needs the user interface to be redrawn, even
+    /// if no events happen.
+    ///
+    /// Animated widgets request redraws while drawing, and shells produce a
+    /// [`window::Event::RedrawRequested`] for the next frame.
+    ///
+    /// [`window::Event::RedrawRequested`]: ../window/enum.Event.html#variant.RedrawRequested
+    fn set_redraw_requested(&mut self, _is_requested: bool) {}
+
+    /// Returns whether a widget requested a redraw during the last draw.
+    fn is_redraw_requested(&self) -> bool {
+        false
+    }
+
+    /// Records the number of physical pixels per logical pixel of the window
+    /// that the user interface is drawn to.
+    ///
+    /// Shells set it before drawing, and widgets can use it to reason about
+    /// the physical size of their contents.
+    fn set_scale_factor(&mut self, _scale_factor: f64) {}
+
+    /// Returns the scale factor of the window, as recorded by the shell.
+    fn scale_factor(&self) -> f64 {
+        1.0
+    }
 }
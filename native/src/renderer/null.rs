@@ -1,8 +1,11 @@
+use crate::overlay::menu;
 use crate::{
-    button, checkbox, column, container, pane_grid, progress_bar, radio, row,
-    scrollable, slider, text, text_input, Color, Element, Font,
-    HorizontalAlignment, Layout, Point, Rectangle, Renderer, Size,
-    VerticalAlignment,
+    button, checkbox, color_picker, column, combo_box, container, date_picker,
+    multi_pick_list, number_input, pane_grid, progress_bar, radio,
+    range_slider, rich_text, row, scrollable, selectable_text, slider, table,
+    tabs, text, text_editor, text_input, time_picker, toggler, tree_view,
+    Color, Element, Font, HorizontalAlignment, Layout, Point, Rectangle,
+    Renderer, Size, Vector, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -67,6 +70,29 @@ impl text::Renderer for Null {
         (0.0, 20.0)
     }
 
+    fn hit_test(
+        &self,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _bounds: Size,
+        _point: Point,
+    ) -> Option<usize> {
+        None
+    }
+
+    fn selection_bounds(
+        &self,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _bounds: Size,
+        _start: usize,
+        _end: usize,
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
     fn draw(
         &mut self,
         _defaults: &Self::Defaults,
@@ -84,13 +110,14 @@ impl text::Renderer for Null {
 impl scrollable::Renderer for Null {
     type Style = ();
 
-    fn scrollbar(
+    fn scrollbars(
         &self,
+        _direction: scrollable::Direction,
         _bounds: Rectangle,
         _content_bounds: Rectangle,
-        _offset: u32,
-    ) -> Option<scrollable::Scrollbar> {
-        None
+        _offset: Vector<u32>,
+    ) -> scrollable::Scrollbars {
+        scrollable::Scrollbars::default()
     }
 
     fn draw(
@@ -100,14 +127,66 @@ impl scrollable::Renderer for Null {
         _content_bounds: Rectangle,
         _is_mouse_over: bool,
         _is_mouse_over_scrollbar: bool,
-        _scrollbar: Option<scrollable::Scrollbar>,
-        _offset: u32,
+        _scrollbars: scrollable::Scrollbars,
+        _offset: Vector<u32>,
         _style: &Self::Style,
         _content: Self::Output,
     ) {
     }
 }
 
+impl table::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw_rows<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _rows: &[Vec<Element<'_, Message, Self>>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _selection: &[usize],
+        _hovered_row: Option<usize>,
+        _style: &<Self as table::Renderer>::Style,
+    ) {
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _header_bounds: Rectangle,
+        _cursor_position: Point,
+        _headers: &[table::Header<'_>],
+        _resizing: Option<usize>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _body: Self::Output,
+        _style: &<Self as table::Renderer>::Style,
+    ) {
+    }
+}
+
+impl tabs::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(
+        &mut self,
+        _bar_bounds: Rectangle,
+        _tabs: &[tabs::Item<'_>],
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _icon_font: Font,
+        _content: Self::Output,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
 impl text_input::Renderer for Null {
     type Style = ();
 
@@ -141,6 +220,195 @@ impl text_input::Renderer for Null {
     }
 }
 
+impl text_editor::Renderer for Null {
+    type Style = ();
+
+    fn measure_value(&self, _value: &str, _size: u16, _font: Font) -> f32 {
+        0.0
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _text_bounds: Rectangle,
+        _cursor_position: Point,
+        _font: Font,
+        _size: u16,
+        _placeholder: Option<&str>,
+        _lines: &[text_editor::Line],
+        _selection: &[Rectangle],
+        _cursor: Option<Rectangle>,
+        _is_focused: bool,
+        _style: &<Self as text_editor::Renderer>::Style,
+    ) -> Self::Output {
+    }
+}
+
+impl selectable_text::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _color: Option<Color>,
+        _selection: &[Rectangle],
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl rich_text::Renderer for Null {
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _runs: &[rich_text::Run<Self::Font>],
+    ) {
+    }
+}
+
+impl menu::Renderer for Null {
+    type Style = ();
+
+    fn decorate(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &(),
+        _primitive: Self::Output,
+    ) {
+    }
+
+    fn draw<T: ToString>(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _options: &[T],
+        _hovered_option: Option<usize>,
+        _checked: Option<&[bool]>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl combo_box::Renderer for Null {
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(&mut self, _text_input: Self::Output) {}
+}
+
+impl color_picker::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _color: Color,
+        _padding: u16,
+        _style: &(),
+    ) {
+    }
+
+    fn draw_picker(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _controls: &color_picker::Controls,
+        _fields: Vec<()>,
+        _style: &(),
+    ) {
+    }
+}
+
+impl date_picker::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _value: Option<String>,
+        _placeholder: Option<&str>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+
+    fn draw_calendar(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _title: &str,
+        _navigation: &[date_picker::Cell],
+        _weekdays: &[date_picker::Cell],
+        _days: &[date_picker::Cell],
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl time_picker::Renderer for Null {
+    fn draw_clock(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _hours: &[date_picker::Cell],
+        _minutes: &[date_picker::Cell],
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl multi_pick_list::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_PADDING: u16 = 5;
+
+    fn menu_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _chips: &[multi_pick_list::Chip<'_>],
+        _placeholder: Option<&str>,
+        _padding: u16,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl number_input::Renderer for Null {
+    fn draw(
+        &mut self,
+        _text_input: Self::Output,
+        _increment: Self::Output,
+        _decrement: Self::Output,
+    ) {
+    }
+}
+
 impl button::Renderer for Null {
     const DEFAULT_PADDING: u16 = 0;
 
@@ -194,6 +462,22 @@ impl checkbox::Renderer for Null {
     }
 }
 
+impl toggler::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 20;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _is_active: bool,
+        _is_mouse_over: bool,
+        _label: Option<Self::Output>,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
 impl slider::Renderer for Null {
     type Style = ();
 
@@ -206,6 +490,25 @@ impl slider::Renderer for Null {
         _range: std::ops::RangeInclusive<f32>,
         _value: f32,
         _is_dragging: bool,
+        _orientation: slider::Orientation,
+        _style_sheet: &Self::Style,
+    ) {
+    }
+}
+
+impl range_slider::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_HEIGHT: u16 = 30;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _range: std::ops::RangeInclusive<f32>,
+        _values: (f32, f32),
+        _dragging: Option<range_slider::Bound>,
+        _orientation: slider::Orientation,
         _style_sheet: &Self::Style,
     ) {
     }
@@ -281,3 +584,21 @@ impl pane_grid::Renderer for Null {
     ) {
     }
 }
+
+impl tree_view::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_INDENT: u16 = 20;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _items: &[tree_view::Item],
+        _contents: Vec<Self::Output>,
+        _indent: u16,
+        _is_focused: bool,
+        _style: &Self::Style,
+    ) {
+    }
+}
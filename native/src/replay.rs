@@ -0,0 +1,176 @@
+//! Record the messages of a program and replay them later.
+//!
+//! Since every state change of a [`Program`] goes through its `update` logic,
+//! the stream of messages it receives is enough to reproduce a session. A
+//! [`Recording`] stores that stream, together with the runtime events that
+//! produced it, and can be persisted to a file as [JSON Lines].
+//!
+//! [`Program`]: ../program/trait.Program.html
+//! [`Recording`]: struct.Recording.html
+//! [JSON Lines]: https://jsonlines.org
+use crate::Event;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A timestamped list of records of a program session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording<Message> {
+    entries: Vec<Entry<Message>>,
+}
+
+/// A single [`Record`] of a [`Recording`], with its timestamp.
+///
+/// [`Record`]: enum.Record.html
+/// [`Recording`]: struct.Recording.html
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry<Message> {
+    /// The time elapsed since the start of the [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub timestamp: Duration,
+
+    /// The recorded [`Record`].
+    ///
+    /// [`Record`]: enum.Record.html
+    pub record: Record<Message>,
+}
+
+/// Something that happened during a program session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Record<Message> {
+    /// A message was handled by the program.
+    Message(Message),
+
+    /// A runtime event was received.
+    Event(Event),
+}
+
+impl<Message> Recording<Message> {
+    /// Creates an empty [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Adds an [`Entry`] at the end of the [`Recording`].
+    ///
+    /// [`Entry`]: struct.Entry.html
+    /// [`Recording`]: struct.Recording.html
+    pub fn push(&mut self, entry: Entry<Message>) {
+        self.entries.push(entry);
+    }
+
+    /// Returns the entries of the [`Recording`], in chronological order.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn entries(&self) -> &[Entry<Message>] {
+        &self.entries
+    }
+
+    /// Returns an iterator over the recorded messages, skipping any event.
+    pub fn messages(&self) -> impl Iterator<Item = &Message> {
+        self.entries.iter().filter_map(|entry| match &entry.record {
+            Record::Message(message) => Some(message),
+            Record::Event(_) => None,
+        })
+    }
+
+    /// Returns the total duration of the [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn duration(&self) -> Duration {
+        self.entries
+            .last()
+            .map(|entry| entry.timestamp)
+            .unwrap_or_default()
+    }
+}
+
+impl<Message> Recording<Message>
+where
+    Message: DeserializeOwned,
+{
+    /// Loads a [`Recording`] from a file previously written by a [`Writer`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    /// [`Writer`]: struct.Writer.html
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = io::BufReader::new(fs::File::open(path)?);
+        let mut recording = Recording::new();
+
+        for line in file.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            recording.push(serde_json::from_str(&line)?);
+        }
+
+        Ok(recording)
+    }
+}
+
+impl<Message> Default for Recording<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes the records of a program session to a file as they happen.
+///
+/// Every [`Entry`] is flushed immediately, so a session that ends in a crash
+/// can still be replayed up to the last message.
+///
+/// [`Entry`]: struct.Entry.html
+#[derive(Debug)]
+pub struct Writer {
+    start: Instant,
+    file: io::BufWriter<fs::File>,
+}
+
+impl Writer {
+    /// Creates a new [`Writer`] that will record to the file at the given
+    /// path, truncating it if it already exists.
+    ///
+    /// [`Writer`]: struct.Writer.html
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            start: Instant::now(),
+            file: io::BufWriter::new(fs::File::create(path)?),
+        })
+    }
+
+    /// Records a [`Record`], timestamping it with the time elapsed since the
+    /// [`Writer`] was created.
+    ///
+    /// [`Record`]: enum.Record.html
+    /// [`Writer`]: struct.Writer.html
+    pub fn write<Message: Serialize>(
+        &mut self,
+        record: &Record<Message>,
+    ) -> io::Result<()> {
+        #[derive(Serialize)]
+        struct EntryRef<'a, Message> {
+            timestamp: Duration,
+            record: &'a Record<Message>,
+        }
+
+        let entry = EntryRef {
+            timestamp: self.start.elapsed(),
+            record,
+        };
+
+        serde_json::to_writer(&mut self.file, &entry)?;
+        self.file.write_all(b"\n")?;
+        self.file.flush()
+    }
+}
@@ -327,6 +327,9 @@ where
         renderer: &mut Renderer,
         cursor_position: Point,
     ) -> Renderer::Output {
+        renderer.set_ime_caret(None);
+        renderer.set_redraw_requested(false);
+
         let overlay = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout))
         {
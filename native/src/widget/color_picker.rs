@@ -0,0 +1,1091 @@
+//! Pick a color with a saturation and value area, sliders and text inputs.
+use crate::{
+    keyboard, layout, mouse, overlay, text, text_input, Clipboard, Color,
+    Element, Event, Hasher, HorizontalAlignment, Layout, Length, Point,
+    Rectangle, Size, TextInput, VerticalAlignment, Widget,
+};
+
+/// The amount of recently picked colors kept by a [`ColorPicker`].
+///
+/// [`ColorPicker`]: struct.ColorPicker.html
+pub const RECENT_LIMIT: usize = 8;
+
+/// A swatch that opens an overlay to pick a [`Color`].
+///
+/// The overlay has an area to pick the saturation and value of the color,
+/// sliders for its hue and alpha, text inputs for its hexadecimal, RGB and
+/// HSL values, and the swatches of the colors picked recently.
+///
+/// [`Color`]: ../../struct.Color.html
+///
+/// # Example
+/// ```
+/// # use iced_native::{color_picker, renderer::Null, Color};
+/// #
+/// # pub type ColorPicker<'a, Message> =
+/// #     iced_native::ColorPicker<'a, Message, Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     ColorPicked(Color),
+/// }
+///
+/// let mut state = color_picker::State::new();
+/// let color = Color::from_rgb(0.2, 0.4, 0.8);
+///
+/// let color_picker = ColorPicker::new(&mut state, color, Message::ColorPicked);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> ColorPicker<'a, Message, Renderer> {
+    /// Creates a new [`ColorPicker`] with the given [`State`], the current
+    /// [`Color`], and the message to produce when the [`Color`] changes.
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    /// [`State`]: struct.State.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn new<F>(state: &'a mut State, color: Color, on_change: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        ColorPicker {
+            state,
+            color,
+            on_change: Box::new(on_change),
+            width: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the swatch of the [`ColorPicker`].
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`ColorPicker`].
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`ColorPicker`].
+    ///
+    /// The size of its overlay is relative to it.
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`ColorPicker`].
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`ColorPicker`].
+///
+/// [`ColorPicker`]: struct.ColorPicker.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_open: bool,
+    hsva: Hsva,
+    opened_with: Option<Color>,
+    dragging: Option<Area>,
+    inputs: [text_input::State; 7],
+    values: [String; 7],
+    recent: Vec<Color>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a closed [`ColorPicker`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the overlay of the [`ColorPicker`] is open.
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Returns the colors picked recently, the latest first.
+    pub fn recent(&self) -> &[Color] {
+        &self.recent
+    }
+
+    fn open(&mut self, color: Color) {
+        self.is_open = true;
+        self.hsva = Hsva::from_color(color, self.hsva);
+        self.opened_with = Some(color);
+        self.dragging = None;
+        self.inputs = Default::default();
+        self.refresh(None);
+    }
+
+    /// Closes the overlay, remembering the picked color if it changed.
+    fn close(&mut self) {
+        let color = self.hsva.into_color();
+
+        if self.is_open && self.opened_with != Some(color) {
+            self.recent.retain(|recent| *recent != color);
+            self.recent.insert(0, color);
+            self.recent.truncate(RECENT_LIMIT);
+        }
+
+        self.is_open = false;
+        self.dragging = None;
+    }
+
+    /// Updates the values of the text inputs, except the one being edited.
+    fn refresh(&mut self, edited: Option<Input>) {
+        let color = self.hsva.into_color();
+        let (saturation, lightness) = hsl(color);
+
+        for input in Input::ALL.iter().copied() {
+            if Some(input) == edited {
+                continue;
+            }
+
+            self.values[input as usize] = match input {
+                Input::Hex => hex(color),
+                Input::Red => channel(color.r),
+                Input::Green => channel(color.g),
+                Input::Blue => channel(color.b),
+                Input::Hue => format!("{}", self.hsva.hue.round()),
+                Input::Saturation => {
+                    format!("{}", (saturation * 100.0).round())
+                }
+                Input::Lightness => format!("{}", (lightness * 100.0).round()),
+            };
+        }
+    }
+
+    /// Applies the value of a text input, if it is valid.
+    fn edit(&mut self, input: Input, value: String) -> bool {
+        let color = self.hsva.into_color();
+        let (saturation, lightness) = hsl(color);
+
+        let parsed = match input {
+            Input::Hex => parse_hex(&value),
+            Input::Red | Input::Green | Input::Blue => {
+                value.trim().parse::<u8>().ok().map(|channel| {
+                    let channel = f32::from(channel) / 255.0;
+
+                    match input {
+                        Input::Red => Color {
+                            r: channel,
+                            ..color
+                        },
+                        Input::Green => Color {
+                            g: channel,
+                            ..color
+                        },
+                        _ => Color {
+                            b: channel,
+                            ..color
+                        },
+                    }
+                })
+            }
+            Input::Hue => parse_within(&value, 360.0).map(|hue| {
+                // Grays have no hue, so it must be kept as typed
+                self.hsva.hue = hue;
+                self.hsva.into_color()
+            }),
+            Input::Saturation => parse_within(&value, 100.0).map(|percent| {
+                from_hsl(self.hsva.hue, percent / 100.0, lightness, color.a)
+            }),
+            Input::Lightness => parse_within(&value, 100.0).map(|percent| {
+                from_hsl(self.hsva.hue, saturation, percent / 100.0, color.a)
+            }),
+        };
+
+        self.values[input as usize] = value;
+
+        match parsed {
+            Some(color) => {
+                self.hsva = Hsva::from_color(color, self.hsva);
+                self.refresh(Some(input));
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// A color in terms of hue, saturation, value and alpha.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsva {
+    /// The hue, in degrees from 0 to 360.
+    pub hue: f32,
+
+    /// The saturation, from 0 to 1.
+    pub saturation: f32,
+
+    /// The value, from 0 to 1.
+    pub value: f32,
+
+    /// The alpha channel, from 0 to 1.
+    pub alpha: f32,
+}
+
+impl Hsva {
+    /// Converts a [`Color`] into an [`Hsva`].
+    ///
+    /// Grays have no hue, and black has no saturation either, so they are
+    /// taken from the given [`Hsva`].
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Hsva`]: struct.Hsva.html
+    pub fn from_color(color: Color, previous: Hsva) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let hue = if delta <= f32::EPSILON {
+            previous.hue
+        } else if max == color.r {
+            60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+
+        Hsva {
+            hue,
+            saturation: if max <= f32::EPSILON {
+                previous.saturation
+            } else {
+                delta / max
+            },
+            value: max,
+            alpha: color.a,
+        }
+    }
+
+    /// Converts the [`Hsva`] into a [`Color`].
+    ///
+    /// [`Hsva`]: struct.Hsva.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn into_color(self) -> Color {
+        let chroma = self.value * self.saturation;
+
+        from_chroma(self.hue, chroma, self.value - chroma, self.alpha)
+    }
+}
+
+/// Builds a color from its hue, chroma and the amount added to every channel.
+fn from_chroma(hue: f32, chroma: f32, offset: f32, alpha: f32) -> Color {
+    let sector = (hue / 60.0).rem_euclid(6.0);
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color::from_rgba(r + offset, g + offset, b + offset, alpha)
+}
+
+fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Color {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+    from_chroma(hue, chroma, lightness - chroma / 2.0, alpha)
+}
+
+/// Returns the saturation and lightness of a color.
+fn hsl(color: Color) -> (f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let lightness = (max + min) / 2.0;
+
+    let saturation = if max - min <= f32::EPSILON {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+
+    (saturation, lightness)
+}
+
+fn channel(value: f32) -> String {
+    format!("{}", (value * 255.0).round() as u8)
+}
+
+/// Formats a color as `#rrggbb`, or `#rrggbbaa` if it is translucent.
+fn hex(color: Color) -> String {
+    let byte = |value: f32| (value * 255.0).round() as u8;
+
+    if color.a < 1.0 {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            byte(color.r),
+            byte(color.g),
+            byte(color.b),
+            byte(color.a)
+        )
+    } else {
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            byte(color.r),
+            byte(color.g),
+            byte(color.b)
+        )
+    }
+}
+
+/// Parses a color written as `#rrggbb` or `#rrggbbaa`.
+fn parse_hex(value: &str) -> Option<Color> {
+    let digits = value.trim().trim_start_matches('#');
+
+    if !(digits.len() == 6 || digits.len() == 8) || !digits.is_ascii() {
+        return None;
+    }
+
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+
+    let alpha = bytes.get(3).map_or(1.0, |alpha| f32::from(*alpha) / 255.0);
+
+    Some(Color::from_rgba8(bytes[0], bytes[1], bytes[2], alpha))
+}
+
+fn parse_within(value: &str, max: f32) -> Option<f32> {
+    value
+        .trim()
+        .trim_end_matches(&['%', '°'][..])
+        .parse::<f32>()
+        .ok()
+        .filter(|number| (0.0..=max).contains(number))
+}
+
+/// A text input of a [`ColorPicker`].
+///
+/// [`ColorPicker`]: struct.ColorPicker.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Input {
+    Hex,
+    Red,
+    Green,
+    Blue,
+    Hue,
+    Saturation,
+    Lightness,
+}
+
+impl Input {
+    const ALL: [Input; 7] = [
+        Input::Hex,
+        Input::Red,
+        Input::Green,
+        Input::Blue,
+        Input::Hue,
+        Input::Saturation,
+        Input::Lightness,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Input::Hex => "Hex",
+            Input::Red => "R",
+            Input::Green => "G",
+            Input::Blue => "B",
+            Input::Hue => "H",
+            Input::Saturation => "S",
+            Input::Lightness => "L",
+        }
+    }
+}
+
+/// A draggable area of a [`ColorPicker`].
+///
+/// [`ColorPicker`]: struct.ColorPicker.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Area {
+    SaturationValue,
+    Hue,
+    Alpha,
+}
+
+/// The controls of the overlay of a [`ColorPicker`], as they are drawn.
+///
+/// [`ColorPicker`]: struct.ColorPicker.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Controls {
+    /// The current color.
+    pub hsva: Hsva,
+
+    /// The bounds of the area picking the saturation and the value.
+    pub saturation_value: Rectangle,
+
+    /// The bounds of the hue slider.
+    pub hue: Rectangle,
+
+    /// The bounds of the alpha slider.
+    pub alpha: Rectangle,
+
+    /// The bounds and colors of the swatches of the recent colors.
+    pub swatches: Vec<(Rectangle, Color)>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ColorPicker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + 'a,
+    Message: 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let text_size =
+            f32::from(self.text_size.unwrap_or(renderer.default_size()));
+
+        let limits =
+            limits.width(self.width).height(Length::Shrink).pad(padding);
+        let size = limits.resolve(Size::new(text_size * 2.0, text_size));
+
+        layout::Node::new(size.pad(padding))
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if self.state.is_open {
+                    // TODO: Encode cursor availability in the type system
+                    if cursor_position.x >= 0.0 && cursor_position.y >= 0.0 {
+                        self.state.close();
+                    }
+                } else if layout.bounds().contains(cursor_position) {
+                    self.state.open(self.color);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.close();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.color,
+            self.padding,
+            &self.style,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(Picker {
+                state: self.state,
+                on_change: &self.on_change,
+                target_height: layout.bounds().height,
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+}
+
+struct Picker<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: &'a dyn Fn(Color) -> Message,
+    target_height: f32,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+/// The index of the first label in the layout of a [`Picker`].
+const LABELS: usize = 3;
+
+/// The index of the first text input in the layout of a [`Picker`].
+const INPUTS: usize = LABELS + Input::ALL.len();
+
+/// The index of the first swatch in the layout of a [`Picker`].
+const SWATCHES: usize = INPUTS + Input::ALL.len();
+
+impl<'a, Message, Renderer: self::Renderer> Picker<'a, Message, Renderer> {
+    fn inputs(&mut self) -> Vec<TextInput<'_, (Input, String), Renderer>> {
+        let text_size = self.text_size;
+        let padding = self.padding / 2;
+        let font = self.font;
+
+        self.state
+            .inputs
+            .iter_mut()
+            .zip(self.state.values.iter())
+            .zip(Input::ALL.iter().copied())
+            .map(|((state, value), input)| {
+                let mut text_input =
+                    TextInput::new(state, "", value, move |value| {
+                        (input, value)
+                    })
+                    .padding(padding)
+                    .font(font);
+
+                if let Some(text_size) = text_size {
+                    text_input = text_input.size(text_size);
+                }
+
+                text_input
+            })
+            .collect()
+    }
+
+    fn drag(&mut self, area: Area, bounds: Rectangle, cursor_position: Point) {
+        let x = ((cursor_position.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+        let y =
+            ((cursor_position.y - bounds.y) / bounds.height).clamp(0.0, 1.0);
+
+        let hsva = &mut self.state.hsva;
+
+        match area {
+            Area::SaturationValue => {
+                hsva.saturation = x;
+                hsva.value = 1.0 - y;
+            }
+            Area::Hue => hsva.hue = x * 360.0,
+            Area::Alpha => hsva.alpha = x,
+        }
+
+        self.state.refresh(None);
+    }
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Picker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let padding = f32::from(self.padding);
+        let width = f32::from(text_size) * 10.0;
+        let bar_height = (f32::from(text_size) * 0.6).round();
+
+        let mut children = Vec::new();
+        let mut y = padding;
+
+        let mut push = |size: Size, x: f32, y: f32| {
+            let mut node = layout::Node::new(size);
+            node.move_to(Point::new(padding + x, y));
+            children.push(node);
+        };
+
+        let area_height = (width * 0.75).round();
+        push(Size::new(width, area_height), 0.0, y);
+        y += area_height + padding;
+
+        push(Size::new(width, bar_height), 0.0, y);
+        y += bar_height + padding;
+
+        push(Size::new(width, bar_height), 0.0, y);
+        y += bar_height + padding;
+
+        // The hexadecimal input takes a row, and the RGB and HSL inputs share
+        // the next two rows
+        let measure = |label: &str| {
+            let (width, _) = renderer.measure(
+                label,
+                text_size,
+                self.font,
+                Size::new(f32::INFINITY, f32::INFINITY),
+            );
+
+            width.round() + padding
+        };
+
+        let input_height = f32::from(text_size + (self.padding / 2) * 2);
+        let column_width = (width - padding * 2.0) / 3.0;
+
+        let slots: Vec<(f32, f32, f32)> = Input::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                let label_width = measure(input.label());
+
+                match i {
+                    0 => (0.0, y, label_width),
+                    _ => {
+                        let row = (i - 1) / 3;
+                        let column = (i - 1) % 3;
+
+                        (
+                            column as f32 * (column_width + padding),
+                            y + (row + 1) as f32 * (input_height + padding),
+                            label_width,
+                        )
+                    }
+                }
+            })
+            .collect();
+
+        for (x, y, label_width) in slots.iter().copied() {
+            push(Size::new(label_width, input_height), x, y);
+        }
+
+        let input_nodes: Vec<layout::Node> = slots
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(i, (x, y, label_width))| {
+                let input_width = if i == 0 {
+                    width - label_width
+                } else {
+                    column_width - label_width
+                };
+
+                // A text input expects the bounds of its text as a child
+                let input_padding = f32::from(self.padding / 2);
+                let mut text = layout::Node::new(Size::new(
+                    (input_width - input_padding * 2.0).max(0.0),
+                    f32::from(text_size),
+                ));
+                text.move_to(Point::new(input_padding, input_padding));
+
+                let mut node = layout::Node::with_children(
+                    Size::new(input_width.max(0.0), input_height),
+                    vec![text],
+                );
+                node.move_to(Point::new(padding + x + label_width, y));
+                node
+            })
+            .collect();
+
+        children.extend(input_nodes);
+
+        y += (input_height + padding) * 3.0;
+
+        let spacing = padding;
+        let swatch_width =
+            (width - spacing * (RECENT_LIMIT - 1) as f32) / RECENT_LIMIT as f32;
+
+        for i in 0..self.state.recent.len() {
+            let mut node =
+                layout::Node::new(Size::new(swatch_width, bar_height * 2.0));
+            node.move_to(Point::new(
+                padding + i as f32 * (swatch_width + spacing),
+                y,
+            ));
+            children.push(node);
+        }
+
+        if !self.state.recent.is_empty() {
+            y += bar_height * 2.0 + padding;
+        }
+
+        let size = Size::new(width + padding * 2.0, y);
+        let mut node = layout::Node::with_children(size, children);
+
+        node.move_to(crate::date_picker::popup_position(
+            bounds,
+            position,
+            self.target_height,
+            size,
+        ));
+
+        node
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.target_height.to_bits().hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.state.recent.len().hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let children: Vec<Layout<'_>> = layout.children().collect();
+        let areas = [
+            (Area::SaturationValue, children[0].bounds()),
+            (Area::Hue, children[1].bounds()),
+            (Area::Alpha, children[2].bounds()),
+        ];
+
+        let before = self.state.hsva;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some((area, bounds)) = areas
+                    .iter()
+                    .find(|(_, bounds)| bounds.contains(cursor_position))
+                {
+                    self.state.dragging = Some(*area);
+                    self.drag(*area, *bounds, cursor_position);
+                } else if let Some(color) = children[SWATCHES..]
+                    .iter()
+                    .zip(self.state.recent.iter())
+                    .find(|(swatch, _)| {
+                        swatch.bounds().contains(cursor_position)
+                    })
+                    .map(|(_, color)| *color)
+                {
+                    self.state.hsva = Hsva::from_color(color, self.state.hsva);
+                    self.state.refresh(None);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(area) = self.state.dragging {
+                    let bounds = areas
+                        .iter()
+                        .find(|(candidate, _)| *candidate == area)
+                        .map(|(_, bounds)| *bounds)
+                        .unwrap_or(children[0].bounds());
+
+                    self.drag(area, bounds, cursor_position);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.dragging = None;
+            }
+            _ => {}
+        }
+
+        let mut edits = Vec::new();
+
+        for (mut text_input, layout) in
+            self.inputs().into_iter().zip(&children[INPUTS..SWATCHES])
+        {
+            text_input.on_event(
+                event.clone(),
+                *layout,
+                cursor_position,
+                &mut edits,
+                renderer,
+                clipboard,
+            );
+        }
+
+        for (input, value) in edits {
+            let _ = self.state.edit(input, value);
+        }
+
+        if self.state.hsva != before {
+            messages.push((self.on_change)(self.state.hsva.into_color()));
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let children: Vec<Layout<'_>> = layout.children().collect();
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let mut fields: Vec<Renderer::Output> = Input::ALL
+            .iter()
+            .zip(&children[LABELS..INPUTS])
+            .map(|(input, layout)| {
+                text::Renderer::draw(
+                    renderer,
+                    defaults,
+                    layout.bounds(),
+                    input.label(),
+                    text_size,
+                    self.font,
+                    None,
+                    HorizontalAlignment::Left,
+                    VerticalAlignment::Center,
+                )
+            })
+            .collect();
+
+        for ((state, value), layout) in self
+            .state
+            .inputs
+            .iter()
+            .zip(self.state.values.iter())
+            .zip(&children[INPUTS..SWATCHES])
+        {
+            let mut state = state.clone();
+            let mut text_input = TextInput::<'_, (), Renderer>::new(
+                &mut state,
+                "",
+                value,
+                |_| (),
+            )
+            .padding(self.padding / 2)
+            .font(self.font);
+
+            if let Some(text_size) = self.text_size {
+                text_input = text_input.size(text_size);
+            }
+
+            fields.push(text_input.draw(
+                renderer,
+                defaults,
+                *layout,
+                cursor_position,
+            ));
+        }
+
+        let controls = Controls {
+            hsva: self.state.hsva,
+            saturation_value: children[0].bounds(),
+            hue: children[1].bounds(),
+            alpha: children[2].bounds(),
+            swatches: children[SWATCHES..]
+                .iter()
+                .map(Layout::bounds)
+                .zip(self.state.recent.iter().copied())
+                .collect(),
+        };
+
+        renderer.draw_picker(
+            layout.bounds(),
+            cursor_position,
+            &controls,
+            fields,
+            self.style,
+        )
+    }
+}
+
+/// The renderer of a [`ColorPicker`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ColorPicker`] in your user interface.
+///
+/// [`ColorPicker`]: struct.ColorPicker.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text_input::Renderer {
+    /// The default padding of a [`ColorPicker`].
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the swatch of a [`ColorPicker`].
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        color: Color,
+        padding: u16,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+
+    /// Draws the overlay of a [`ColorPicker`].
+    ///
+    /// It receives the [`Controls`] to draw, and the labels and text inputs
+    /// of the overlay, already drawn.
+    ///
+    /// [`ColorPicker`]: struct.ColorPicker.html
+    /// [`Controls`]: struct.Controls.html
+    fn draw_picker(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        controls: &Controls,
+        fields: Vec<Self::Output>,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ColorPicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        color_picker: ColorPicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(color_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsva_round_trips_colors() {
+        let color = Color::from_rgba8(51, 102, 204, 0.5);
+        let hsva = Hsva::from_color(color, Hsva::default());
+
+        assert!((hsva.hue - 220.0).abs() < 0.01);
+        assert_eq!(hex(hsva.into_color()), "#3366cc80");
+        assert_eq!(parse_hex("#3366cc"), Some(Color::from_rgb8(51, 102, 204)));
+        assert_eq!(parse_hex("3366c"), None);
+    }
+
+    #[test]
+    fn text_inputs_keep_the_hue_of_grays() {
+        let mut state = State::new();
+        state.open(Color::from_rgb8(51, 204, 51));
+
+        assert!(state.edit(Input::Saturation, String::from("0")));
+        assert_eq!(state.values[Input::Hex as usize], "#808080");
+        assert_eq!(state.values[Input::Hue as usize], "120");
+
+        assert!(state.edit(Input::Hue, String::from("240")));
+        assert!(!state.edit(Input::Red, String::from("300")));
+        assert_eq!(state.values[Input::Red as usize], "300");
+        assert_eq!(state.hsva.hue, 240.0);
+    }
+
+    #[test]
+    fn dragging_the_area_changes_saturation_and_value() {
+        use crate::{renderer::Null, Overlay as _};
+
+        let mut state = State::new();
+        state.open(Color::from_rgb(1.0, 0.0, 0.0));
+
+        let on_change = |color: Color| color;
+        let mut picker: Picker<'_, Color, Null> = Picker {
+            state: &mut state,
+            on_change: &on_change,
+            target_height: 30.0,
+            padding: 5,
+            text_size: None,
+            font: Default::default(),
+            style: &(),
+        };
+
+        let renderer = Null::new();
+        let node =
+            picker.layout(&renderer, Size::new(800.0, 600.0), Point::ORIGIN);
+        let area = Layout::new(&node).children().next().unwrap().bounds();
+
+        let mut messages = Vec::new();
+        picker.on_event(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            Point::new(area.x + area.width / 2.0, area.y + area.height),
+            &mut messages,
+            &renderer,
+            None,
+        );
+
+        assert_eq!(messages, vec![Color::from_rgb(0.0, 0.0, 0.0)]);
+        assert_eq!(picker.state.values[Input::Hex as usize], "#000000");
+        assert_eq!(picker.state.hsva.saturation, 0.5);
+    }
+}
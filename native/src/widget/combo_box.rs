@@ -0,0 +1,646 @@
+//! Let your users search and pick a value from a list of options.
+//!
+//! A [`ComboBox`] has some local [`State`].
+//!
+//! [`ComboBox`]: struct.ComboBox.html
+//! [`State`]: struct.State.html
+use crate::{
+    keyboard, layout, mouse, overlay,
+    overlay::menu::{self, Menu},
+    text_input, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    TextInput, Widget,
+};
+
+/// A field that filters a list of options as the user types in it.
+///
+/// Once focused, a [`ComboBox`] shows the options matching the typed text in
+/// a [`Menu`]. The options can be browsed with the arrow keys and picked with
+/// `Enter` or a click, while `Escape` dismisses them.
+///
+/// # Example
+/// ```
+/// # use iced_native::{combo_box, renderer::Null};
+/// #
+/// # pub type ComboBox<'a, T, Message> =
+/// #     iced_native::ComboBox<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CountrySelected(String),
+/// }
+///
+/// let mut state = combo_box::State::new();
+/// let countries = vec![String::from("Finland"), String::from("France")];
+///
+/// let combo_box = ComboBox::new(
+///     &mut state,
+///     "Type a country...",
+///     &countries,
+///     None,
+///     Message::CountrySelected,
+/// )
+/// .filter(combo_box::Filter::Fuzzy);
+/// ```
+///
+/// [`ComboBox`]: struct.ComboBox.html
+/// [`Menu`]: ../../overlay/menu/struct.Menu.html
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer: self::Renderer> {
+    menu: &'a mut menu::State,
+    is_open: &'a mut bool,
+    query: &'a mut Option<String>,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<T>,
+    options: &'a [T],
+    filtered: Vec<T>,
+    selected: Option<T>,
+    filter: Filter,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    on_custom: Option<Box<dyn Fn(String) -> Message>>,
+    text_input: TextInput<'a, String, Renderer>,
+    padding: u16,
+    text_size: Option<u16>,
+    menu_max_height: Option<u32>,
+    font: Renderer::Font,
+    menu_style: <Renderer as menu::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`ComboBox`].
+    ///
+    /// It expects:
+    /// - some local [`State`]
+    /// - a placeholder
+    /// - the list of options
+    /// - the current selected value, if any
+    /// - a function that produces a message when an option is selected
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State<T>,
+        placeholder: &str,
+        options: &'a [T],
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        let State {
+            text_input,
+            menu,
+            is_open,
+            query,
+            hovered_option,
+            last_selection,
+        } = state;
+
+        // Show the typed text while searching, and the selection otherwise
+        let content = match query {
+            Some(query) => query.clone(),
+            None => selected
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        };
+
+        ComboBox {
+            menu,
+            is_open,
+            query,
+            hovered_option,
+            last_selection,
+            options,
+            filtered: Vec::new(),
+            selected,
+            filter: Filter::Substring,
+            on_selected: Box::new(on_selected),
+            on_custom: None,
+            text_input: TextInput::new(
+                text_input,
+                placeholder,
+                &content,
+                std::convert::identity,
+            )
+            .padding(Renderer::DEFAULT_PADDING),
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            menu_max_height: None,
+            font: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Filter`] used to match the options of the [`ComboBox`]
+    /// against the typed text.
+    ///
+    /// [`Filter`]: enum.Filter.html
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Allows values that are not in the list of options to be entered in
+    /// the [`ComboBox`].
+    ///
+    /// The given function will be called with the typed text when `Enter` is
+    /// pressed without a highlighted option, or when the [`ComboBox`] loses
+    /// focus while some text has been typed.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn on_custom<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        self.on_custom = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the padding of the field and the options of the [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the maximum height of the menu of the [`ComboBox`].
+    ///
+    /// The options that do not fit can be scrolled.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn menu_max_height(mut self, max_height: u32) -> Self {
+        self.menu_max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the field of the [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the [`Menu`] of the [`ComboBox`].
+    ///
+    /// [`Menu`]: ../../overlay/menu/struct.Menu.html
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer as menu::Renderer>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+
+    /// Returns the options matching the current query, best matches first.
+    fn filtered(&self) -> Vec<T> {
+        match self.query.as_deref() {
+            Some(query) => self.filter.apply(self.options, query),
+            None => self.options.to_vec(),
+        }
+    }
+
+    fn close(&mut self) {
+        *self.is_open = false;
+        *self.query = None;
+        *self.hovered_option = None;
+    }
+}
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + PartialEq,
+    Renderer: self::Renderer,
+{
+    fn open(&mut self) {
+        let selected = self.selected.as_ref();
+
+        *self.is_open = true;
+        *self.query = None;
+        *self.hovered_option = self
+            .options
+            .iter()
+            .position(|option| Some(option) == selected);
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + PartialEq,
+    Message: 'static,
+    Renderer: self::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        Widget::<String, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.text_input.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let was_focused = self.text_input.state().is_focused();
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code, ..
+        }) = event
+        {
+            match key_code {
+                keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                    if was_focused =>
+                {
+                    // Once open, the menu highlights the options by itself
+                    if !*self.is_open {
+                        self.open();
+                    }
+
+                    return;
+                }
+                keyboard::KeyCode::Enter if was_focused => {
+                    // The menu picks the highlighted option, if any
+                    let selection = self.last_selection.take();
+
+                    match (selection, &self.on_custom, &*self.query) {
+                        (Some(option), _, _) => {
+                            messages.push((self.on_selected)(option));
+                        }
+                        (None, Some(on_custom), Some(query))
+                            if !query.is_empty() =>
+                        {
+                            messages.push(on_custom(query.clone()));
+                        }
+                        _ => {}
+                    }
+
+                    self.close();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let is_escape = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            })
+        );
+
+        let is_clicked = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        ) && layout.bounds().contains(cursor_position);
+
+        let mut changes = Vec::new();
+
+        self.text_input.on_event(
+            event,
+            layout,
+            cursor_position,
+            &mut changes,
+            renderer,
+            clipboard,
+        );
+
+        for text in changes {
+            let is_empty = text.is_empty();
+
+            *self.query = Some(text);
+            *self.is_open = true;
+            *self.hovered_option = if is_empty || self.filtered().is_empty() {
+                None
+            } else {
+                Some(0)
+            };
+        }
+
+        let is_focused = self.text_input.state().is_focused();
+
+        if let Some(selection) = self.last_selection.take() {
+            messages.push((self.on_selected)(selection));
+
+            self.close();
+        } else if is_clicked && is_focused && !*self.is_open {
+            self.open();
+
+            if !was_focused {
+                self.text_input.state_mut().select_all();
+            }
+        } else if was_focused && !is_focused {
+            if let (Some(on_custom), Some(query)) =
+                (&self.on_custom, &*self.query)
+            {
+                if !is_escape && !query.is_empty() {
+                    messages.push(on_custom(query.clone()));
+                }
+            }
+
+            self.close();
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let text_input =
+            self.text_input
+                .draw(renderer, defaults, layout, cursor_position);
+
+        self::Renderer::draw(renderer, text_input)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::{any::TypeId, hash::Hash};
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.text_input.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !*self.is_open {
+            return None;
+        }
+
+        self.filtered = self.filtered();
+
+        if self.filtered.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu = Menu::new(
+            self.menu,
+            &self.filtered,
+            self.hovered_option,
+            self.last_selection,
+        )
+        .width(bounds.width.round() as u16)
+        .padding(self.padding)
+        .font(self.font)
+        .type_ahead(false)
+        .style(self.menu_style.clone());
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        if let Some(max_height) = self.menu_max_height {
+            menu = menu.max_height(max_height);
+        }
+
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+}
+
+/// The strategy a [`ComboBox`] uses to match its options against the typed
+/// text.
+///
+/// Both strategies ignore case.
+///
+/// [`ComboBox`]: struct.ComboBox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Keeps the options that contain the typed text, listing the ones that
+    /// start with it first.
+    Substring,
+
+    /// Keeps the options that contain the typed characters in order, even if
+    /// they are not next to each other, listing the closest matches first.
+    Fuzzy,
+}
+
+impl Filter {
+    /// Returns the options matching the given query, best matches first.
+    ///
+    /// Options that match equally well keep their original order.
+    pub fn apply<T: Clone + ToString>(
+        self,
+        options: &[T],
+        query: &str,
+    ) -> Vec<T> {
+        let query = query.to_lowercase();
+
+        let mut matches: Vec<_> = options
+            .iter()
+            .filter_map(|option| {
+                let label = option.to_string().to_lowercase();
+
+                self.score(&label, &query).map(|score| (score, option))
+            })
+            .collect();
+
+        matches.sort_by_key(|(score, _)| *score);
+
+        matches
+            .into_iter()
+            .map(|(_, option)| option.clone())
+            .collect()
+    }
+
+    /// Scores how well a label matches a query, lower being better.
+    fn score(self, label: &str, query: &str) -> Option<usize> {
+        match self {
+            Filter::Substring => {
+                label
+                    .find(query)
+                    .map(|index| if index == 0 { 0 } else { 1 })
+            }
+            Filter::Fuzzy => {
+                let mut chars = label.char_indices();
+                let mut score = 0;
+                let mut last = None;
+
+                for c in query.chars() {
+                    let (index, _) = chars.find(|(_, l)| *l == c)?;
+
+                    // Gaps between matched characters make a match worse
+                    score += match last {
+                        Some(last) => index - last - 1,
+                        None => index,
+                    };
+
+                    last = Some(index + c.len_utf8() - 1);
+                }
+
+                Some(score)
+            }
+        }
+    }
+}
+
+/// The renderer of a [`ComboBox`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ComboBox`] in your user interface.
+///
+/// [`ComboBox`]: struct.ComboBox.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text_input::Renderer + menu::Renderer {
+    /// The default padding of a [`ComboBox`].
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    const DEFAULT_PADDING: u16;
+
+    /// Draws a [`ComboBox`], given the output of its field.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    fn draw(&mut self, text_input: Self::Output) -> Self::Output;
+}
+
+/// The local state of a [`ComboBox`].
+///
+/// [`ComboBox`]: struct.ComboBox.html
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    text_input: text_input::State,
+    menu: menu::State,
+    is_open: bool,
+    query: Option<String>,
+    hovered_option: Option<usize>,
+    last_selection: Option<T>,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            text_input: text_input::State::default(),
+            menu: menu::State::default(),
+            is_open: false,
+            query: None,
+            hovered_option: None,
+            last_selection: None,
+        }
+    }
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`], representing an unfocused [`ComboBox`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the field of the [`ComboBox`] is currently focused or
+    /// not.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+
+    /// Returns the text typed in the [`ComboBox`] since it was focused, if
+    /// any.
+    ///
+    /// [`ComboBox`]: struct.ComboBox.html
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + PartialEq,
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        combo_box: ComboBox<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(combo_box)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_lists_prefixes_first() {
+        let options = ["Nigeria", "Germany", "Niger", "Algeria"];
+
+        assert_eq!(
+            Filter::Substring.apply(&options, "GER"),
+            vec!["Germany", "Nigeria", "Niger", "Algeria"]
+        );
+        assert_eq!(
+            Filter::Substring.apply(&options, "niger"),
+            vec!["Nigeria", "Niger"]
+        );
+    }
+
+    #[test]
+    fn fuzzy_lists_closest_matches_first() {
+        let options = ["Monospace", "Sans Serif", "Serif", "Fira Sans"];
+
+        assert_eq!(
+            Filter::Fuzzy.apply(&options, "srf"),
+            vec!["Serif", "Sans Serif"]
+        );
+        assert_eq!(Filter::Fuzzy.apply(&options, "fs"), vec!["Fira Sans"]);
+        assert!(Filter::Fuzzy.apply(&options, "xyz").is_empty());
+    }
+}
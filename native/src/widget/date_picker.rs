@@ -0,0 +1,1159 @@
+//! Pick a date from a calendar.
+use crate::{
+    keyboard, layout, mouse, overlay, text, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Widget,
+};
+use std::fmt;
+
+/// A field that opens a calendar to pick a [`Date`].
+///
+/// The calendar shows a month at a time and can be browsed with its buttons
+/// or the keyboard: the arrow keys move by days and weeks, `PageUp` and
+/// `PageDown` move by months (or years, with `Shift`), `Home` and `End` jump
+/// to the first and last days of the month, and `Enter` picks the focused
+/// day.
+///
+/// [`Date`]: struct.Date.html
+///
+/// # Example
+/// ```
+/// # use iced_native::{date_picker, renderer::Null};
+/// #
+/// # pub type DatePicker<'a, Message> =
+/// #     iced_native::DatePicker<'a, Message, Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     DatePicked(date_picker::Date),
+/// }
+///
+/// let mut state = date_picker::State::new();
+/// let min = date_picker::Date::new(2020, 1, 1).unwrap();
+///
+/// let date_picker = DatePicker::new(&mut state, None, Message::DatePicked)
+///     .placeholder("Pick a date...")
+///     .min(min)
+///     .disabled(|date| date.weekday() >= 5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    value: Option<Date>,
+    on_change: Box<dyn Fn(Date) -> Message>,
+    min: Option<Date>,
+    max: Option<Date>,
+    disabled: Box<dyn Fn(Date) -> bool>,
+    placeholder: Option<String>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> DatePicker<'a, Message, Renderer> {
+    /// Creates a new [`DatePicker`] with the given [`State`], the current
+    /// [`Date`], if any, and the message to produce when a [`Date`] is picked.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    /// [`State`]: struct.State.html
+    /// [`Date`]: struct.Date.html
+    pub fn new<F>(
+        state: &'a mut State,
+        value: Option<Date>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(Date) -> Message,
+    {
+        DatePicker {
+            state,
+            value,
+            on_change: Box::new(on_change),
+            min: None,
+            max: None,
+            disabled: Box::new(|_| false),
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets a function that decides which dates cannot be picked, like
+    /// weekends or holidays.
+    pub fn disabled<F>(mut self, disabled: F) -> Self
+    where
+        F: 'static + Fn(Date) -> bool,
+    {
+        self.disabled = Box::new(disabled);
+        self
+    }
+
+    /// Sets the text shown by the [`DatePicker`] when no date is picked.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`DatePicker`].
+///
+/// [`DatePicker`]: struct.DatePicker.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+    focused: Option<Date>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a closed [`DatePicker`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the calendar of the [`DatePicker`] is open.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new [`Date`], if the given day exists.
+    ///
+    /// Months go from 1 to 12.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+        {
+            Some(Date { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the current [`Date`] in UTC, according to the system clock.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn today() -> Self {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Returns the year of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], from 1 to 12.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the day of the week of the [`Date`], from 0 (Monday) to 6
+    /// (Sunday).
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn weekday(self) -> u8 {
+        // The 1st of January of 1970 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as u8
+    }
+
+    /// Returns the [`Date`] the given number of days later, or earlier if
+    /// negative.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Returns the [`Date`] the given number of months later, or earlier if
+    /// negative.
+    ///
+    /// The day is kept unless the new month is shorter, in which case its
+    /// last day is used.
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u8 + 1;
+
+        Date {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn first_of_month(self) -> Self {
+        Date { day: 1, ..self }
+    }
+
+    /// Returns the last day of the month of the [`Date`].
+    ///
+    /// [`Date`]: struct.Date.html
+    pub fn last_of_month(self) -> Self {
+        Date {
+            day: days_in_month(self.year, self.month),
+            ..self
+        }
+    }
+
+    // Days since the 1st of January of 1970, from Howard Hinnant's
+    // `days_from_civil` algorithm
+    fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = (i64::from(self.month) + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns the number of days of a month of the given year.
+///
+/// Months go from 1 to 12.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The names of the months, from January to December.
+pub const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The short names of the days of the week, from Monday to Sunday.
+pub const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A labelled cell of a picker grid, as it is drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cell {
+    /// The label of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub label: String,
+
+    /// The bounds of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub bounds: Rectangle,
+
+    /// Whether the [`Cell`] holds the current value.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub is_selected: bool,
+
+    /// Whether the [`Cell`] has the keyboard focus.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub is_focused: bool,
+
+    /// Whether the [`Cell`] cannot be picked.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub is_disabled: bool,
+
+    /// Whether the [`Cell`] is less relevant than the others, like the days
+    /// of the previous and next months in a calendar.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub is_secondary: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DatePicker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + 'a,
+    Message: 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        field_layout(
+            renderer,
+            limits,
+            "0000-00-00",
+            self.width,
+            self.padding,
+            self.text_size,
+            self.font,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if self.state.is_open {
+                    // TODO: Encode cursor availability in the type system
+                    self.state.is_open =
+                        cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                } else if layout.bounds().contains(cursor_position) {
+                    let focused = self.value.unwrap_or_else(Date::today);
+
+                    self.state.is_open = true;
+                    self.state.focused =
+                        Some(clamp(focused, self.min, self.max));
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.is_open = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.value.map(|value| value.to_string()),
+            self.placeholder.as_deref(),
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        let State { is_open, focused } = &mut self.state;
+        let bounds = layout.bounds();
+        let focused = focused.get_or_insert_with(Date::today);
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(Calendar {
+                is_open,
+                focused,
+                value: self.value,
+                on_change: &self.on_change,
+                min: self.min,
+                max: self.max,
+                disabled: &self.disabled,
+                target_height: bounds.height,
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+}
+
+/// Lays out a picker field that fits the given sample value.
+pub(crate) fn field_layout<Renderer: text::Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    sample: &str,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+) -> layout::Node {
+    let padding = f32::from(padding);
+    let text_size = text_size.unwrap_or(renderer.default_size());
+    let limits = limits.width(width).height(Length::Shrink).pad(padding);
+
+    let (sample_width, _) = renderer.measure(
+        sample,
+        text_size,
+        font,
+        Size::new(f32::INFINITY, f32::INFINITY),
+    );
+
+    let intrinsic = Size::new(
+        sample_width.round() + f32::from(text_size) + padding,
+        f32::from(text_size),
+    );
+
+    layout::Node::new(limits.resolve(intrinsic).pad(padding))
+}
+
+/// Places a popup of the given size under its target, unless it does not fit
+/// there and there is more space on top.
+pub(crate) fn popup_position(
+    bounds: Size,
+    position: Point,
+    target_height: f32,
+    size: Size,
+) -> Point {
+    let space_below = bounds.height - (position.y + target_height);
+    let space_above = position.y;
+
+    let x = position.x.min((bounds.width - size.width).max(0.0));
+
+    if size.height <= space_below || space_below >= space_above {
+        Point::new(x, position.y + target_height)
+    } else {
+        Point::new(x, position.y - size.height)
+    }
+}
+
+/// Returns the bounds of a cell of a grid starting at the given origin.
+pub(crate) fn cell_bounds(
+    origin: Point,
+    size: f32,
+    row: usize,
+    column: usize,
+) -> Rectangle {
+    Rectangle {
+        x: origin.x + column as f32 * size,
+        y: origin.y + row as f32 * size,
+        width: size,
+        height: size,
+    }
+}
+
+fn clamp(date: Date, min: Option<Date>, max: Option<Date>) -> Date {
+    let date = min.map_or(date, |min| date.max(min));
+
+    max.map_or(date, |max| date.min(max))
+}
+
+/// A button of the header of a calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Navigation {
+    PreviousYear,
+    PreviousMonth,
+    NextMonth,
+    NextYear,
+}
+
+impl Navigation {
+    const ALL: [(Navigation, usize, &'static str); 4] = [
+        (Navigation::PreviousYear, 0, "«"),
+        (Navigation::PreviousMonth, 1, "‹"),
+        (Navigation::NextMonth, 5, "›"),
+        (Navigation::NextYear, 6, "»"),
+    ];
+
+    fn months(self) -> i32 {
+        match self {
+            Navigation::PreviousYear => -12,
+            Navigation::PreviousMonth => -1,
+            Navigation::NextMonth => 1,
+            Navigation::NextYear => 12,
+        }
+    }
+}
+
+struct Calendar<'a, Message, Renderer: self::Renderer> {
+    is_open: &'a mut bool,
+    focused: &'a mut Date,
+    value: Option<Date>,
+    on_change: &'a dyn Fn(Date) -> Message,
+    min: Option<Date>,
+    max: Option<Date>,
+    disabled: &'a dyn Fn(Date) -> bool,
+    target_height: f32,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> Calendar<'a, Message, Renderer> {
+    fn is_disabled(&self, date: Date) -> bool {
+        clamp(date, self.min, self.max) != date || (self.disabled)(date)
+    }
+
+    /// Returns whether any day of the month of the given date can be shown.
+    fn is_month_in_range(&self, date: Date) -> bool {
+        !matches!(self.min, Some(min) if date.last_of_month() < min)
+            && !matches!(self.max, Some(max) if date.first_of_month() > max)
+    }
+
+    /// Returns the first day of the grid, the Monday on or before the first
+    /// day of the focused month.
+    fn first_day(&self) -> Date {
+        let first = self.focused.first_of_month();
+
+        first.add_days(-i64::from(first.weekday()))
+    }
+
+    fn cell_size(&self, renderer: &Renderer) -> f32 {
+        f32::from(self.text_size.unwrap_or(renderer.default_size()) * 2)
+    }
+
+    fn origin(&self, bounds: Rectangle) -> Point {
+        Point::new(
+            bounds.x + f32::from(self.padding),
+            bounds.y + f32::from(self.padding),
+        )
+    }
+
+    fn focus(&mut self, date: Date) {
+        *self.focused = clamp(date, self.min, self.max);
+    }
+
+    fn pick(&mut self, date: Date, messages: &mut Vec<Message>) {
+        if !self.is_disabled(date) {
+            messages.push((self.on_change)(date));
+
+            *self.focused = date;
+            *self.is_open = false;
+        }
+    }
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Calendar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let cell = self.cell_size(renderer);
+        let padding = f32::from(self.padding) * 2.0;
+
+        // A header, the names of the days and six weeks
+        let size = Size::new(cell * 7.0 + padding, cell * 8.0 + padding);
+
+        let mut node = layout::Node::new(size);
+        node.move_to(popup_position(
+            bounds,
+            position,
+            self.target_height,
+            size,
+        ));
+
+        node
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.target_height.to_bits().hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let focused = *self.focused;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                let cell = self.cell_size(renderer);
+                let relative = cursor_position - self.origin(bounds);
+
+                if relative.x < 0.0 || relative.y < 0.0 {
+                    return;
+                }
+
+                let row = (relative.y / cell) as usize;
+                let column = (relative.x / cell) as usize;
+
+                match row {
+                    0 => {
+                        let navigation = Navigation::ALL
+                            .iter()
+                            .find(|(_, index, _)| *index == column);
+
+                        if let Some((navigation, _, _)) = navigation {
+                            let date = focused.add_months(navigation.months());
+
+                            if self.is_month_in_range(date) {
+                                self.focus(date);
+                            }
+                        }
+                    }
+                    2..=7 if column < 7 => {
+                        let index = (row - 2) * 7 + column;
+                        let date = self.first_day().add_days(index as i64);
+
+                        self.pick(date, messages);
+                    }
+                    _ => {}
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => match key_code {
+                keyboard::KeyCode::Left => self.focus(focused.add_days(-1)),
+                keyboard::KeyCode::Right => self.focus(focused.add_days(1)),
+                keyboard::KeyCode::Up => self.focus(focused.add_days(-7)),
+                keyboard::KeyCode::Down => self.focus(focused.add_days(7)),
+                keyboard::KeyCode::PageUp => {
+                    self.focus(focused.add_months(if modifiers.shift {
+                        -12
+                    } else {
+                        -1
+                    }))
+                }
+                keyboard::KeyCode::PageDown => {
+                    self.focus(focused.add_months(if modifiers.shift {
+                        12
+                    } else {
+                        1
+                    }))
+                }
+                keyboard::KeyCode::Home => self.focus(focused.first_of_month()),
+                keyboard::KeyCode::End => self.focus(focused.last_of_month()),
+                keyboard::KeyCode::Enter => self.pick(focused, messages),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let cell = self.cell_size(renderer);
+        let origin = self.origin(bounds);
+        let focused = *self.focused;
+
+        let title = format!(
+            "{} {}",
+            MONTHS[usize::from(focused.month() - 1)],
+            focused.year()
+        );
+
+        let navigation: Vec<Cell> = Navigation::ALL
+            .iter()
+            .map(|(navigation, column, label)| Cell {
+                label: label.to_string(),
+                bounds: cell_bounds(origin, cell, 0, *column),
+                is_selected: false,
+                is_focused: false,
+                is_disabled: !self
+                    .is_month_in_range(focused.add_months(navigation.months())),
+                is_secondary: false,
+            })
+            .collect();
+
+        let weekdays: Vec<Cell> = WEEKDAYS
+            .iter()
+            .enumerate()
+            .map(|(column, label)| Cell {
+                label: label.to_string(),
+                bounds: cell_bounds(origin, cell, 1, column),
+                is_selected: false,
+                is_focused: false,
+                is_disabled: false,
+                is_secondary: true,
+            })
+            .collect();
+
+        let first_day = self.first_day();
+
+        let days: Vec<Cell> = (0..42)
+            .map(|index| {
+                let date = first_day.add_days(index as i64);
+
+                Cell {
+                    label: date.day().to_string(),
+                    bounds: cell_bounds(origin, cell, 2 + index / 7, index % 7),
+                    is_selected: self.value == Some(date),
+                    is_focused: date == focused,
+                    is_disabled: self.is_disabled(date),
+                    is_secondary: date.month() != focused.month(),
+                }
+            })
+            .collect();
+
+        renderer.draw_calendar(
+            bounds,
+            cursor_position,
+            &title,
+            &navigation,
+            &weekdays,
+            &days,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            self.style,
+        )
+    }
+}
+
+/// The renderer of a [`DatePicker`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`DatePicker`] in your user interface.
+///
+/// [`DatePicker`]: struct.DatePicker.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of a [`DatePicker`].
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    const DEFAULT_PADDING: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the field of a [`DatePicker`], showing its current value or its
+    /// placeholder.
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        value: Option<String>,
+        placeholder: Option<&str>,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+
+    /// Draws the calendar of a [`DatePicker`].
+    ///
+    /// It receives:
+    ///   * the bounds of the calendar
+    ///   * the current cursor position
+    ///   * the title of the shown month
+    ///   * the buttons that move to other months and years, in the header
+    ///   * the names of the days of the week
+    ///   * the six weeks of days shown
+    ///   * the text size and font of the calendar
+    ///   * the style of the [`DatePicker`]
+    ///
+    /// [`DatePicker`]: struct.DatePicker.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw_calendar(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        title: &str,
+        navigation: &[Cell],
+        weekdays: &[Cell],
+        days: &[Cell],
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<DatePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        date_picker: DatePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(date_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Font, Overlay};
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    fn key(key_code: keyboard::KeyCode, shift: bool) -> (Event, Point) {
+        let event = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::ModifiersState {
+                shift,
+                ..Default::default()
+            },
+        });
+
+        (event, Point::ORIGIN)
+    }
+
+    fn click(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(x, y),
+        )
+    }
+
+    /// Sends the given events to a calendar open at the given date, with a
+    /// padding of 5 and cells of 40, returning the focused date and the
+    /// picked dates.
+    fn send(
+        focused: Date,
+        min: Option<Date>,
+        max: Option<Date>,
+        disabled: &dyn Fn(Date) -> bool,
+        events: impl IntoIterator<Item = (Event, Point)>,
+    ) -> (Date, Vec<Date>) {
+        let mut is_open = true;
+        let mut focused = focused;
+        let mut messages = Vec::new();
+
+        let mut calendar = Calendar::<Date, Null> {
+            is_open: &mut is_open,
+            focused: &mut focused,
+            value: None,
+            on_change: &|date| date,
+            min,
+            max,
+            disabled,
+            target_height: 0.0,
+            padding: 5,
+            text_size: None,
+            font: Font::Default,
+            style: &(),
+        };
+
+        let node = calendar.layout(&Null::new(), Size::INFINITY, Point::ORIGIN);
+
+        for (event, cursor_position) in events {
+            calendar.on_event(
+                event,
+                Layout::new(&node),
+                cursor_position,
+                &mut messages,
+                &Null::new(),
+                None,
+            );
+        }
+
+        assert_eq!(is_open, messages.is_empty());
+
+        (focused, messages)
+    }
+
+    #[test]
+    fn arrows_move_the_focused_day() {
+        let navigate = |key_code, shift| {
+            send(
+                date(2020, 2, 28),
+                None,
+                None,
+                &|_| false,
+                vec![key(key_code, shift)],
+            )
+            .0
+        };
+
+        assert_eq!(
+            navigate(keyboard::KeyCode::Right, false),
+            date(2020, 2, 29)
+        );
+        assert_eq!(navigate(keyboard::KeyCode::Left, false), date(2020, 2, 27));
+        assert_eq!(navigate(keyboard::KeyCode::Down, false), date(2020, 3, 6));
+        assert_eq!(navigate(keyboard::KeyCode::Up, false), date(2020, 2, 21));
+        assert_eq!(
+            navigate(keyboard::KeyCode::PageDown, false),
+            date(2020, 3, 28)
+        );
+        assert_eq!(
+            navigate(keyboard::KeyCode::PageUp, true),
+            date(2019, 2, 28)
+        );
+        assert_eq!(navigate(keyboard::KeyCode::Home, false), date(2020, 2, 1));
+        assert_eq!(navigate(keyboard::KeyCode::End, false), date(2020, 2, 29));
+
+        let (_, picked) = send(
+            date(2020, 2, 28),
+            None,
+            None,
+            &|_| false,
+            vec![key(keyboard::KeyCode::Enter, false)],
+        );
+
+        assert_eq!(picked, vec![date(2020, 2, 28)]);
+    }
+
+    #[test]
+    fn focus_is_clamped_to_min_and_max() {
+        let min = Some(date(2020, 2, 10));
+        let max = Some(date(2020, 2, 20));
+        let navigate = |key_code| {
+            send(
+                date(2020, 2, 12),
+                min,
+                max,
+                &|_| false,
+                vec![key(key_code, false)],
+            )
+            .0
+        };
+
+        assert_eq!(navigate(keyboard::KeyCode::Up), date(2020, 2, 10));
+        assert_eq!(navigate(keyboard::KeyCode::Down), date(2020, 2, 19));
+        assert_eq!(navigate(keyboard::KeyCode::PageDown), date(2020, 2, 20));
+        assert_eq!(navigate(keyboard::KeyCode::Home), date(2020, 2, 10));
+
+        // The days of February 2020 start at (5, 85), from Monday the 27th of
+        // January, and the header buttons of the next month at (205, 5)
+        let (focused, picked) = send(
+            date(2020, 2, 12),
+            min,
+            max,
+            &|_| false,
+            vec![click(25.0, 105.0), click(225.0, 25.0)],
+        );
+
+        assert_eq!(focused, date(2020, 2, 12));
+        assert!(picked.is_empty());
+    }
+
+    #[test]
+    fn disabled_dates_cannot_be_picked() {
+        let weekend = |date: Date| date.weekday() >= 5;
+
+        // Saturday the 1st of February 2020
+        let (_, picked) = send(
+            date(2020, 2, 1),
+            None,
+            None,
+            &weekend,
+            vec![key(keyboard::KeyCode::Enter, false), click(225.0, 105.0)],
+        );
+
+        assert!(picked.is_empty());
+
+        // Monday the 3rd of February 2020
+        let (_, picked) = send(
+            date(2020, 2, 1),
+            None,
+            None,
+            &weekend,
+            vec![click(25.0, 145.0)],
+        );
+
+        assert_eq!(picked, vec![date(2020, 2, 3)]);
+    }
+
+    #[test]
+    fn dates_round_trip_through_days() {
+        let leap_day = Date::new(2020, 2, 29).unwrap();
+
+        assert_eq!(leap_day.weekday(), 5);
+        assert_eq!(leap_day.add_days(1), Date::new(2020, 3, 1).unwrap());
+        assert_eq!(leap_day.add_days(-366), Date::new(2019, 2, 28).unwrap());
+        assert_eq!(Date::new(1970, 1, 1).unwrap().weekday(), 3);
+        assert_eq!(Date::new(2019, 2, 29), None);
+    }
+
+    #[test]
+    fn months_keep_the_day_when_possible() {
+        let date = Date::new(2020, 1, 31).unwrap();
+
+        assert_eq!(date.add_months(1), Date::new(2020, 2, 29).unwrap());
+        assert_eq!(date.add_months(-1), Date::new(2019, 12, 31).unwrap());
+        assert_eq!(date.add_months(14), Date::new(2021, 3, 31).unwrap());
+    }
+}
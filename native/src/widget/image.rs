@@ -231,6 +231,17 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The filtering method used to sample an image when it is scaled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FilterMethod {
+    /// Bilinear interpolation, which looks smooth when shrinking an image.
+    #[default]
+    Linear,
+
+    /// Nearest neighbor, which keeps pixels sharp when enlarging an image.
+    Nearest,
+}
+
 /// The renderer of an [`Image`].
 ///
 /// Your [renderer] will need to implement this trait before being able to use
@@ -0,0 +1,511 @@
+//! Inspect an image by zooming and panning it.
+//!
+//! An [`ImageViewer`] has some local [`State`].
+//!
+//! [`ImageViewer`]: struct.ImageViewer.html
+//! [`State`]: struct.State.html
+use crate::image::{self, FilterMethod, Handle};
+use crate::{
+    keyboard, layout,
+    mouse::{self, click},
+    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Vector, Widget,
+};
+
+use std::hash::Hash;
+
+/// The pixels of a precise scroll that amount to a wheel line.
+const PIXELS_PER_LINE: f32 = 20.0;
+
+/// A frame that displays an image which can be zoomed and panned.
+///
+/// At its initial scale, the image fits the [`ImageViewer`] while keeping
+/// its aspect ratio. Then:
+///   * the mouse wheel zooms toward the cursor
+///   * dragging pans the image
+///   * double-clicking resets the view
+///   * once clicked, `+`, `-`, `0` and the arrow keys zoom, reset and pan
+///
+/// The image is sampled with nearest neighbor filtering whenever it is
+/// enlarged past its original size, so every pixel stays sharp.
+///
+/// [`ImageViewer`]: struct.ImageViewer.html
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{image_viewer, ImageViewer};
+/// #
+/// let state = &mut image_viewer::State::new();
+///
+/// let viewer = ImageViewer::new(state, "resources/ferris.png")
+///     .max_scale(20.0);
+/// ```
+#[derive(Debug)]
+pub struct ImageViewer<'a> {
+    state: &'a mut State,
+    handle: Handle,
+    width: Length,
+    height: Length,
+    min_scale: f32,
+    max_scale: f32,
+    scale_step: f32,
+}
+
+impl<'a> ImageViewer<'a> {
+    /// Creates a new [`ImageViewer`] with the given [`State`] and image.
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    /// [`State`]: struct.State.html
+    pub fn new<T: Into<Handle>>(state: &'a mut State, handle: T) -> Self {
+        ImageViewer {
+            state,
+            handle: handle.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            min_scale: 0.25,
+            max_scale: 10.0,
+            scale_step: 0.1,
+        }
+    }
+
+    /// Sets the width of the [`ImageViewer`].
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ImageViewer`].
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the minimum scale of the [`ImageViewer`], relative to the scale
+    /// that fits the image. By default, it is `0.25`.
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    pub fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale.max(f32::EPSILON);
+        self
+    }
+
+    /// Sets the maximum scale of the [`ImageViewer`], relative to the scale
+    /// that fits the image. By default, it is `10.0`.
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    pub fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale.max(f32::EPSILON);
+        self
+    }
+
+    /// Sets the fraction by which every wheel line or key press zooms the
+    /// [`ImageViewer`]. By default, it is `0.1`.
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    pub fn scale_step(mut self, scale_step: f32) -> Self {
+        self.scale_step = scale_step.max(0.0);
+        self
+    }
+
+    fn image_size<Renderer>(&self, renderer: &Renderer) -> Size
+    where
+        Renderer: self::Renderer,
+    {
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        Size::new(width.max(1) as f32, height.max(1) as f32)
+    }
+
+    fn zoom(&mut self, factor: f32, anchor: Point, viewport: &Viewport) {
+        let scale = (self.state.scale * factor)
+            .max(self.min_scale)
+            .min(self.max_scale);
+
+        self.state.zoom(scale, anchor, viewport);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ImageViewer<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(self.image_size(renderer));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let viewport = Viewport {
+            bounds: layout.bounds(),
+            image_size: self.image_size(renderer),
+        };
+        let is_mouse_over = viewport.bounds.contains(cursor_position);
+        let step = 1.0 + self.scale_step;
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_mouse_over =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_LINE,
+                };
+
+                self.zoom(step.powf(lines), cursor_position, &viewport);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                self.state.is_focused = is_mouse_over;
+
+                if !is_mouse_over {
+                    return;
+                }
+
+                let click =
+                    mouse::Click::new(cursor_position, self.state.last_click);
+
+                match click.kind() {
+                    click::Kind::Double => {
+                        self.state.reset();
+                    }
+                    click::Kind::Single | click::Kind::Triple => {
+                        self.state.grabbed_at = Some(cursor_position);
+                    }
+                }
+
+                self.state.last_click = Some(click);
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.grabbed_at = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(grabbed_at) = self.state.grabbed_at {
+                    let offset =
+                        self.state.offset + (cursor_position - grabbed_at);
+
+                    self.state.pan(offset, &viewport);
+                    self.state.grabbed_at = Some(cursor_position);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                let center = viewport.bounds.center();
+                let pan = Vector::new(
+                    viewport.bounds.width * self.scale_step,
+                    viewport.bounds.height * self.scale_step,
+                );
+                let offset = self.state.offset;
+
+                match key_code {
+                    keyboard::KeyCode::Plus
+                    | keyboard::KeyCode::Equals
+                    | keyboard::KeyCode::NumpadAdd => {
+                        self.zoom(step, center, &viewport);
+                    }
+                    keyboard::KeyCode::Minus
+                    | keyboard::KeyCode::NumpadSubtract => {
+                        self.zoom(1.0 / step, center, &viewport);
+                    }
+                    keyboard::KeyCode::Key0 | keyboard::KeyCode::Numpad0 => {
+                        self.state.reset();
+                    }
+                    keyboard::KeyCode::Left => self
+                        .state
+                        .pan(offset + Vector::new(pan.x, 0.0), &viewport),
+                    keyboard::KeyCode::Right => self
+                        .state
+                        .pan(offset - Vector::new(pan.x, 0.0), &viewport),
+                    keyboard::KeyCode::Up => self
+                        .state
+                        .pan(offset + Vector::new(0.0, pan.y), &viewport),
+                    keyboard::KeyCode::Down => self
+                        .state
+                        .pan(offset - Vector::new(0.0, pan.y), &viewport),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let viewport = Viewport {
+            bounds: layout.bounds(),
+            image_size: self.image_size(renderer),
+        };
+        let image_bounds = self.state.image_bounds(&viewport);
+
+        // Linear filtering blurs enlarged pixels together, once an image
+        // pixel covers more than a physical pixel
+        let scale_factor = renderer.scale_factor() as f32;
+
+        let filter_method =
+            if image_bounds.width * scale_factor > viewport.image_size.width {
+                FilterMethod::Nearest
+            } else {
+                FilterMethod::Linear
+            };
+
+        self::Renderer::draw(
+            renderer,
+            self.state,
+            self.handle.clone(),
+            viewport.bounds,
+            image_bounds,
+            filter_method,
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The local state of an [`ImageViewer`].
+///
+/// [`ImageViewer`]: struct.ImageViewer.html
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    scale: f32,
+    offset: Vector,
+    grabbed_at: Option<Point>,
+    last_click: Option<mouse::Click>,
+    is_focused: bool,
+}
+
+impl Default for State {
+    fn default() -> State {
+        State {
+            scale: 1.0,
+            offset: Vector::new(0.0, 0.0),
+            grabbed_at: None,
+            last_click: None,
+            is_focused: false,
+        }
+    }
+}
+
+impl State {
+    /// Creates a new [`State`] that fits the image.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the current scale of the image, relative to the scale that
+    /// fits it.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns the offset of the center of the image from the center of the
+    /// [`ImageViewer`].
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    /// Returns whether the image is being dragged.
+    pub fn is_grabbed(&self) -> bool {
+        self.grabbed_at.is_some()
+    }
+
+    /// Fits and centers the image again.
+    pub fn reset(&mut self) {
+        self.scale = 1.0;
+        self.offset = Vector::new(0.0, 0.0);
+        self.grabbed_at = None;
+    }
+
+    fn image_bounds(&self, viewport: &Viewport) -> Rectangle {
+        let Viewport { bounds, image_size } = *viewport;
+
+        let fit = (bounds.width / image_size.width)
+            .min(bounds.height / image_size.height);
+
+        let width = image_size.width * fit * self.scale;
+        let height = image_size.height * fit * self.scale;
+
+        Rectangle {
+            x: bounds.center_x() - width / 2.0 + self.offset.x,
+            y: bounds.center_y() - height / 2.0 + self.offset.y,
+            width,
+            height,
+        }
+    }
+
+    fn zoom(&mut self, scale: f32, anchor: Point, viewport: &Viewport) {
+        // The point of the image under the anchor must stay there
+        let anchor = anchor - viewport.bounds.center();
+        let ratio = scale / self.scale;
+
+        self.scale = scale;
+        self.pan(anchor - (anchor - self.offset) * ratio, viewport);
+    }
+
+    fn pan(&mut self, offset: Vector, viewport: &Viewport) {
+        let image = self.image_bounds(viewport);
+
+        // The image can only move while it overflows the viewport
+        let max_x = ((image.width - viewport.bounds.width) / 2.0).max(0.0);
+        let max_y = ((image.height - viewport.bounds.height) / 2.0).max(0.0);
+
+        self.offset = Vector::new(
+            offset.x.clamp(-max_x, max_x),
+            offset.y.clamp(-max_y, max_y),
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Viewport {
+    bounds: Rectangle,
+    image_size: Size,
+}
+
+/// The renderer of an [`ImageViewer`].
+///
+/// Your [renderer] will need to implement this trait before being able to
+/// use an [`ImageViewer`] in your user interface.
+///
+/// [`ImageViewer`]: struct.ImageViewer.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: image::Renderer {
+    /// Draws an [`ImageViewer`].
+    ///
+    /// It receives:
+    ///   * the [`State`] of the [`ImageViewer`]
+    ///   * the [`Handle`] of the image
+    ///   * the bounds of the [`ImageViewer`]
+    ///   * the bounds of the zoomed and panned image, which may overflow
+    ///     the [`ImageViewer`]
+    ///   * the [`FilterMethod`] to sample the image with
+    ///   * the cursor position
+    ///
+    /// [`ImageViewer`]: struct.ImageViewer.html
+    /// [`State`]: struct.State.html
+    /// [`Handle`]: ../image/struct.Handle.html
+    /// [`FilterMethod`]: ../image/enum.FilterMethod.html
+    fn draw(
+        &mut self,
+        state: &State,
+        handle: Handle,
+        bounds: Rectangle,
+        image_bounds: Rectangle,
+        filter_method: FilterMethod,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ImageViewer<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(viewer: ImageViewer<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(viewer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport() -> Viewport {
+        Viewport {
+            bounds: Rectangle {
+                x: 10.0,
+                y: 20.0,
+                width: 400.0,
+                height: 200.0,
+            },
+            image_size: Size::new(100.0, 100.0),
+        }
+    }
+
+    #[test]
+    fn zooming_keeps_the_point_under_the_anchor() {
+        let viewport = viewport();
+        let mut state = State::new();
+
+        let anchor = Point::new(260.0, 70.0);
+        let before = state.image_bounds(&viewport);
+
+        state.zoom(4.0, anchor, &viewport);
+
+        let after = state.image_bounds(&viewport);
+
+        assert_eq!(
+            before,
+            Rectangle::new(Point::new(110.0, 20.0), [200.0, 200.0].into())
+        );
+        assert_eq!(after.width, 800.0);
+        assert_eq!(
+            (anchor.x - before.x) / before.width,
+            (anchor.x - after.x) / after.width
+        );
+        assert_eq!(
+            (anchor.y - before.y) / before.height,
+            (anchor.y - after.y) / after.height
+        );
+    }
+
+    #[test]
+    fn panning_stops_at_the_edges() {
+        let viewport = viewport();
+        let mut state = State::new();
+
+        state.pan(Vector::new(50.0, 50.0), &viewport);
+        assert_eq!(state.offset(), Vector::new(0.0, 0.0));
+
+        state.zoom(3.0, viewport.bounds.center(), &viewport);
+        state.pan(Vector::new(500.0, -500.0), &viewport);
+        assert_eq!(state.offset(), Vector::new(100.0, -200.0));
+
+        state.reset();
+        assert_eq!(state.image_bounds(&viewport).width, 200.0);
+    }
+}
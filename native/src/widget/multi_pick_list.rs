@@ -0,0 +1,601 @@
+//! Display a dropdown list of options to pick a set of values from.
+use crate::{
+    keyboard, layout, mouse, overlay,
+    overlay::menu::{self, Menu},
+    scrollable, text, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+use std::borrow::Cow;
+
+/// A widget for selecting a set of values from a list of options.
+///
+/// The selected values are shown as chips that can be removed one by one.
+/// The menu of a [`MultiPickList`] shows a checkmark next to the selected
+/// options and stays open while options are toggled.
+///
+/// [`MultiPickList`]: struct.MultiPickList.html
+#[allow(missing_debug_implementations)]
+pub struct MultiPickList<'a, T, Message, Renderer: self::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    menu: &'a mut menu::State,
+    is_open: &'a mut bool,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<T>,
+    on_add: Box<dyn Fn(T) -> Message>,
+    on_remove: Box<dyn Fn(T) -> Message>,
+    options: Cow<'a, [T]>,
+    selected: Cow<'a, [T]>,
+    checked: Vec<bool>,
+    placeholder: Option<String>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    menu_max_height: Option<u32>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+/// The local state of a [`MultiPickList`].
+///
+/// [`MultiPickList`]: struct.MultiPickList.html
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    menu: menu::State,
+    is_open: bool,
+    hovered_option: Option<usize>,
+    last_selection: Option<T>,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            menu: menu::State::default(),
+            is_open: bool::default(),
+            hovered_option: Option::default(),
+            last_selection: Option::default(),
+        }
+    }
+}
+
+/// A selected value of a [`MultiPickList`], as it is drawn.
+///
+/// [`MultiPickList`]: struct.MultiPickList.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chip<'a> {
+    /// The label of the selected value.
+    pub label: &'a str,
+
+    /// The bounds of the [`Chip`].
+    ///
+    /// [`Chip`]: struct.Chip.html
+    pub bounds: Rectangle,
+
+    /// The bounds of the button that removes the value from the selection.
+    pub remove_bounds: Rectangle,
+}
+
+/// The spacing between the chips of a [`MultiPickList`].
+///
+/// [`MultiPickList`]: struct.MultiPickList.html
+pub const CHIP_SPACING: f32 = 4.0;
+
+impl<'a, T: 'a, Message, Renderer: self::Renderer>
+    MultiPickList<'a, T, Message, Renderer>
+where
+    T: ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`MultiPickList`] with the given [`State`], a list of
+    /// options, the currently selected values, and the messages to produce
+    /// when an option is added to or removed from the selection.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State<T>,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: impl Into<Cow<'a, [T]>>,
+        on_add: impl Fn(T) -> Message + 'static,
+        on_remove: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        let State {
+            menu,
+            is_open,
+            hovered_option,
+            last_selection,
+        } = state;
+
+        Self {
+            menu,
+            is_open,
+            hovered_option,
+            last_selection,
+            on_add: Box::new(on_add),
+            on_remove: Box::new(on_remove),
+            options: options.into(),
+            selected: selected.into(),
+            checked: Vec::new(),
+            placeholder: None,
+            width: Length::Shrink,
+            text_size: None,
+            menu_max_height: None,
+            padding: Renderer::DEFAULT_PADDING,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the text shown by the [`MultiPickList`] when nothing is selected.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the maximum height of the menu of the [`MultiPickList`].
+    ///
+    /// The options that do not fit can be scrolled.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn menu_max_height(mut self, max_height: u32) -> Self {
+        self.menu_max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the font of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for MultiPickList<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'static,
+    Renderer: self::Renderer + scrollable::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        use std::f32;
+
+        let padding = f32::from(self.padding);
+        let limits =
+            limits.width(self.width).height(Length::Shrink).pad(padding);
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let arrow_width = f32::from(text_size) + padding;
+        let chip_height = f32::from(text_size) + CHIP_SPACING;
+
+        let measure = |label: &str| {
+            let (width, _) = renderer.measure(
+                label,
+                text_size,
+                self.font,
+                Size::new(f32::INFINITY, f32::INFINITY),
+            );
+
+            width.round()
+        };
+
+        // Chips flow from left to right and wrap when they reach the arrow
+        let max_width = limits.max().width - arrow_width;
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut content_width: f32 = 0.0;
+
+        let chips: Vec<_> = self
+            .selected
+            .iter()
+            .map(|value| {
+                let width = measure(&value.to_string()) + padding + chip_height;
+
+                if x > 0.0 && x + width > max_width {
+                    x = 0.0;
+                    y += chip_height + CHIP_SPACING;
+                }
+
+                let mut chip = layout::Node::new(Size::new(width, chip_height));
+                chip.move_to(Point::new(padding + x, padding + y));
+
+                x += width + CHIP_SPACING;
+                content_width = content_width.max(x - CHIP_SPACING);
+
+                chip
+            })
+            .collect();
+
+        let min_width = match self.width {
+            Length::Shrink => self
+                .options
+                .iter()
+                .map(|option| measure(&option.to_string()))
+                .fold(0.0, f32::max),
+            _ => 0.0,
+        };
+
+        let content_height = if chips.is_empty() {
+            f32::from(text_size)
+        } else {
+            y + chip_height
+        };
+
+        let size = {
+            let intrinsic = Size::new(
+                content_width.max(min_width) + arrow_width,
+                content_height,
+            );
+
+            limits.resolve(intrinsic).pad(padding)
+        };
+
+        layout::Node::with_children(size, chips)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash as _;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+
+        self.selected
+            .iter()
+            .map(ToString::to_string)
+            .for_each(|label| label.hash(state));
+
+        if self.width == Length::Shrink {
+            self.options
+                .iter()
+                .map(ToString::to_string)
+                .for_each(|label| label.hash(state));
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let removed = layout.children().position(|chip| {
+                    remove_bounds(chip.bounds()).contains(cursor_position)
+                });
+
+                if let Some(value) =
+                    removed.and_then(|i| self.selected.get(i).cloned())
+                {
+                    messages.push((self.on_remove)(value));
+                } else if *self.is_open {
+                    // TODO: Encode cursor availability in the type system
+                    *self.is_open =
+                        cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                } else if layout.bounds().contains(cursor_position) {
+                    *self.is_open = true;
+                    *self.hovered_option = None;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                *self.is_open = false;
+            }
+            _ => {}
+        }
+
+        // The menu toggles an option on click or when `Enter` is pressed,
+        // and stays open so more options can be toggled
+        if let Some(last_selection) = self.last_selection.take() {
+            if self.selected.contains(&last_selection) {
+                messages.push((self.on_remove)(last_selection));
+            } else {
+                messages.push((self.on_add)(last_selection));
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let labels: Vec<String> =
+            self.selected.iter().map(ToString::to_string).collect();
+
+        let chips: Vec<Chip<'_>> = labels
+            .iter()
+            .zip(layout.children())
+            .map(|(label, layout)| Chip {
+                label,
+                bounds: layout.bounds(),
+                remove_bounds: remove_bounds(layout.bounds()),
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &chips,
+            self.placeholder.as_deref(),
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if *self.is_open {
+            let bounds = layout.bounds();
+            let selected = &self.selected;
+
+            self.checked = self
+                .options
+                .iter()
+                .map(|option| selected.contains(option))
+                .collect();
+
+            let mut menu = Menu::new(
+                self.menu,
+                &self.options,
+                self.hovered_option,
+                self.last_selection,
+            )
+            .checked(&self.checked)
+            .width(bounds.width.round() as u16)
+            .padding(self.padding)
+            .font(self.font)
+            .style(Renderer::menu_style(&self.style));
+
+            if let Some(text_size) = self.text_size {
+                menu = menu.text_size(text_size);
+            }
+
+            if let Some(max_height) = self.menu_max_height {
+                menu = menu.max_height(max_height);
+            }
+
+            Some(menu.overlay(layout.position(), bounds.height))
+        } else {
+            None
+        }
+    }
+}
+
+/// The remove button of a chip is a square at its right end.
+fn remove_bounds(chip: Rectangle) -> Rectangle {
+    Rectangle {
+        x: chip.x + chip.width - chip.height,
+        width: chip.height,
+        ..chip
+    }
+}
+
+/// The renderer of a [`MultiPickList`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`MultiPickList`] in your user interface.
+///
+/// [`MultiPickList`]: struct.MultiPickList.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer + menu::Renderer {
+    /// The default padding of a [`MultiPickList`].
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    const DEFAULT_PADDING: u16;
+
+    /// The [`MultiPickList`] style supported by this renderer.
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    type Style: Default;
+
+    /// Returns the style of the [`Menu`] of the [`MultiPickList`].
+    ///
+    /// [`Menu`]: ../../overlay/menu/struct.Menu.html
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    fn menu_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as menu::Renderer>::Style;
+
+    /// Draws a [`MultiPickList`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`MultiPickList`]
+    ///   * the current cursor position
+    ///   * the [`Chip`] of every selected value
+    ///   * the placeholder to show when nothing is selected, if any
+    ///   * the padding, text size and font of the [`MultiPickList`]
+    ///   * the style of the [`MultiPickList`]
+    ///
+    /// [`MultiPickList`]: struct.MultiPickList.html
+    /// [`Chip`]: struct.Chip.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        chips: &[Chip<'_>],
+        placeholder: Option<&str>,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T: 'a, Message, Renderer> From<MultiPickList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: self::Renderer + 'a,
+    Message: 'static,
+{
+    fn from(
+        multi_pick_list: MultiPickList<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(multi_pick_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    #[test]
+    fn chips_wrap_before_the_arrow() {
+        let mut state = State::default();
+        let selected = ["a", "b", "c"];
+
+        let multi_pick_list: MultiPickList<'_, &str, (), Null> =
+            MultiPickList::new(
+                &mut state,
+                &selected[..],
+                &selected[..],
+                |_| (),
+                |_| (),
+            )
+            .width(Length::Units(100));
+
+        let node = multi_pick_list.layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        let rows: Vec<f32> =
+            node.children().iter().map(|chip| chip.bounds().y).collect();
+
+        assert_eq!(rows, vec![5.0, 5.0, 33.0]);
+        assert_eq!(node.bounds().height, 33.0 + 24.0 + 5.0);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Added(&'static str),
+        Removed(&'static str),
+    }
+
+    fn toggle(
+        state: &mut State<&'static str>,
+        selected: &[&'static str],
+        option: &'static str,
+    ) -> Vec<Message> {
+        let options = ["a", "b", "c"];
+        state.last_selection = Some(option);
+
+        let mut multi_pick_list: MultiPickList<'_, _, _, Null> =
+            MultiPickList::new(
+                state,
+                &options[..],
+                selected.to_vec(),
+                Message::Added,
+                Message::Removed,
+            );
+
+        let node = Widget::<Message, Null>::layout(
+            &multi_pick_list,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        let mut messages = Vec::new();
+
+        multi_pick_list.on_event(
+            Event::Mouse(mouse::Event::CursorMoved { x: 0.0, y: 0.0 }),
+            Layout::new(&node),
+            Point::new(-1.0, -1.0),
+            &mut messages,
+            &Null::new(),
+            None,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn menu_selection_toggles_the_option() {
+        let mut state = State {
+            is_open: true,
+            ..State::default()
+        };
+
+        assert_eq!(toggle(&mut state, &["a"], "b"), vec![Message::Added("b")]);
+        assert_eq!(
+            toggle(&mut state, &["a", "b"], "a"),
+            vec![Message::Removed("a")]
+        );
+
+        // The menu stays open to toggle more options
+        assert!(state.is_open);
+        assert_eq!(state.last_selection, None);
+    }
+}
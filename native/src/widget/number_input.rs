@@ -0,0 +1,611 @@
+//! Let your users type or step through numeric values.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::{
+    button, keyboard, layout, text, text_input, Button, Clipboard, Element,
+    Event, Hasher, Layout, Length, Point, Size, Text, TextInput, Widget,
+};
+
+use num_traits::{Bounded, Zero};
+use std::ops::Sub;
+use std::str::FromStr;
+
+/// A field that holds a number, with buttons to increment and decrement it.
+///
+/// Only numeric characters can be typed into a [`NumberInput`]. Values that
+/// fall outside of its range are kept in the field, but do not produce
+/// messages.
+///
+/// # Example
+/// ```
+/// # use iced_native::{number_input, renderer::Null};
+/// #
+/// # pub type NumberInput<'a, T, Message> =
+/// #     iced_native::NumberInput<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let mut state = number_input::State::new();
+/// let quantity = 5;
+///
+/// let input = NumberInput::new(&mut state, quantity, Message::QuantityChanged)
+///     .min(1)
+///     .max(10);
+/// ```
+///
+/// [`NumberInput`]: struct.NumberInput.html
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer: self::Renderer> {
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    text: &'a mut Option<String>,
+    on_change: Box<dyn Fn(T) -> Message>,
+    text_input: TextInput<'a, Internal, Renderer>,
+    increment: Button<'a, Internal, Renderer>,
+    decrement: Button<'a, Internal, Renderer>,
+}
+
+#[derive(Debug, Clone)]
+enum Internal {
+    Changed(String),
+    Increment,
+    Decrement,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + From<u8> + FromStr + ToString,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    /// - some local [`State`]
+    /// - the current value
+    /// - a function that produces a message when the value changes
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let State {
+            text_input,
+            increment,
+            decrement,
+            text,
+        } = state;
+
+        // Keep the typed text while it is not a different valid number
+        let content = match text {
+            Some(text) => match text.parse::<T>() {
+                Ok(number) if number != value => value.to_string(),
+                _ => text.clone(),
+            },
+            None => value.to_string(),
+        };
+
+        NumberInput {
+            value,
+            min: None,
+            max: None,
+            step: T::from(1),
+            text,
+            on_change: Box::new(on_change),
+            text_input: TextInput::new(
+                text_input,
+                "",
+                &content,
+                Internal::Changed,
+            )
+            .filter(filter::<T>())
+            .padding(5),
+            increment: Button::new(increment, Text::new("+").size(12))
+                .on_press(Internal::Increment)
+                .padding(0)
+                .min_width(20),
+            decrement: Button::new(decrement, Text::new("-").size(12))
+                .on_press(Internal::Decrement)
+                .padding(0)
+                .min_width(20),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    ///
+    /// The step size defaults to 1.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the padding of the field of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.text_input = self.text_input.padding(units);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the style of the field of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the buttons of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn button_style(
+        mut self,
+        style: impl Into<<Renderer as button::Renderer>::Style> + Clone,
+    ) -> Self {
+        self.increment = self.increment.style(style.clone());
+        self.decrement = self.decrement.style(style);
+        self
+    }
+
+    fn contains(&self, value: T) -> bool {
+        self.min.map(|min| value >= min).unwrap_or(true)
+            && self.max.map(|max| value <= max).unwrap_or(true)
+    }
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + PartialOrd + Bounded + Zero + Sub<Output = T>,
+    Renderer: self::Renderer,
+{
+    fn incremented(&self) -> T {
+        saturating_add(
+            self.value,
+            self.step,
+            self.max.unwrap_or_else(T::max_value),
+        )
+    }
+
+    fn decremented(&self) -> T {
+        saturating_sub(
+            self.value,
+            self.step,
+            self.min.unwrap_or_else(T::min_value),
+        )
+    }
+}
+
+/// Adds a non-negative `step` to `value` without going past `max`, nor
+/// overflowing.
+fn saturating_add<T>(value: T, step: T, max: T) -> T
+where
+    T: Copy + PartialOrd + Zero + Sub<Output = T>,
+{
+    if value >= max {
+        max
+    } else if value < T::zero() && max > T::zero() {
+        // `max - value` could overflow, but `value + step` cannot
+        let next = value + step;
+
+        if next > max {
+            max
+        } else {
+            next
+        }
+    } else if max - value < step {
+        max
+    } else {
+        value + step
+    }
+}
+
+/// Subtracts a non-negative `step` from `value` without going past `min`, nor
+/// overflowing.
+fn saturating_sub<T>(value: T, step: T, min: T) -> T
+where
+    T: Copy + PartialOrd + Zero + Sub<Output = T>,
+{
+    if value <= min {
+        min
+    } else if value > T::zero() && min < T::zero() {
+        // `value - min` could overflow, but `value - step` cannot
+        let next = value - step;
+
+        if next < min {
+            min
+        } else {
+            next
+        }
+    } else if value - min < step {
+        min
+    } else {
+        value - step
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + From<u8>
+        + FromStr
+        + ToString
+        + Bounded
+        + Zero
+        + Sub<Output = T>,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Widget::<Internal, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(Widget::<Internal, Renderer>::width(&self.text_input))
+            .height(Length::Shrink);
+
+        let buttons_width = self
+            .increment
+            .layout(renderer, &limits.loose())
+            .size()
+            .width
+            .max(
+                self.decrement
+                    .layout(renderer, &limits.loose())
+                    .size()
+                    .width,
+            );
+
+        let text_input = self
+            .text_input
+            .layout(renderer, &limits.shrink(Size::new(buttons_width, 0.0)));
+
+        let text_input_size = text_input.size();
+
+        // The buttons split the height of the field
+        let button_size =
+            Size::new(buttons_width, text_input_size.height / 2.0);
+        let button_limits = layout::Limits::new(button_size, button_size);
+
+        let mut increment = self.increment.layout(renderer, &button_limits);
+        increment.move_to(Point::new(text_input_size.width, 0.0));
+
+        let mut decrement = self.decrement.layout(renderer, &button_limits);
+        decrement
+            .move_to(Point::new(text_input_size.width, button_size.height));
+
+        layout::Node::with_children(
+            Size::new(
+                text_input_size.width + buttons_width,
+                text_input_size.height,
+            ),
+            vec![text_input, increment, decrement],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut children = layout.children();
+        let text_input_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+
+        let mut internal = Vec::new();
+
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.text_input.state().is_focused() => match key_code {
+                keyboard::KeyCode::Up => internal.push(Internal::Increment),
+                keyboard::KeyCode::Down => internal.push(Internal::Decrement),
+                _ => {}
+            },
+            _ => {}
+        }
+
+        self.text_input.on_event(
+            event.clone(),
+            text_input_layout,
+            cursor_position,
+            &mut internal,
+            renderer,
+            clipboard,
+        );
+
+        self.increment.on_event(
+            event.clone(),
+            increment_layout,
+            cursor_position,
+            &mut internal,
+            renderer,
+            clipboard,
+        );
+
+        self.decrement.on_event(
+            event,
+            decrement_layout,
+            cursor_position,
+            &mut internal,
+            renderer,
+            clipboard,
+        );
+
+        for message in internal {
+            let value = match message {
+                Internal::Changed(text) => {
+                    let value = text.parse::<T>().ok();
+
+                    *self.text = Some(text);
+
+                    match value {
+                        Some(value) if self.contains(value) => value,
+                        _ => continue,
+                    }
+                }
+                Internal::Increment => {
+                    *self.text = None;
+
+                    self.incremented()
+                }
+                Internal::Decrement => {
+                    *self.text = None;
+
+                    self.decremented()
+                }
+            };
+
+            self.value = value;
+
+            messages.push((self.on_change)(value));
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+
+        let text_input = self.text_input.draw(
+            renderer,
+            defaults,
+            children.next().unwrap(),
+            cursor_position,
+        );
+
+        let increment = self.increment.draw(
+            renderer,
+            defaults,
+            children.next().unwrap(),
+            cursor_position,
+        );
+
+        let decrement = self.decrement.draw(
+            renderer,
+            defaults,
+            children.next().unwrap(),
+            cursor_position,
+        );
+
+        self::Renderer::draw(renderer, text_input, increment, decrement)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::{any::TypeId, hash::Hash};
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.text_input.hash_layout(state);
+        self.increment.hash_layout(state);
+        self.decrement.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`NumberInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`NumberInput`] in your user interface.
+///
+/// [`NumberInput`]: struct.NumberInput.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer:
+    text_input::Renderer + button::Renderer + text::Renderer + Sized
+{
+    /// Draws a [`NumberInput`], given the output of its field and its
+    /// buttons.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    fn draw(
+        &mut self,
+        text_input: Self::Output,
+        increment: Self::Output,
+        decrement: Self::Output,
+    ) -> Self::Output;
+}
+
+/// The local state of a [`NumberInput`].
+///
+/// [`NumberInput`]: struct.NumberInput.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    text_input: text_input::State,
+    increment: button::State,
+    decrement: button::State,
+    text: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the field of the [`NumberInput`] is currently focused
+    /// or not.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a
+        + Copy
+        + PartialOrd
+        + From<u8>
+        + FromStr
+        + ToString
+        + Bounded
+        + Zero
+        + Sub<Output = T>,
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+/// Returns a character filter for numbers of type `T`, which only allows a
+/// minus sign or a decimal point if `T` can parse them.
+fn filter<T: FromStr>() -> Box<dyn Fn(char) -> bool> {
+    let is_signed = "-1".parse::<T>().is_ok();
+    let is_fractional = "0.5".parse::<T>().is_ok();
+
+    Box::new(move |c| {
+        c.is_ascii_digit()
+            || (c == '-' && is_signed)
+            || (c == '.' && is_fractional)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    #[test]
+    fn unbounded_unsigned_input_does_not_overflow() {
+        let mut state = State::new();
+        let input = NumberInput::<_, _, Null>::new(&mut state, 0u32, |n| n);
+        assert_eq!(input.decremented(), 0);
+        assert_eq!(input.incremented(), 1);
+
+        let mut state = State::new();
+        let input = NumberInput::<_, _, Null>::new(&mut state, u32::MAX, |n| n);
+        assert_eq!(input.incremented(), u32::MAX);
+        assert_eq!(input.decremented(), u32::MAX - 1);
+    }
+
+    #[test]
+    fn unbounded_unsigned_values_saturate() {
+        assert_eq!(saturating_sub(0u32, 1, u32::MIN), 0);
+        assert_eq!(saturating_sub(3u32, 5, u32::MIN), 0);
+        assert_eq!(saturating_add(u32::MAX, 1, u32::MAX), u32::MAX);
+        assert_eq!(saturating_add(u32::MAX - 1, 5, u32::MAX), u32::MAX);
+        assert_eq!(saturating_add(7u32, 1, u32::MAX), 8);
+        assert_eq!(saturating_sub(7u32, 1, u32::MIN), 6);
+    }
+
+    #[test]
+    fn signed_values_saturate_across_zero() {
+        assert_eq!(saturating_add(-5i32, 1, i32::MAX), -4);
+        assert_eq!(saturating_sub(5i32, 1, i32::MIN), 4);
+        assert_eq!(saturating_add(i32::MIN, i32::MAX, i32::MAX), -1);
+        assert_eq!(saturating_sub(i32::MAX, i32::MAX, i32::MIN), 0);
+        assert_eq!(saturating_sub(i32::MIN + 1, 2, i32::MIN), i32::MIN);
+    }
+
+    #[test]
+    fn bounded_values_stop_at_the_bounds() {
+        assert_eq!(saturating_add(9u8, 5, 10), 10);
+        assert_eq!(saturating_add(12u8, 1, 10), 10);
+        assert_eq!(saturating_sub(2i8, 5, -1), -1);
+        assert_eq!(saturating_add(0.5f32, 0.25, 1.0), 0.75);
+        assert_eq!(saturating_sub(-0.5f32, 1.0, -1.0), -1.0);
+    }
+
+    #[test]
+    fn filters_characters_by_number_type() {
+        let unsigned = filter::<u32>();
+        assert!(unsigned('7') && !unsigned('-') && !unsigned('.'));
+
+        let signed = filter::<i64>();
+        assert!(signed('7') && signed('-') && !signed('.'));
+
+        let float = filter::<f32>();
+        assert!(float('7') && float('-') && float('.') && !float('e'));
+    }
+}
@@ -536,8 +536,8 @@ where
                 }
                 _ => {}
             },
-            Event::Keyboard(keyboard_event) => {
-                match keyboard_event {
+            Event::Keyboard(ref keyboard_event) => {
+                match *keyboard_event {
                     keyboard::Event::KeyPressed {
                         modifiers,
                         key_code,
@@ -1,6 +1,6 @@
 //! Display a dropdown list of selectable values.
 use crate::{
-    layout, mouse, overlay,
+    keyboard, layout, mouse, overlay,
     overlay::menu::{self, Menu},
     scrollable, text, Clipboard, Element, Event, Hasher, Layout, Length, Point,
     Rectangle, Size, Widget,
@@ -8,6 +8,11 @@ use crate::{
 use std::borrow::Cow;
 
 /// A widget for selecting a single value from a list of options.
+///
+/// Once open, the options of a [`PickList`] can be browsed with the keyboard
+/// and `Escape` closes them.
+///
+/// [`PickList`]: struct.PickList.html
 #[allow(missing_debug_implementations)]
 pub struct PickList<'a, T, Message, Renderer: self::Renderer>
 where
@@ -23,6 +28,7 @@ where
     width: Length,
     padding: u16,
     text_size: Option<u16>,
+    menu_max_height: Option<u32>,
     font: Renderer::Font,
     style: <Renderer as self::Renderer>::Style,
 }
@@ -84,6 +90,7 @@ where
             selected,
             width: Length::Shrink,
             text_size: None,
+            menu_max_height: None,
             padding: Renderer::DEFAULT_PADDING,
             font: Default::default(),
             style: Default::default(),
@@ -114,6 +121,16 @@ where
         self
     }
 
+    /// Sets the maximum height of the menu of the [`PickList`].
+    ///
+    /// The options that do not fit can be scrolled.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn menu_max_height(mut self, max_height: u32) -> Self {
+        self.menu_max_height = Some(max_height);
+        self
+    }
+
     /// Sets the font of the [`PickList`].
     ///
     /// [`PickList`]: struct.PickList.html
@@ -239,15 +256,22 @@ where
                         .iter()
                         .position(|option| Some(option) == selected);
                 }
-
-                if let Some(last_selection) = self.last_selection.take() {
-                    messages.push((self.on_selected)(last_selection));
-
-                    *self.is_open = false;
-                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                *self.is_open = false;
             }
             _ => {}
         }
+
+        // The menu picks an option on click or when `Enter` is pressed
+        if let Some(last_selection) = self.last_selection.take() {
+            messages.push((self.on_selected)(last_selection));
+
+            *self.is_open = false;
+        }
     }
 
     fn draw(
@@ -291,6 +315,10 @@ where
                 menu = menu.text_size(text_size);
             }
 
+            if let Some(max_height) = self.menu_max_height {
+                menu = menu.max_height(max_height);
+            }
+
             Some(menu.overlay(layout.position(), bounds.height))
         } else {
             None
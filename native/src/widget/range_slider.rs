@@ -0,0 +1,564 @@
+//! Display an interactive selector of a range of values, with two handles.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+//!
+//! [`RangeSlider`]: struct.RangeSlider.html
+//! [`State`]: struct.State.html
+use crate::slider::{self, Orientation};
+use crate::{
+    keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::{hash::Hash, ops::RangeInclusive};
+
+/// A bar with two handles that select a minimum and a maximum value from a
+/// range of values.
+///
+/// A [`RangeSlider`] behaves like a [`Slider`], but produces both of its
+/// values every time one of its handles is moved. A handle can never cross the
+/// other one.
+///
+/// When focused, the keyboard moves the handle that was grabbed last.
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+/// [`Slider`]: ../slider/struct.Slider.html
+///
+/// # Example
+/// ```
+/// # use iced_native::{range_slider, renderer::Null};
+/// #
+/// # pub type RangeSlider<'a, T, Message> =
+/// #     iced_native::RangeSlider<'a, T, Message, Null>;
+/// pub enum Message {
+///     PriceFilterChanged(u32, u32),
+/// }
+///
+/// let state = &mut range_slider::State::new();
+///
+/// RangeSlider::new(state, 0..=500, (20, 150), Message::PriceFilterChanged)
+///     .step(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    range: RangeInclusive<T>,
+    step: T,
+    values: (T, T),
+    on_change: Box<dyn Fn(T, T) -> Message>,
+    on_release: Option<Message>,
+    orientation: Orientation,
+    width: Option<Length>,
+    height: Option<Length>,
+    style: Renderer::Style,
+}
+
+impl<'a, T, Message, Renderer> RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RangeSlider`]
+    ///   * an inclusive range of possible values
+    ///   * the current lower and upper values of the [`RangeSlider`]
+    ///   * a function that will be called when any of the handles of the
+    ///     [`RangeSlider`] is dragged. It receives the new lower and upper
+    ///     values and must produce a `Message`.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        values: (T, T),
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T, T) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (lower, upper) = (clamp(values.0), clamp(values.1));
+
+        let values = if lower <= upper {
+            (lower, upper)
+        } else {
+            (upper, lower)
+        };
+
+        RangeSlider {
+            state,
+            range,
+            step: T::from(1),
+            values,
+            on_change: Box::new(on_change),
+            on_release: None,
+            orientation: Orientation::Horizontal,
+            width: None,
+            height: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when a handle is released, or when a key that moved a
+    /// focused slider is released.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`Orientation`]: ../slider/enum.Orientation.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(Length::Units(height));
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+/// One of the two handles of a [`RangeSlider`].
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    /// The handle of the lower value.
+    Lower,
+
+    /// The handle of the upper value.
+    Upper,
+}
+
+/// The local state of a [`RangeSlider`].
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    dragging: Option<Bound>,
+    last_grabbed: Bound,
+    is_overlap_grabbed: bool,
+    is_focused: bool,
+    is_key_pressed: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            dragging: None,
+            last_grabbed: Bound::Lower,
+            is_overlap_grabbed: false,
+            is_focused: false,
+            is_key_pressed: false,
+        }
+    }
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`RangeSlider`] is currently focused or not.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Unfocuses the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, T, Message, Renderer> RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Renderer: self::Renderer,
+{
+    fn size(&self) -> (Length, Length) {
+        self.orientation
+            .size(self.width, self.height, Renderer::DEFAULT_HEIGHT)
+    }
+
+    /// Produces the values of the slider once the given handle is moved to
+    /// the given value, keeping it from crossing the other handle.
+    fn change(&self, bound: Bound, value: T, messages: &mut Vec<Message>) {
+        let (lower, upper) = self.values;
+
+        let (lower, upper) = match bound {
+            Bound::Lower if value.into() > upper.into() => (upper, upper),
+            Bound::Lower => (value, upper),
+            Bound::Upper if value.into() < lower.into() => (lower, lower),
+            Bound::Upper => (lower, value),
+        };
+
+        if lower.into() != self.values.0.into()
+            || upper.into() != self.values.1.into()
+        {
+            messages.push((self.on_change)(lower, upper));
+        }
+    }
+
+    /// Returns the handle that should be grabbed when clicking the given
+    /// value.
+    ///
+    /// Overlapping handles are pulled apart in the direction of the click.
+    /// When the click lands right on them, the handle is picked once the
+    /// cursor moves; see [`is_overlap_grabbed`].
+    ///
+    /// [`is_overlap_grabbed`]: #method.is_overlap_grabbed
+    fn closest(&self, value: T) -> Bound {
+        let value = value.into();
+        let (lower, upper) = (self.values.0.into(), self.values.1.into());
+
+        if value <= lower {
+            Bound::Lower
+        } else if value >= upper || value - lower >= upper - value {
+            Bound::Upper
+        } else {
+            Bound::Lower
+        }
+    }
+
+    /// Returns whether the given value lies right on both handles, when they
+    /// overlap.
+    fn is_overlap_grabbed(&self, value: T) -> bool {
+        let value = value.into();
+
+        value == self.values.0.into() && value == self.values.1.into()
+    }
+
+    fn value(&self, bound: Bound) -> T {
+        match bound {
+            Bound::Lower => self.values.0,
+            Bound::Upper => self.values.1,
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for RangeSlider<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.size().0
+    }
+
+    fn height(&self) -> Length {
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.size().1,
+        }
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = self.size();
+        let limits = limits.width(width).height(height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let percent = self.orientation.percent(bounds, cursor_position);
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    let is_clicked = bounds.contains(cursor_position);
+
+                    if is_clicked {
+                        if let Some(value) =
+                            slider::value_at(&self.range, self.step, percent)
+                        {
+                            let bound = self.closest(value);
+
+                            self.state.dragging = Some(bound);
+                            self.state.last_grabbed = bound;
+                            self.state.is_overlap_grabbed =
+                                self.is_overlap_grabbed(value);
+
+                            self.change(bound, value, messages);
+                        }
+                    }
+
+                    self.state.is_focused = is_clicked;
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Left)
+                    if self.state.dragging.is_some() =>
+                {
+                    if let Some(on_release) = self.on_release.clone() {
+                        messages.push(on_release);
+                    }
+
+                    self.state.dragging = None;
+                    self.state.is_overlap_grabbed = false;
+                }
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(bound) = self.state.dragging {
+                        if let Some(value) =
+                            slider::value_at(&self.range, self.step, percent)
+                        {
+                            let bound = if self.state.is_overlap_grabbed {
+                                // Pick the handle in the direction of the
+                                // first movement
+                                if value.into() == self.values.0.into() {
+                                    return;
+                                }
+
+                                let bound = self.closest(value);
+
+                                self.state.dragging = Some(bound);
+                                self.state.last_grabbed = bound;
+                                self.state.is_overlap_grabbed = false;
+
+                                bound
+                            } else {
+                                bound
+                            };
+
+                            self.change(bound, value, messages);
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                if let Some(steps) = slider::key_steps(key_code) {
+                    let bound = self.state.last_grabbed;
+
+                    if let Some(value) = slider::nudge(
+                        &self.range,
+                        self.step,
+                        self.value(bound),
+                        steps,
+                    ) {
+                        self.change(bound, value, messages);
+                    }
+
+                    self.state.is_key_pressed = true;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code, ..
+            }) if self.state.is_key_pressed
+                && slider::key_steps(key_code).is_some() =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    messages.push(on_release);
+                }
+
+                self.state.is_key_pressed = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let start = *self.range.start();
+        let end = *self.range.end();
+
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            start.into() as f32..=end.into() as f32,
+            (self.values.0.into() as f32, self.values.1.into() as f32),
+            self.state.dragging,
+            self.orientation,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.orientation.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`RangeSlider`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RangeSlider`] in your user interface.
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default thickness of a [`RangeSlider`]: its height when
+    /// horizontal and its width when vertical.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    const DEFAULT_HEIGHT: u16;
+
+    /// Draws a [`RangeSlider`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RangeSlider`]
+    ///   * the current cursor position
+    ///   * the range of values of the [`RangeSlider`]
+    ///   * the current lower and upper values of the [`RangeSlider`]
+    ///   * the handle being dragged, if any
+    ///   * the [`Orientation`] of the [`RangeSlider`]
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    /// [`Orientation`]: ../slider/enum.Orientation.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        range: RangeInclusive<f32>,
+        values: (f32, f32),
+        dragging: Option<Bound>,
+        orientation: Orientation,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(range_slider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn drag(from: f32, to: f32) -> Vec<(u32, u32)> {
+        let mut state = State::new();
+        let mut slider = RangeSlider::<_, _, Null>::new(
+            &mut state,
+            0..=100,
+            (50, 50),
+            |lower, upper| (lower, upper),
+        );
+
+        let node = layout::Node::new(Size::new(100.0, 20.0));
+        let mut messages = Vec::new();
+
+        for (event, x) in &[
+            (mouse::Event::ButtonPressed(mouse::Button::Left), from),
+            (mouse::Event::CursorMoved { x: to, y: 10.0 }, to),
+        ] {
+            slider.on_event(
+                Event::Mouse(*event),
+                Layout::new(&node),
+                Point::new(*x, 10.0),
+                &mut messages,
+                &Null,
+                None,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn overlapping_handles_are_pulled_apart_to_the_right() {
+        assert_eq!(drag(50.0, 70.0), vec![(50, 70)]);
+    }
+
+    #[test]
+    fn overlapping_handles_are_pulled_apart_to_the_left() {
+        assert_eq!(drag(50.0, 30.0), vec![(30, 50)]);
+    }
+
+    #[test]
+    fn clicks_next_to_overlapping_handles_pull_them_apart() {
+        assert_eq!(drag(80.0, 90.0), vec![(50, 80), (50, 90)]);
+        assert_eq!(drag(20.0, 10.0), vec![(20, 50), (10, 50)]);
+    }
+}
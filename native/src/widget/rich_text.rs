@@ -0,0 +1,630 @@
+//! Write a paragraph of text made of differently styled spans.
+use crate::{
+    layout, text, Color, Element, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A paragraph of text made of [`Span`]s with their own style.
+///
+/// The spans are laid out one after another, wrapping between words when
+/// they do not fit the available width. Words that do not fit in a line on
+/// their own are broken between graphemes.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{rich_text::Span, Color};
+/// # type RichText = iced_native::RichText<iced_native::renderer::Null>;
+/// #
+/// RichText::new()
+///     .push(Span::new("Found "))
+///     .push(Span::new("iced").highlight(Color::from_rgb(1.0, 1.0, 0.0)))
+///     .push(Span::new(" in 3 files"))
+///     .size(20);
+/// ```
+///
+/// [`Span`]: struct.Span.html
+#[allow(missing_debug_implementations)]
+pub struct RichText<Renderer: self::Renderer> {
+    spans: Vec<Span<Renderer::Font>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+}
+
+impl<Renderer: self::Renderer> RichText<Renderer> {
+    /// Creates an empty [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn new() -> Self {
+        Self::with_spans(Vec::new())
+    }
+
+    /// Creates a [`RichText`] with the given spans.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn with_spans(spans: Vec<Span<Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Adds a [`Span`] to the [`RichText`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn push(mut self, span: Span<Renderer::Font>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Color`] of the spans of the [`RichText`].
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the default font of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Breaks the spans of the [`RichText`] into runs that fit the given
+    /// width, returning them along with the size of the whole paragraph.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    fn runs(
+        &self,
+        renderer: &Renderer,
+        max_width: f32,
+    ) -> (Vec<Run<Renderer::Font>>, Size) {
+        self.layout_spans(
+            renderer.default_size(),
+            max_width,
+            |content, size, font| {
+                renderer.measure(content, size, font, Size::INFINITY)
+            },
+        )
+    }
+
+    fn layout_spans(
+        &self,
+        default_size: u16,
+        max_width: f32,
+        measure: impl Fn(&str, u16, Renderer::Font) -> (f32, f32),
+    ) -> (Vec<Run<Renderer::Font>>, Size) {
+        let mut paragraph = Paragraph::default();
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let size = span.size.or(self.size).unwrap_or(default_size);
+
+            let style = Run {
+                content: String::new(),
+                bounds: Rectangle::default(),
+                size,
+                font: span.font.unwrap_or(self.font),
+                color: span.color.or(self.color),
+                underline: span.underline,
+                strikethrough: span.strikethrough,
+                highlight: span.highlight,
+            };
+
+            for (i, line) in span.content.split('\n').enumerate() {
+                if i > 0 {
+                    paragraph.break_line(f32::from(size));
+                }
+
+                for word in line.split_word_bounds() {
+                    let (width, height) = measure(word, size, style.font);
+                    let is_whitespace = word.trim().is_empty();
+
+                    if paragraph.x + width > max_width
+                        && paragraph.x > 0.0
+                        && !is_whitespace
+                    {
+                        paragraph.wrap();
+                    }
+
+                    if width > max_width && !is_whitespace {
+                        // The word does not fit in a line on its own
+                        for grapheme in word.graphemes(true) {
+                            let (width, height) =
+                                measure(grapheme, size, style.font);
+
+                            if paragraph.x + width > max_width
+                                && paragraph.x > 0.0
+                            {
+                                paragraph.wrap();
+                            }
+
+                            paragraph
+                                .push(index, &style, grapheme, width, height);
+                        }
+                    } else if paragraph.x > 0.0
+                        || !paragraph.is_wrapped
+                        || !is_whitespace
+                    {
+                        // Wrapped lines do not start with whitespace
+                        paragraph.push(index, &style, word, width, height);
+                    }
+                }
+            }
+        }
+
+        paragraph.finish()
+    }
+}
+
+impl<Renderer: self::Renderer> Default for RichText<Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The state of a [`RichText`] while its spans are being laid out.
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug)]
+struct Paragraph<Font> {
+    runs: Vec<Run<Font>>,
+    line_start: usize,
+    line_height: f32,
+    last_span: Option<usize>,
+    is_wrapped: bool,
+    x: f32,
+    y: f32,
+    width: f32,
+}
+
+impl<Font> Default for Paragraph<Font> {
+    fn default() -> Self {
+        Paragraph {
+            runs: Vec::new(),
+            line_start: 0,
+            line_height: 0.0,
+            last_span: None,
+            is_wrapped: false,
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+        }
+    }
+}
+
+impl<Font> Paragraph<Font> {
+    fn finish_line(&mut self) {
+        // Runs are aligned to the bottom of their line
+        for run in &mut self.runs[self.line_start..] {
+            run.bounds.y = self.y + self.line_height - run.bounds.height;
+        }
+
+        self.width = self.width.max(self.x);
+        self.y += self.line_height;
+
+        self.line_start = self.runs.len();
+        self.line_height = 0.0;
+        self.last_span = None;
+        self.x = 0.0;
+    }
+
+    fn push(
+        &mut self,
+        span: usize,
+        style: &Run<Font>,
+        content: &str,
+        width: f32,
+        height: f32,
+    ) where
+        Font: Copy,
+    {
+        match self.runs.last_mut() {
+            Some(run) if self.last_span == Some(span) => {
+                run.content.push_str(content);
+                run.bounds.width += width;
+                run.bounds.height = run.bounds.height.max(height);
+            }
+            _ => {
+                self.runs.push(Run {
+                    content: content.to_string(),
+                    bounds: Rectangle {
+                        x: self.x,
+                        y: 0.0,
+                        width,
+                        height,
+                    },
+                    ..style.clone()
+                });
+
+                self.last_span = Some(span);
+            }
+        }
+
+        self.x += width;
+        self.line_height = self.line_height.max(height);
+    }
+
+    fn wrap(&mut self) {
+        self.finish_line();
+        self.is_wrapped = true;
+    }
+
+    fn break_line(&mut self, min_height: f32) {
+        self.line_height = self.line_height.max(min_height);
+        self.finish_line();
+        self.is_wrapped = false;
+    }
+
+    fn finish(mut self) -> (Vec<Run<Font>>, Size) {
+        if self.runs.len() > self.line_start {
+            self.finish_line();
+        }
+
+        (self.runs, Size::new(self.width, self.y))
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for RichText<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let (_, size) = self.runs(renderer, limits.max().width);
+
+        layout::Node::new(limits.resolve(size))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let (mut runs, _) = self.runs(renderer, bounds.width);
+
+        for run in &mut runs {
+            run.bounds.x += bounds.x;
+            run.bounds.y += bounds.y;
+        }
+
+        self::Renderer::draw(renderer, defaults, bounds, &runs)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+        }
+
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// A fragment of text with its own style in a [`RichText`].
+///
+/// Any property that is not set falls back to the one of the [`RichText`].
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<Font> {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Option<Font>,
+    underline: bool,
+    strikethrough: bool,
+    highlight: Option<Color>,
+}
+
+impl<Font> Span<Font> {
+    /// Creates a new [`Span`] with the given contents.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            underline: false,
+            strikethrough: false,
+            highlight: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Span`]: struct.Span.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Underlines the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Strikes through the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Highlights the [`Span`] with the given background [`Color`].
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Span`]: struct.Span.html
+    pub fn highlight<C: Into<Color>>(mut self, color: C) -> Self {
+        self.highlight = Some(color.into());
+        self
+    }
+}
+
+/// A piece of a [`Span`] laid out in a single line of a [`RichText`].
+///
+/// [`Span`]: struct.Span.html
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run<Font> {
+    /// The text of the [`Run`].
+    ///
+    /// [`Run`]: struct.Run.html
+    pub content: String,
+
+    /// The bounds of the [`Run`].
+    ///
+    /// [`Run`]: struct.Run.html
+    pub bounds: Rectangle,
+
+    /// The text size of the [`Run`].
+    ///
+    /// [`Run`]: struct.Run.html
+    pub size: u16,
+
+    /// The font of the [`Run`].
+    ///
+    /// [`Run`]: struct.Run.html
+    pub font: Font,
+
+    /// The color of the [`Run`], if different from the default one.
+    ///
+    /// [`Run`]: struct.Run.html
+    pub color: Option<Color>,
+
+    /// Whether the [`Run`] is underlined.
+    ///
+    /// [`Run`]: struct.Run.html
+    pub underline: bool,
+
+    /// Whether the [`Run`] is struck through.
+    ///
+    /// [`Run`]: struct.Run.html
+    pub strikethrough: bool,
+
+    /// The background color of the [`Run`], if highlighted.
+    ///
+    /// [`Run`]: struct.Run.html
+    pub highlight: Option<Color>,
+}
+
+/// The renderer of a [`RichText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RichText`] in your user interface.
+///
+/// [`RichText`]: struct.RichText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws a [`RichText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RichText`]
+    ///   * the [`Run`]s of text to draw, already positioned
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Run`]: struct.Run.html
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        runs: &[Run<Self::Font>],
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RichText<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + 'a,
+{
+    fn from(rich_text: RichText<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Font};
+
+    fn measure(content: &str, size: u16, _font: Font) -> (f32, f32) {
+        (content.chars().count() as f32 * 10.0, f32::from(size))
+    }
+
+    fn layout(
+        rich_text: RichText<Null>,
+        max_width: f32,
+    ) -> Vec<(String, f32, f32)> {
+        let (runs, _) = rich_text.layout_spans(10, max_width, measure);
+
+        runs.into_iter()
+            .map(|run| (run.content, run.bounds.x, run.bounds.y))
+            .collect()
+    }
+
+    fn run(content: &str, x: f32, y: f32) -> (String, f32, f32) {
+        (content.to_string(), x, y)
+    }
+
+    #[test]
+    fn spans_are_laid_out_one_after_another() {
+        let rich_text = RichText::<Null>::new()
+            .push(Span::new("Found "))
+            .push(Span::new("iced").size(20))
+            .push(Span::new(" in 3 files"));
+
+        let (runs, size) = rich_text.layout_spans(10, 1000.0, measure);
+
+        assert_eq!(size, Size::new(210.0, 20.0));
+        assert_eq!(
+            runs.iter()
+                .map(|run| (run.content.as_str(), run.bounds))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "Found ",
+                    Rectangle {
+                        x: 0.0,
+                        y: 10.0,
+                        width: 60.0,
+                        height: 10.0
+                    }
+                ),
+                (
+                    "iced",
+                    Rectangle {
+                        x: 60.0,
+                        y: 0.0,
+                        width: 40.0,
+                        height: 20.0
+                    }
+                ),
+                (
+                    " in 3 files",
+                    Rectangle {
+                        x: 100.0,
+                        y: 10.0,
+                        width: 110.0,
+                        height: 10.0
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_wrap_between_words() {
+        let rich_text = RichText::new()
+            .push(Span::new("aaa bb"))
+            .push(Span::new("b cc\ndd"));
+
+        assert_eq!(
+            layout(rich_text, 50.0),
+            vec![
+                run("aaa ", 0.0, 0.0),
+                run("bb", 0.0, 10.0),
+                run("b ", 20.0, 10.0),
+                run("cc", 0.0, 20.0),
+                run("dd", 0.0, 30.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn long_words_are_broken_between_graphemes() {
+        let rich_text = RichText::new().push(Span::new("ab ñandúes"));
+
+        assert_eq!(
+            layout(rich_text, 30.0),
+            vec![
+                run("ab ", 0.0, 0.0),
+                run("ñan", 0.0, 10.0),
+                run("dúe", 0.0, 20.0),
+                run("s", 0.0, 30.0),
+            ]
+        );
+    }
+}
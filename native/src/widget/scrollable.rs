@@ -1,18 +1,31 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::{
-    column, layout, mouse, overlay, Align, Clipboard, Column, Element, Event,
-    Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    column, keyboard, layout, mouse, overlay, window, Align, Clipboard, Column,
+    Element, Event, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
+    Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{f32, hash::Hash, u32};
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// The default amount of pixels scrolled for every line of a mouse wheel.
+pub const LINE_HEIGHT: u16 = 60;
+
+/// A widget that can display an infinite amount of content with scrollbars.
+///
+/// By default, it scrolls vertically. Use [`direction`] to scroll
+/// horizontally or in both directions.
+///
+/// [`direction`]: #method.direction
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     height: Length,
     max_height: u32,
+    direction: Direction,
+    line_height: u16,
+    is_smooth: bool,
+    on_scroll: Option<Box<dyn Fn(RelativeOffset) -> Message + 'a>>,
     content: Column<'a, Message, Renderer>,
     style: Renderer::Style,
 }
@@ -27,6 +40,10 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
             state,
             height: Length::Shrink,
             max_height: u32::MAX,
+            direction: Direction::default(),
+            line_height: LINE_HEIGHT,
+            is_smooth: false,
+            on_scroll: None,
             content: Column::new(),
             style: Renderer::Style::default(),
         }
@@ -82,6 +99,49 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`Direction`] in which the [`Scrollable`] scrolls.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the distance, in pixels, that the [`Scrollable`] scrolls for
+    /// every line of a mouse wheel. By default, it is [`LINE_HEIGHT`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`LINE_HEIGHT`]: constant.LINE_HEIGHT.html
+    pub fn line_height(mut self, line_height: u16) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Enables smooth scrolling in the [`Scrollable`].
+    ///
+    /// Mouse wheel scrolling is animated, and touchpad scrolling keeps some
+    /// momentum after the fingers are lifted.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn smooth(mut self, is_smooth: bool) -> Self {
+        self.is_smooth = is_smooth;
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Scrollable`] is
+    /// scrolled, given its new [`RelativeOffset`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`RelativeOffset`]: struct.RelativeOffset.html
+    pub fn on_scroll<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(RelativeOffset) -> Message,
+    {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -133,9 +193,21 @@ where
             .width(Widget::<Message, Renderer>::width(&self.content))
             .height(self.height);
 
+        let max_width = if self.direction.is_horizontal() {
+            f32::INFINITY
+        } else {
+            limits.max().width
+        };
+
+        let max_height = if self.direction.is_vertical() {
+            f32::INFINITY
+        } else {
+            limits.max().height
+        };
+
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
-            Size::new(limits.max().width, f32::INFINITY),
+            Size::new(max_width, max_height),
         );
 
         let content = self.content.layout(renderer, &child_limits);
@@ -159,86 +231,25 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        // TODO: Event capture. Nested scrollables should capture scroll events.
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+        let previous_offset = self.state.offset(bounds, content_bounds);
 
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
-
-        if self.state.is_scroller_grabbed() {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(
-                    mouse::Button::Left,
-                )) => {
-                    self.state.scroller_grabbed_at = None;
-                }
-                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
-                    {
-                        self.state.scroll_to(
-                            scrollbar.scroll_percentage(
-                                scroller_grabbed_at,
-                                cursor_position,
-                            ),
-                            bounds,
-                            content_bounds,
-                        );
-                    }
-                }
-                _ => {}
-            }
-        } else if is_mouse_over_scrollbar {
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(
-                    mouse::Button::Left,
-                )) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
-                        {
-                            self.state.scroll_to(
-                                scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
-                                    cursor_position,
-                                ),
-                                bounds,
-                                content_bounds,
-                            );
-
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+        let is_mouse_over_scrollbar = self.state.update(
+            &event,
+            bounds,
+            content_bounds,
+            cursor_position,
+            self.direction,
+            f32::from(self.line_height),
+            self.is_smooth,
+            renderer,
+        );
 
         let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+            let offset = self.state.offset(bounds, content_bounds);
+
             Point::new(
-                cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
+                cursor_position.x + offset.x as f32,
+                cursor_position.y + offset.y as f32,
             )
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
@@ -248,6 +259,14 @@ where
             Point::new(cursor_position.x, -1.0)
         };
 
+        if let Some(on_scroll) = &self.on_scroll {
+            if self.state.offset(bounds, content_bounds) != previous_offset {
+                messages.push(on_scroll(
+                    self.state.relative_offset(bounds, content_bounds),
+                ));
+            }
+        }
+
         self.content.on_event(
             event,
             content,
@@ -269,27 +288,34 @@ where
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
         let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let scrollbars =
+            renderer.scrollbars(self.direction, bounds, content_bounds, offset);
 
         let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        if self.state.is_animating() {
+            renderer.set_redraw_requested(true);
+        }
 
         let content = {
             let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+                Point::new(
+                    cursor_position.x + offset.x as f32,
+                    cursor_position.y + offset.y as f32,
+                )
             } else {
                 Point::new(cursor_position.x, -1.0)
             };
 
-            self.content.draw(
-                renderer,
-                defaults,
-                content_layout,
-                cursor_position,
-            )
+            draw_content(renderer, offset, |renderer| {
+                self.content.draw(
+                    renderer,
+                    defaults,
+                    content_layout,
+                    cursor_position,
+                )
+            })
         };
 
         self::Renderer::draw(
@@ -299,7 +325,7 @@ where
             content_layout.bounds(),
             is_mouse_over,
             is_mouse_over_scrollbar,
-            scrollbar,
+            scrollbars,
             offset,
             &self.style,
             content,
@@ -312,6 +338,7 @@ where
 
         self.height.hash(state);
         self.max_height.hash(state);
+        self.direction.hash(state);
 
         self.content.hash_layout(state)
     }
@@ -330,28 +357,330 @@ where
                 let content_bounds = content_layout.bounds();
                 let offset = state.offset(bounds, content_bounds);
 
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
+                overlay.translate(Vector::new(
+                    -(offset.x as f32),
+                    -(offset.y as f32),
+                ))
             })
     }
 }
 
+/// The direction in which a [`Scrollable`] can scroll.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The content can only be scrolled vertically.
+    #[default]
+    Vertical,
+
+    /// The content can only be scrolled horizontally.
+    Horizontal,
+
+    /// The content can be scrolled in both directions.
+    Both,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] allows scrolling vertically.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    pub fn is_vertical(self) -> bool {
+        self != Direction::Horizontal
+    }
+
+    /// Returns true if the [`Direction`] allows scrolling horizontally.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    pub fn is_horizontal(self) -> bool {
+        self != Direction::Vertical
+    }
+
+    /// Turns a scrolling delta into one that only moves along the allowed
+    /// axes.
+    ///
+    /// Vertical scrolling moves horizontally when only horizontal scrolling
+    /// is allowed, or when the shift key is pressed.
+    fn align(self, delta: Vector, is_shift_pressed: bool) -> Vector {
+        let delta = if delta.x == 0.0
+            && (is_shift_pressed || self == Direction::Horizontal)
+        {
+            Vector::new(delta.y, 0.0)
+        } else {
+            delta
+        };
+
+        Vector::new(
+            if self.is_horizontal() { delta.x } else { 0.0 },
+            if self.is_vertical() { delta.y } else { 0.0 },
+        )
+    }
+}
+
+/// An axis of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+impl Axis {
+    fn position(self, point: Point) -> f32 {
+        match self {
+            Axis::Vertical => point.y,
+            Axis::Horizontal => point.x,
+        }
+    }
+
+    fn start(self, bounds: Rectangle) -> f32 {
+        match self {
+            Axis::Vertical => bounds.y,
+            Axis::Horizontal => bounds.x,
+        }
+    }
+
+    fn length(self, bounds: Rectangle) -> f32 {
+        match self {
+            Axis::Vertical => bounds.height,
+            Axis::Horizontal => bounds.width,
+        }
+    }
+}
+
+/// The scrolling offset of a [`Scrollable`], relative to the size of its
+/// hidden content.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RelativeOffset {
+    /// The horizontal offset, from `0` at the left to `1` at the right.
+    pub x: f32,
+
+    /// The vertical offset, from `0` at the top to `1` at the bottom.
+    pub y: f32,
+}
+
+/// The duration of an animated scroll.
+const SMOOTH_SCROLLING_DURATION: Duration = Duration::from_millis(150);
+
+/// The time touchpad scrolling must stop before momentum kicks in.
+const MOMENTUM_DELAY: Duration = Duration::from_millis(50);
+
+/// The rate at which momentum decays, per second.
+const MOMENTUM_FRICTION: f32 = 4.0;
+
+/// The speed, in pixels per second, under which momentum stops.
+const MOMENTUM_MIN_SPEED: f32 = 10.0;
+
+/// An animation of the scrolling offset of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy)]
+enum Animation {
+    Smooth {
+        from: Vector,
+        to: Vector,
+        start: Instant,
+    },
+    Momentum {
+        velocity: Vector,
+        last_input: Instant,
+        last_frame: Instant,
+    },
+}
+
+/// Draws the content of a [`Scrollable`] scrolled by the given offset.
+///
+/// The text caret recorded by the content is translated by the offset, so
+/// nested scrollables report it in absolute coordinates.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+pub(crate) fn draw_content<Renderer: crate::Renderer>(
+    renderer: &mut Renderer,
+    offset: Vector<u32>,
+    draw: impl FnOnce(&mut Renderer) -> Renderer::Output,
+) -> Renderer::Output {
+    let caret = renderer.ime_caret();
+    renderer.set_ime_caret(None);
+
+    let content = draw(renderer);
+
+    match renderer.ime_caret() {
+        Some(content_caret) => renderer.set_ime_caret(Some(Rectangle {
+            x: content_caret.x - offset.x as f32,
+            y: content_caret.y - offset.y as f32,
+            ..content_caret
+        })),
+        None => renderer.set_ime_caret(caret),
+    }
+
+    content
+}
+
 /// The local state of a [`Scrollable`].
 ///
 /// [`Scrollable`]: struct.Scrollable.html
 #[derive(Debug, Clone, Copy, Default)]
 pub struct State {
-    scroller_grabbed_at: Option<f32>,
-    offset: f32,
+    scroller_grabbed_at: Option<(Axis, f32)>,
+    keyboard_modifiers: keyboard::ModifiersState,
+    offset: Vector,
+    animation: Option<Animation>,
 }
 
 impl State {
-    /// Creates a new [`State`] with the scrollbar located at the top.
+    /// Creates a new [`State`] with the scrollbars located at the top left.
     ///
     /// [`State`]: struct.State.html
     pub fn new() -> Self {
         State::default()
     }
 
+    /// Processes an [`Event`] that may scroll the contents or grab a
+    /// scrollbar, returning whether the mouse is over a scrollbar.
+    ///
+    /// [`Event`]: ../../enum.Event.html
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn update<Renderer: self::Renderer>(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        cursor_position: Point,
+        direction: Direction,
+        line_height: f32,
+        is_smooth: bool,
+        renderer: &Renderer,
+    ) -> bool {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        match *event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.keyboard_modifiers = modifiers;
+            }
+            Event::Window(window::Event::RedrawRequested) => {
+                self.animate(Instant::now(), bounds, content_bounds);
+            }
+            _ => {}
+        }
+
+        // TODO: Event capture. Nested scrollables should capture scroll events.
+        if is_mouse_over {
+            match *event {
+                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                    let is_shift_pressed = self.keyboard_modifiers.shift;
+
+                    match delta {
+                        mouse::ScrollDelta::Lines { x, y } => {
+                            let delta = direction.align(
+                                Vector::new(x, y) * line_height,
+                                is_shift_pressed,
+                            );
+
+                            if is_smooth {
+                                self.scroll_smoothly(
+                                    delta,
+                                    Instant::now(),
+                                    bounds,
+                                    content_bounds,
+                                );
+                            } else {
+                                self.scroll(delta, bounds, content_bounds);
+                            }
+                        }
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            let delta = direction
+                                .align(Vector::new(x, y), is_shift_pressed);
+
+                            if is_smooth {
+                                self.scroll_with_momentum(
+                                    delta,
+                                    Instant::now(),
+                                    bounds,
+                                    content_bounds,
+                                );
+                            } else {
+                                self.scroll(delta, bounds, content_bounds);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let offset = self.offset(bounds, content_bounds);
+        let scrollbars =
+            renderer.scrollbars(direction, bounds, content_bounds, offset);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        if let Some((axis, scroller_grabbed_at)) = self.scroller_grabbed_at {
+            match *event {
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) => {
+                    self.scroller_grabbed_at = None;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(scrollbar) = scrollbars.get(axis) {
+                        let percentage = scrollbar.scroll_percentage(
+                            axis,
+                            scroller_grabbed_at,
+                            cursor_position,
+                        );
+
+                        self.snap_axis_to(
+                            axis,
+                            percentage,
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        } else if is_mouse_over_scrollbar {
+            match *event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                )) => {
+                    for &axis in &[Axis::Vertical, Axis::Horizontal] {
+                        let grabbed =
+                            scrollbars.get(axis).and_then(|scrollbar| {
+                                scrollbar
+                                    .grab_scroller(axis, cursor_position)
+                                    .map(|grabbed_at| (scrollbar, grabbed_at))
+                            });
+
+                        if let Some((scrollbar, scroller_grabbed_at)) = grabbed
+                        {
+                            self.snap_axis_to(
+                                axis,
+                                scrollbar.scroll_percentage(
+                                    axis,
+                                    scroller_grabbed_at,
+                                    cursor_position,
+                                ),
+                                bounds,
+                                content_bounds,
+                            );
+
+                            self.scroller_grabbed_at =
+                                Some((axis, scroller_grabbed_at));
+
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        is_mouse_over_scrollbar
+    }
+
     /// Apply a scrolling offset to the current [`State`], given the bounds of
     /// the [`Scrollable`] and its contents.
     ///
@@ -359,35 +688,192 @@ impl State {
     /// [`State`]: struct.State.html
     pub fn scroll(
         &mut self,
-        delta_y: f32,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.animation = None;
+        self.offset = clamp(self.offset - delta, bounds, content_bounds);
+    }
+
+    /// Starts an animation that applies a scrolling offset to the current
+    /// [`State`] over a short period of time.
+    ///
+    /// Consecutive calls accumulate their offsets.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn scroll_smoothly(
+        &mut self,
+        delta: Vector,
+        now: Instant,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        if bounds.height >= content_bounds.height {
-            return;
+        let target = match self.animation {
+            Some(Animation::Smooth { to, .. }) => to,
+            _ => self.offset,
+        };
+
+        self.animation = Some(Animation::Smooth {
+            from: self.offset,
+            to: clamp(target - delta, bounds, content_bounds),
+            start: now,
+        });
+    }
+
+    /// Applies a scrolling offset to the current [`State`], keeping the
+    /// scrolling speed as momentum once the offsets stop coming.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn scroll_with_momentum(
+        &mut self,
+        delta: Vector,
+        now: Instant,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let elapsed = match self.animation {
+            Some(Animation::Momentum { last_input, .. }) => {
+                (now - last_input).as_secs_f32()
+            }
+            _ => 1.0 / 60.0,
+        };
+
+        self.offset = clamp(self.offset - delta, bounds, content_bounds);
+
+        self.animation = Some(Animation::Momentum {
+            velocity: delta * (1.0 / elapsed.clamp(1.0 / 240.0, 0.1)),
+            last_input: now,
+            last_frame: now,
+        });
+    }
+
+    /// Advances the current animation of the [`State`], if any.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn animate(
+        &mut self,
+        now: Instant,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        match self.animation {
+            Some(Animation::Smooth { from, to, start }) => {
+                let progress = (now - start).as_secs_f32()
+                    / SMOOTH_SCROLLING_DURATION.as_secs_f32();
+
+                if progress >= 1.0 {
+                    self.offset = to;
+                    self.animation = None;
+                } else {
+                    // Ease out cubic
+                    let eased = 1.0 - (1.0 - progress).powi(3);
+
+                    self.offset = from + (to - from) * eased;
+                }
+            }
+            Some(Animation::Momentum {
+                velocity,
+                last_input,
+                last_frame,
+            }) => {
+                if now - last_input < MOMENTUM_DELAY {
+                    self.animation = Some(Animation::Momentum {
+                        velocity,
+                        last_input,
+                        last_frame: now,
+                    });
+
+                    return;
+                }
+
+                let elapsed = (now - last_frame).as_secs_f32();
+                let offset = clamp(
+                    self.offset - velocity * elapsed,
+                    bounds,
+                    content_bounds,
+                );
+                let velocity = velocity * (-MOMENTUM_FRICTION * elapsed).exp();
+
+                let is_moving = offset != self.offset || elapsed == 0.0;
+                let speed = velocity.x.hypot(velocity.y);
+
+                self.offset = offset;
+                self.animation = if is_moving && speed > MOMENTUM_MIN_SPEED {
+                    Some(Animation::Momentum {
+                        velocity,
+                        last_input,
+                        last_frame: now,
+                    })
+                } else {
+                    None
+                };
+            }
+            None => {}
         }
+    }
 
-        self.offset = (self.offset - delta_y)
-            .max(0.0)
-            .min((content_bounds.height - bounds.height) as f32);
+    /// Returns whether the [`State`] is currently animating its scrolling
+    /// offset.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some()
     }
 
-    /// Moves the scroll position to a relative amount, given the bounds of
+    /// Moves the scroll position to the given offset, given the bounds of
     /// the [`Scrollable`] and its contents.
     ///
-    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
-    /// the bottom.
-    ///
     /// [`Scrollable`]: struct.Scrollable.html
-    /// [`State`]: struct.State.html
     pub fn scroll_to(
         &mut self,
+        offset: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.animation = None;
+        self.offset = clamp(offset, bounds, content_bounds);
+    }
+
+    /// Moves the scroll position to a relative amount on each axis, given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// `0` represents scrollbars at the top left, while `1` represents
+    /// scrollbars at the bottom right.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn snap_to(
+        &mut self,
+        percentage: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.snap_axis_to(
+            Axis::Horizontal,
+            percentage.x,
+            bounds,
+            content_bounds,
+        );
+        self.snap_axis_to(Axis::Vertical, percentage.y, bounds, content_bounds);
+    }
+
+    fn snap_axis_to(
+        &mut self,
+        axis: Axis,
         percentage: f32,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        self.offset =
-            ((content_bounds.height - bounds.height) * percentage).max(0.0);
+        let offset = ((axis.length(content_bounds) - axis.length(bounds))
+            * percentage)
+            .max(0.0);
+
+        self.animation = None;
+
+        match axis {
+            Axis::Vertical => self.offset.y = offset,
+            Axis::Horizontal => self.offset.x = offset,
+        }
     }
 
     /// Returns the current scrolling offset of the [`State`], given the bounds
@@ -395,20 +881,150 @@ impl State {
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
-    pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        let hidden_content =
+    pub fn offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector<u32> {
+        let hidden_width =
+            (content_bounds.width - bounds.width).max(0.0).round() as u32;
+        let hidden_height =
             (content_bounds.height - bounds.height).max(0.0).round() as u32;
 
-        self.offset.min(hidden_content as f32) as u32
+        Vector::new(
+            self.offset.x.min(hidden_width as f32) as u32,
+            self.offset.y.min(hidden_height as f32) as u32,
+        )
+    }
+
+    /// Returns the current [`RelativeOffset`] of the [`State`], given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`RelativeOffset`]: struct.RelativeOffset.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn relative_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> RelativeOffset {
+        let offset = self.offset(bounds, content_bounds);
+        let hidden_width = content_bounds.width - bounds.width;
+        let hidden_height = content_bounds.height - bounds.height;
+
+        RelativeOffset {
+            x: if hidden_width > 0.0 {
+                (offset.x as f32 / hidden_width).min(1.0)
+            } else {
+                0.0
+            },
+            y: if hidden_height > 0.0 {
+                (offset.y as f32 / hidden_height).min(1.0)
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Apply a vertical scrolling offset to the current [`State`], given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    #[deprecated(note = "use `scroll` with a `Vector` instead")]
+    pub fn scroll_vertically(
+        &mut self,
+        delta_y: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.scroll(Vector::new(0.0, delta_y), bounds, content_bounds);
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Moves the vertical scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
+    /// the bottom.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    #[deprecated(note = "use `snap_to` instead")]
+    pub fn scroll_to_percentage(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.snap_axis_to(Axis::Vertical, percentage, bounds, content_bounds);
+    }
+
+    /// Returns the current vertical scrolling offset of the [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    #[deprecated(note = "use `offset` instead")]
+    pub fn vertical_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> u32 {
+        self.offset(bounds, content_bounds).y
+    }
+
+    /// Returns whether a scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
         self.scroller_grabbed_at.is_some()
     }
 }
 
-/// The scrollbar of a [`Scrollable`].
+/// Keeps a scrolling offset inside the hidden content of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+fn clamp(
+    offset: Vector,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+) -> Vector {
+    Vector::new(
+        offset.x.min(content_bounds.width - bounds.width).max(0.0),
+        offset.y.min(content_bounds.height - bounds.height).max(0.0),
+    )
+}
+
+/// The scrollbars of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Default)]
+pub struct Scrollbars {
+    /// The vertical [`Scrollbar`], if the content overflows vertically.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub vertical: Option<Scrollbar>,
+
+    /// The horizontal [`Scrollbar`], if the content overflows horizontally.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub horizontal: Option<Scrollbar>,
+}
+
+impl Scrollbars {
+    fn get(&self, axis: Axis) -> Option<&Scrollbar> {
+        match axis {
+            Axis::Vertical => self.vertical.as_ref(),
+            Axis::Horizontal => self.horizontal.as_ref(),
+        }
+    }
+
+    pub(crate) fn is_mouse_over(&self, cursor_position: Point) -> bool {
+        self.vertical
+            .iter()
+            .chain(self.horizontal.iter())
+            .any(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+    }
+}
+
+/// A scrollbar of a [`Scrollable`].
 ///
 /// [`Scrollable`]: struct.Scrollable.html
 #[derive(Debug)]
@@ -429,11 +1045,12 @@ impl Scrollbar {
         self.bounds.contains(cursor_position)
     }
 
-    fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
+    fn grab_scroller(&self, axis: Axis, cursor_position: Point) -> Option<f32> {
         if self.bounds.contains(cursor_position) {
             Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
+                (axis.position(cursor_position)
+                    - axis.start(self.scroller.bounds))
+                    / axis.length(self.scroller.bounds)
             } else {
                 0.5
             })
@@ -444,13 +1061,14 @@ impl Scrollbar {
 
     fn scroll_percentage(
         &self,
+        axis: Axis,
         grabbed_at: f32,
         cursor_position: Point,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        (axis.position(cursor_position)
+            - axis.start(self.bounds)
+            - axis.length(self.scroller.bounds) * grabbed_at)
+            / (axis.length(self.bounds) - axis.length(self.scroller.bounds))
     }
 }
 
@@ -476,17 +1094,19 @@ pub trait Renderer: column::Renderer + Sized {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Returns the [`Scrollbar`] given the bounds and content bounds of a
-    /// [`Scrollable`].
+    /// Returns the [`Scrollbars`] given the [`Direction`], bounds, content
+    /// bounds and scrolling offset of a [`Scrollable`].
     ///
-    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
+    /// [`Direction`]: enum.Direction.html
     /// [`Scrollable`]: struct.Scrollable.html
-    fn scrollbar(
+    fn scrollbars(
         &self,
+        direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
-        offset: u32,
-    ) -> Option<Scrollbar>;
+        offset: Vector<u32>,
+    ) -> Scrollbars;
 
     /// Draws the [`Scrollable`].
     ///
@@ -495,12 +1115,13 @@ pub trait Renderer: column::Renderer + Sized {
     /// - the bounds of the [`Scrollable`] widget
     /// - the bounds of the [`Scrollable`] content
     /// - whether the mouse is over the [`Scrollable`] or not
-    /// - whether the mouse is over the [`Scrollbar`] or not
-    /// - a optional [`Scrollbar`] to be rendered
+    /// - whether the mouse is over a [`Scrollbar`] or not
+    /// - the [`Scrollbars`] to be rendered
     /// - the scrolling offset
     /// - the drawn content
     ///
     /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     fn draw(
@@ -510,8 +1131,8 @@ pub trait Renderer: column::Renderer + Sized {
         content_bounds: Rectangle,
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
-        scrollbar: Option<Scrollbar>,
-        offset: u32,
+        scrollbars: Scrollbars,
+        offset: Vector<u32>,
         style: &Self::Style,
         content: Self::Output,
     ) -> Self::Output;
@@ -529,3 +1150,160 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    const CONTENT_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 300.0,
+        height: 500.0,
+    };
+
+    fn offset(state: &State) -> Vector<u32> {
+        state.offset(BOUNDS, CONTENT_BOUNDS)
+    }
+
+    #[test]
+    fn offsets_are_clamped_at_both_ends() {
+        let mut state = State::new();
+
+        state.scroll(Vector::new(20.0, 50.0), BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(offset(&state), Vector::new(0, 0));
+
+        state.scroll(Vector::new(-1000.0, -1000.0), BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(offset(&state), Vector::new(200, 400));
+        assert_eq!(
+            state.relative_offset(BOUNDS, CONTENT_BOUNDS),
+            RelativeOffset { x: 1.0, y: 1.0 }
+        );
+
+        state.scroll_to(Vector::new(50.0, -10.0), BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(offset(&state), Vector::new(50, 0));
+
+        // Contents that fit cannot be scrolled
+        state.scroll(Vector::new(-10.0, -10.0), BOUNDS, BOUNDS);
+        assert_eq!(state.offset(BOUNDS, BOUNDS), Vector::new(0, 0));
+    }
+
+    #[test]
+    fn snapping_is_relative_to_the_hidden_content() {
+        let mut state = State::new();
+
+        state.snap_to(Vector::new(0.5, 0.25), BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(offset(&state), Vector::new(100, 100));
+        assert_eq!(
+            state.relative_offset(BOUNDS, CONTENT_BOUNDS),
+            RelativeOffset { x: 0.5, y: 0.25 }
+        );
+
+        state.snap_to(Vector::new(0.0, 1.0), BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(offset(&state), Vector::new(0, 400));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn vertical_methods_keep_working() {
+        let mut state = State::new();
+
+        state.scroll_vertically(-30.0, BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(state.vertical_offset(BOUNDS, CONTENT_BOUNDS), 30);
+
+        state.scroll_to_percentage(1.0, BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(state.vertical_offset(BOUNDS, CONTENT_BOUNDS), 400);
+    }
+
+    #[test]
+    fn smooth_scrolling_eases_into_the_target() {
+        let mut state = State::new();
+        let now = Instant::now();
+
+        state.scroll_smoothly(
+            Vector::new(0.0, -50.0),
+            now,
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+        state.scroll_smoothly(
+            Vector::new(0.0, -50.0),
+            now,
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+        assert!(state.is_animating());
+
+        state.animate(now + Duration::from_millis(75), BOUNDS, CONTENT_BOUNDS);
+        let halfway = offset(&state).y;
+        assert!(halfway > 50 && halfway < 100);
+
+        state.animate(now + SMOOTH_SCROLLING_DURATION, BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(offset(&state), Vector::new(0, 100));
+        assert!(!state.is_animating());
+    }
+
+    #[test]
+    fn momentum_decays_until_it_stops() {
+        let mut state = State::new();
+        let start = Instant::now();
+
+        state.scroll_with_momentum(
+            Vector::new(0.0, -10.0),
+            start,
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+        assert_eq!(offset(&state), Vector::new(0, 10));
+
+        let mut now = start;
+        let mut previous = offset(&state).y;
+
+        for _ in 0..1000 {
+            if !state.is_animating() {
+                break;
+            }
+
+            now += Duration::from_millis(16);
+            state.animate(now, BOUNDS, CONTENT_BOUNDS);
+
+            let current = offset(&state).y;
+            assert!(current >= previous);
+            previous = current;
+        }
+
+        assert!(!state.is_animating());
+        assert!(previous > 10 && previous < 400);
+    }
+
+    #[test]
+    fn momentum_stops_at_the_end_of_the_content() {
+        let mut state = State::new();
+        let start = Instant::now();
+
+        state.snap_to(Vector::new(0.0, 1.0), BOUNDS, CONTENT_BOUNDS);
+        state.scroll_with_momentum(
+            Vector::new(0.0, -10.0),
+            start,
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+
+        state.animate(start + MOMENTUM_DELAY, BOUNDS, CONTENT_BOUNDS);
+        state.animate(
+            start + MOMENTUM_DELAY + Duration::from_millis(16),
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+
+        assert_eq!(offset(&state), Vector::new(0, 400));
+        assert!(!state.is_animating());
+    }
+}
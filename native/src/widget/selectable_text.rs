@@ -0,0 +1,442 @@
+//! Let your users select and copy some text.
+//!
+//! A [`SelectableText`] has some local [`State`].
+//!
+//! [`SelectableText`]: struct.SelectableText.html
+//! [`State`]: struct.State.html
+use crate::{
+    keyboard, layout,
+    mouse::{self, click},
+    text, Clipboard, Color, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A paragraph of text that can be selected and copied.
+///
+/// Text can be selected by dragging the mouse over it. Double-clicking selects
+/// a word and triple-clicking selects everything. The selection can be copied
+/// to the clipboard.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::selectable_text;
+/// # type SelectableText<'a> =
+/// #     iced_native::SelectableText<'a, iced_native::renderer::Null>;
+/// #
+/// let mut state = selectable_text::State::new();
+///
+/// SelectableText::new(&mut state, "Error 0x1f: connection refused")
+///     .size(16);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> SelectableText<'a, Renderer> {
+    /// Creates a new [`SelectableText`] with the given [`State`] and contents.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`State`]: struct.State.html
+    pub fn new<T: Into<String>>(state: &'a mut State, content: T) -> Self {
+        SelectableText {
+            state,
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn hit_test(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        point: Point,
+    ) -> usize {
+        renderer
+            .hit_test(
+                &self.content,
+                self.size.unwrap_or(renderer.default_size()),
+                self.font,
+                Size::new(bounds.width, f32::INFINITY),
+                Point::new(point.x - bounds.x, point.y - bounds.y),
+            )
+            .unwrap_or(0)
+    }
+
+    fn selected(&self) -> Option<String> {
+        let (start, end) = self.state.selection()?;
+
+        Some(self.content.chars().skip(start).take(end - start).collect())
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SelectableText<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let bounds = limits.max();
+
+        let (width, height) =
+            renderer.measure(&self.content, size, self.font, bounds);
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_clicked = bounds.contains(cursor_position);
+
+                if is_clicked {
+                    let position =
+                        self.hit_test(renderer, bounds, cursor_position);
+
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    self.state.selected_word = None;
+
+                    match click.kind() {
+                        click::Kind::Single => {
+                            self.state.anchor = position;
+                            self.state.head = position;
+                        }
+                        click::Kind::Double => {
+                            let (start, end) = word_at(&self.content, position);
+
+                            self.state.anchor = start;
+                            self.state.head = end;
+                            self.state.selected_word = Some((start, end));
+                        }
+                        click::Kind::Triple => {
+                            self.state.anchor = 0;
+                            self.state.head = self.content.chars().count();
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+                } else {
+                    self.state.anchor = 0;
+                    self.state.head = 0;
+                }
+
+                self.state.is_dragging = is_clicked;
+                self.state.is_focused = is_clicked;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.state.is_dragging =>
+            {
+                let position = self.hit_test(renderer, bounds, cursor_position);
+
+                match (
+                    self.state.last_click.map(|click| click.kind()),
+                    self.state.selected_word,
+                ) {
+                    (Some(click::Kind::Triple), _) => {}
+                    (Some(click::Kind::Double), Some((start, end))) => {
+                        // Extend the selection by whole words, keeping the
+                        // double-clicked word selected
+                        let word = word_at(&self.content, position);
+
+                        if position < start {
+                            self.state.anchor = end;
+                            self.state.head = word.0;
+                        } else {
+                            self.state.anchor = start;
+                            self.state.head = word.1.max(end);
+                        }
+                    }
+                    _ => {
+                        self.state.head = position;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused => match key_code {
+                keyboard::KeyCode::C
+                    if platform::is_copy_paste_modifier_pressed(modifiers) =>
+                {
+                    if let (Some(clipboard), Some(selected)) =
+                        (clipboard, self.selected())
+                    {
+                        let _ = clipboard.write(selected);
+                    }
+                }
+                keyboard::KeyCode::A
+                    if platform::is_copy_paste_modifier_pressed(modifiers) =>
+                {
+                    self.state.anchor = 0;
+                    self.state.head = self.content.chars().count();
+                }
+                keyboard::KeyCode::Escape => {
+                    self.state.anchor = 0;
+                    self.state.head = 0;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let selection = match self.state.selection() {
+            Some((start, end)) => renderer
+                .selection_bounds(
+                    &self.content,
+                    size,
+                    self.font,
+                    Size::new(bounds.width, f32::INFINITY),
+                    start,
+                    end,
+                )
+                .into_iter()
+                .map(|selection| selection + Vector::new(bounds.x, bounds.y))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            bounds,
+            cursor_position,
+            &self.content,
+            size,
+            self.font,
+            self.color,
+            &selection,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash(state);
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`SelectableText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`SelectableText`] in your user interface.
+///
+/// [`SelectableText`]: struct.SelectableText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`SelectableText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`SelectableText`]
+    ///   * the cursor position
+    ///   * the contents, size, font and color of the text
+    ///   * the bounds of the selected text, one per line
+    ///   * the style of the [`SelectableText`]
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        color: Option<Color>,
+        selection: &[Rectangle],
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        selectable_text: SelectableText<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(selectable_text)
+    }
+}
+
+/// The state of a [`SelectableText`].
+///
+/// [`SelectableText`]: struct.SelectableText.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    anchor: usize,
+    head: usize,
+    is_dragging: bool,
+    is_focused: bool,
+    last_click: Option<mouse::Click>,
+    selected_word: Option<(usize, usize)>,
+}
+
+impl State {
+    /// Creates a new [`State`], with nothing selected.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the range of selected characters, if any.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        if self.anchor == self.head {
+            None
+        } else {
+            Some((self.anchor.min(self.head), self.anchor.max(self.head)))
+        }
+    }
+}
+
+/// Returns the character range of the word around the given position.
+fn word_at(content: &str, position: usize) -> (usize, usize) {
+    let mut start = 0;
+
+    for word in content.split_word_bounds() {
+        let end = start + word.chars().count();
+
+        if position < end {
+            return (start, end);
+        }
+
+        start = end;
+    }
+
+    (start, start)
+}
+
+mod platform {
+    use crate::keyboard;
+
+    pub fn is_copy_paste_modifier_pressed(
+        modifiers: keyboard::ModifiersState,
+    ) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.logo
+        } else {
+            modifiers.control
+        }
+    }
+}
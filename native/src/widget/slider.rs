@@ -5,21 +5,27 @@
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
 use crate::{
-    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Size, Widget,
+    keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
 };
 
 use std::{hash::Hash, ops::RangeInclusive};
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+/// A bar and a handle that selects a single value from a range of values.
 ///
-/// A [`Slider`] will try to fill the horizontal space of its container.
+/// A [`Slider`] is horizontal by default and will try to fill the space of its
+/// container along its [`Orientation`].
+///
+/// Once clicked, a [`Slider`] is focused and can be controlled with the
+/// keyboard: the arrow keys move it by one step, `PageUp` and `PageDown` by
+/// [`PAGE_STEPS`] steps, and `Home` and `End` to the ends of its range.
 ///
 /// The [`Slider`] range of numeric values is generic and its step size defaults
 /// to 1 unit.
 ///
 /// [`Slider`]: struct.Slider.html
+/// [`Orientation`]: enum.Orientation.html
+/// [`PAGE_STEPS`]: constant.PAGE_STEPS.html
 ///
 /// # Example
 /// ```
@@ -45,8 +51,9 @@ pub struct Slider<'a, T, Message, Renderer: self::Renderer> {
     value: T,
     on_change: Box<dyn Fn(T) -> Message>,
     on_release: Option<Message>,
-    width: Length,
-    height: u16,
+    orientation: Orientation,
+    width: Option<Length>,
+    height: Option<Length>,
     style: Renderer::Style,
 }
 
@@ -95,14 +102,16 @@ where
             step: T::from(1),
             on_change: Box::new(on_change),
             on_release: None,
-            width: Length::Fill,
-            height: Renderer::DEFAULT_HEIGHT,
+            orientation: Orientation::Horizontal,
+            width: None,
+            height: None,
             style: Renderer::Style::default(),
         }
     }
 
     /// Sets the release message of the [`Slider`].
-    /// This is called when the mouse is released from the slider.
+    /// This is called when the mouse is released from the slider, or when a
+    /// key that moved a focused slider is released.
     ///
     /// Typically, the user's interaction with the slider is finished when this message is produced.
     /// This is useful if you need to spawn a long-running task from the slider's result, where
@@ -114,11 +123,20 @@ where
         self
     }
 
+    /// Sets the [`Orientation`] of the [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`Orientation`]: enum.Orientation.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
     pub fn width(mut self, width: Length) -> Self {
-        self.width = width;
+        self.width = Some(width);
         self
     }
 
@@ -126,7 +144,7 @@ where
     ///
     /// [`Slider`]: struct.Slider.html
     pub fn height(mut self, height: u16) -> Self {
-        self.height = height;
+        self.height = Some(Length::Units(height));
         self
     }
 
@@ -153,6 +171,8 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
+    is_key_pressed: bool,
 }
 
 impl State {
@@ -162,6 +182,138 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Slider`] is currently focused or not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Unfocuses the [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The number of steps a focused slider moves when `PageUp` or `PageDown` is
+/// pressed.
+pub const PAGE_STEPS: f64 = 10.0;
+
+/// The direction along which the rail of a slider lies.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// A rail from left to right.
+    #[default]
+    Horizontal,
+
+    /// A rail from bottom to top.
+    Vertical,
+}
+
+impl Orientation {
+    /// Returns the size of a slider with this [`Orientation`], given its
+    /// optional width and height and the default thickness of its rail.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    pub(crate) fn size(
+        self,
+        width: Option<Length>,
+        height: Option<Length>,
+        thickness: u16,
+    ) -> (Length, Length) {
+        match self {
+            Orientation::Horizontal => (
+                width.unwrap_or(Length::Fill),
+                height.unwrap_or(Length::Units(thickness)),
+            ),
+            Orientation::Vertical => (
+                width.unwrap_or(Length::Units(thickness)),
+                height.unwrap_or(Length::Fill),
+            ),
+        }
+    }
+
+    /// Returns how far along the rail of the given bounds a point is, from
+    /// `0.0` at its start to `1.0` at its end.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    pub(crate) fn percent(self, bounds: Rectangle, point: Point) -> f64 {
+        let percent = match self {
+            Orientation::Horizontal => (point.x - bounds.x) / bounds.width,
+            Orientation::Vertical => {
+                (bounds.y + bounds.height - point.y) / bounds.height
+            }
+        };
+
+        f64::from(percent.clamp(0.0, 1.0))
+    }
+}
+
+/// Returns the value of a slider at the given percent of its rail, rounded to
+/// the closest step.
+pub(crate) fn value_at<T>(
+    range: &RangeInclusive<T>,
+    step: T,
+    percent: f64,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    if percent >= 1.0 {
+        return Some(*range.end());
+    }
+
+    let step = step.into();
+    let start = (*range.start()).into();
+    let end = (*range.end()).into();
+
+    let steps = (percent * (end - start) / step).round();
+
+    T::from_f64(steps * step + start)
+}
+
+/// Moves a value by the given amount of steps, staying inside the range.
+///
+/// Returns `None` if the value would not change.
+pub(crate) fn nudge<T>(
+    range: &RangeInclusive<T>,
+    step: T,
+    value: T,
+    steps: f64,
+) -> Option<T>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+{
+    let start = (*range.start()).into();
+    let end = (*range.end()).into();
+    let current = value.into();
+
+    let new = (current + steps * step.into()).clamp(start, end);
+
+    if (new - current).abs() < f64::EPSILON {
+        None
+    } else {
+        T::from_f64(new)
+    }
+}
+
+/// Returns the amount of steps a key moves a focused slider by.
+///
+/// `Home` and `End` move it by an infinite amount, reaching the ends of its
+/// range.
+pub(crate) fn key_steps(key_code: keyboard::KeyCode) -> Option<f64> {
+    match key_code {
+        keyboard::KeyCode::Left | keyboard::KeyCode::Down => Some(-1.0),
+        keyboard::KeyCode::Right | keyboard::KeyCode::Up => Some(1.0),
+        keyboard::KeyCode::PageDown => Some(-PAGE_STEPS),
+        keyboard::KeyCode::PageUp => Some(PAGE_STEPS),
+        keyboard::KeyCode::Home => Some(f64::NEG_INFINITY),
+        keyboard::KeyCode::End => Some(f64::INFINITY),
+        _ => None,
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
@@ -172,11 +324,14 @@ where
     Message: Clone,
 {
     fn width(&self) -> Length {
-        self.width
+        self.size().0
     }
 
     fn height(&self) -> Length {
-        Length::Shrink
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.size().1,
+        }
     }
 
     fn layout(
@@ -184,8 +339,8 @@ where
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits =
-            limits.width(self.width).height(Length::Units(self.height));
+        let (width, height) = self.size();
+        let limits = limits.width(width).height(height);
 
         let size = limits.resolve(Size::ZERO);
 
@@ -202,35 +357,25 @@ where
         _clipboard: Option<&dyn Clipboard>,
     ) {
         let mut change = || {
-            let bounds = layout.bounds();
-            if cursor_position.x <= bounds.x {
-                messages.push((self.on_change)(*self.range.start()));
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                messages.push((self.on_change)(*self.range.end()));
-            } else {
-                let step = self.step.into();
-                let start = (*self.range.start()).into();
-                let end = (*self.range.end()).into();
-
-                let percent = f64::from(cursor_position.x - bounds.x)
-                    / f64::from(bounds.width);
-
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
-
-                if let Some(value) = T::from_f64(value) {
-                    messages.push((self.on_change)(value));
-                }
+            let percent =
+                self.orientation.percent(layout.bounds(), cursor_position);
+
+            if let Some(value) = value_at(&self.range, self.step, percent) {
+                messages.push((self.on_change)(value));
             }
         };
 
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
+                    let is_clicked = layout.bounds().contains(cursor_position);
+
+                    if is_clicked {
                         change();
                         self.state.is_dragging = true;
                     }
+
+                    self.state.is_focused = is_clicked;
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     if self.state.is_dragging {
@@ -247,6 +392,30 @@ where
                 }
                 _ => {}
             },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                if let Some(steps) = key_steps(key_code) {
+                    if let Some(value) =
+                        nudge(&self.range, self.step, self.value, steps)
+                    {
+                        messages.push((self.on_change)(value));
+                    }
+
+                    self.state.is_key_pressed = true;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code, ..
+            }) if self.state.is_key_pressed
+                && key_steps(key_code).is_some() =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    messages.push(on_release);
+                }
+
+                self.state.is_key_pressed = false;
+            }
             _ => {}
         }
     }
@@ -267,6 +436,7 @@ where
             start.into() as f32..=end.into() as f32,
             self.value.into() as f32,
             self.state.is_dragging,
+            self.orientation,
             &self.style,
         )
     }
@@ -275,7 +445,19 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
+        self.orientation.hash(state);
         self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, T, Message, Renderer> Slider<'a, T, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn size(&self) -> (Length, Length) {
+        self.orientation
+            .size(self.width, self.height, Renderer::DEFAULT_HEIGHT)
     }
 }
 
@@ -290,7 +472,8 @@ pub trait Renderer: crate::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// The default height of a [`Slider`].
+    /// The default thickness of a [`Slider`]: its height when horizontal
+    /// and its width when vertical.
     ///
     /// [`Slider`]: struct.Slider.html
     const DEFAULT_HEIGHT: u16;
@@ -303,10 +486,13 @@ pub trait Renderer: crate::Renderer {
     ///   * the local state of the [`Slider`]
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
+    ///   * the [`Orientation`] of the [`Slider`]
     ///
     /// [`Slider`]: struct.Slider.html
     /// [`State`]: struct.State.html
     /// [`Class`]: enum.Class.html
+    /// [`Orientation`]: enum.Orientation.html
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -314,6 +500,7 @@ pub trait Renderer: crate::Renderer {
         range: RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
+        orientation: Orientation,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -331,3 +518,20 @@ where
         Element::new(slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_to_steps_and_stays_in_range() {
+        assert_eq!(value_at(&(0u32..=95), 10, 0.0), Some(0));
+        assert_eq!(value_at(&(0u32..=95), 10, 0.5), Some(50));
+        assert_eq!(value_at(&(0u32..=95), 10, 1.0), Some(95));
+
+        assert_eq!(nudge(&(0u32..=95), 10, 90, 1.0), Some(95));
+        assert_eq!(nudge(&(0u32..=95), 10, 50, -PAGE_STEPS), Some(0));
+        assert_eq!(nudge(&(0u32..=95), 10, 0, f64::NEG_INFINITY), None);
+        assert_eq!(nudge(&(0u32..=95), 10, 20, f64::INFINITY), Some(95));
+    }
+}
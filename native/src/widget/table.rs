@@ -0,0 +1,1207 @@
+//! Display data in rows and columns, with sortable and resizable columns.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: struct.Table.html
+//! [`State`]: struct.State.html
+use crate::{
+    keyboard, layout, mouse, scrollable, text, Clipboard, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+/// A grid of rows under a header of titled columns.
+///
+/// The header stays visible while the rows scroll. Columns can be sorted and
+/// resized from the header, and rows can be selected.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{table, Text};
+/// # type Table<'a, Message> =
+/// #     iced_native::Table<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sorted(usize, table::SortOrder),
+/// }
+///
+/// let mut state = table::State::new();
+///
+/// let table = Table::new(
+///     &mut state,
+///     vec![
+///         table::Column::new("Name").sortable(true),
+///         table::Column::new("Age").width(iced_native::Length::Units(60)),
+///     ],
+/// )
+/// .push(vec![Text::new("Alice"), Text::new("32")])
+/// .push(vec![Text::new("Bob"), Text::new("27")])
+/// .sorted_by(0, table::SortOrder::Ascending)
+/// .on_sort(Message::Sorted);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    columns: Vec<Column>,
+    rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+    width: Length,
+    height: Length,
+    max_height: u32,
+    padding: u16,
+    line_height: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    sorted_by: Option<(usize, SortOrder)>,
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(ResizeEvent) -> Message + 'a>>,
+    selection: Selection,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    style: <Renderer as self::Renderer>::Style,
+    scrollbar_style: <Renderer as scrollable::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Table`] with the given [`State`] and columns.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State, columns: Vec<Column>) -> Self {
+        Table {
+            state,
+            columns,
+            rows: Vec::new(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_height: u32::MAX,
+            padding: Renderer::DEFAULT_PADDING,
+            line_height: scrollable::LINE_HEIGHT,
+            text_size: None,
+            font: Default::default(),
+            sorted_by: None,
+            on_sort: None,
+            on_resize: None,
+            selection: Selection::None,
+            on_select: None,
+            style: Default::default(),
+            scrollbar_style: Default::default(),
+        }
+    }
+
+    /// Adds a row to the [`Table`], with a cell for each column.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn push<I, E>(mut self, row: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum height of the [`Table`] in pixels.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the padding of the cells of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the distance, in pixels, that the rows of the [`Table`] scroll
+    /// for every line of a mouse wheel.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn line_height(mut self, line_height: u16) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Sets the text size of the header of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the header of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Shows a sort indicator in the header of the given column of the
+    /// [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn sorted_by(mut self, column: usize, order: SortOrder) -> Self {
+        self.sorted_by = Some((column, order));
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a
+    /// sortable [`Column`] is clicked.
+    ///
+    /// It receives the index of the column and the requested [`SortOrder`].
+    ///
+    /// [`Column`]: struct.Column.html
+    /// [`SortOrder`]: enum.SortOrder.html
+    pub fn on_sort<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize, SortOrder) -> Message,
+    {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the resizing of the columns by dragging the edges of their
+    /// headers.
+    ///
+    /// Resizing produces a [`ResizeEvent`], which should be passed to
+    /// [`State::resize`].
+    ///
+    /// [`ResizeEvent`]: struct.ResizeEvent.html
+    /// [`State::resize`]: struct.State.html#method.resize
+    pub fn on_resize<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(ResizeEvent) -> Message,
+    {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets how the rows of the [`Table`] can be selected.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the message that should be produced when the selected rows
+    /// change.
+    ///
+    /// It receives the indices of the selected rows, in ascending order.
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Vec<usize>) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn scrollbar_style(
+        mut self,
+        style: impl Into<<Renderer as scrollable::Renderer>::Style>,
+    ) -> Self {
+        self.scrollbar_style = style.into();
+        self
+    }
+
+    fn column_widths(
+        &self,
+        renderer: &Renderer,
+        max_width: f32,
+        text_size: u16,
+    ) -> Vec<f32> {
+        let padding = f32::from(self.padding) * 2.0;
+
+        let intrinsic_width = |index: usize, column: &Column| {
+            let (title_width, _) = renderer.measure(
+                &column.title,
+                text_size,
+                self.font,
+                Size::new(f32::INFINITY, f32::INFINITY),
+            );
+
+            let cells_width = self
+                .rows
+                .iter()
+                .filter_map(|row| row.get(index))
+                .map(|cell| {
+                    cell.layout(
+                        renderer,
+                        &layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(f32::INFINITY, f32::INFINITY),
+                        ),
+                    )
+                    .size()
+                    .width
+                })
+                .filter(|width| width.is_finite())
+                .fold(0.0, f32::max);
+
+            // Leave room for the sort indicator
+            (title_width + f32::from(text_size)).max(cells_width) + padding
+        };
+
+        let mut widths: Vec<f32> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                if let Some(width) = self.state.widths.get(&index) {
+                    return *width;
+                }
+
+                match column.width {
+                    Length::Units(units) => f32::from(units),
+                    Length::Shrink => intrinsic_width(index, column),
+                    Length::Fill | Length::FillPortion(_) => 0.0,
+                }
+            })
+            .collect();
+
+        let is_fill = |index: usize, column: &Column| {
+            !self.state.widths.contains_key(&index)
+                && column.width.fill_factor() > 0
+        };
+
+        let total_fill: u16 = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(index, column)| is_fill(*index, column))
+            .map(|(_, column)| column.width.fill_factor())
+            .sum();
+
+        if total_fill > 0 {
+            let remaining = (max_width - widths.iter().sum::<f32>()).max(0.0);
+
+            for (index, column) in self.columns.iter().enumerate() {
+                if is_fill(index, column) {
+                    widths[index] = if remaining.is_finite() {
+                        remaining * f32::from(column.width.fill_factor())
+                            / f32::from(total_fill)
+                    } else {
+                        intrinsic_width(index, column)
+                    }
+                    .max(MIN_COLUMN_WIDTH);
+                }
+            }
+        }
+
+        widths
+    }
+
+    fn toggled_order(&self, column: usize) -> SortOrder {
+        match self.sorted_by {
+            Some((sorted, SortOrder::Ascending)) if sorted == column => {
+                SortOrder::Descending
+            }
+            _ => SortOrder::Ascending,
+        }
+    }
+}
+
+/// The minimum width of a column, in pixels.
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+
+/// The distance from the edge of a header, in pixels, at which a column can
+/// be resized.
+const RESIZE_LEEWAY: f32 = 4.0;
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let padding = f32::from(self.padding);
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let widths =
+            self.column_widths(renderer, limits.max().width, text_size);
+        let total_width = widths.iter().sum();
+
+        let header_height = f32::from(text_size) + padding * 2.0;
+        let mut x = 0.0;
+
+        let header = layout::Node::with_children(
+            Size::new(total_width, header_height),
+            widths
+                .iter()
+                .map(|width| {
+                    let mut node =
+                        layout::Node::new(Size::new(*width, header_height));
+                    node.move_to(Point::new(x, 0.0));
+
+                    x += width;
+                    node
+                })
+                .collect(),
+        );
+
+        let mut y = 0.0;
+
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut x = 0.0;
+                let mut height: f32 = 0.0;
+
+                let cells = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| {
+                        let cell_width = (width - padding * 2.0).max(0.0);
+
+                        let limits = layout::Limits::new(
+                            Size::new(cell_width, 0.0),
+                            Size::new(cell_width, f32::INFINITY),
+                        );
+
+                        let mut node = cell.layout(renderer, &limits);
+                        node.move_to(Point::new(x + padding, padding));
+
+                        x += width;
+                        height = height.max(node.size().height);
+
+                        node
+                    })
+                    .collect();
+
+                let mut node = layout::Node::with_children(
+                    Size::new(total_width, height + padding * 2.0),
+                    cells,
+                );
+                node.move_to(Point::new(0.0, y));
+
+                y += node.size().height;
+                node
+            })
+            .collect();
+
+        let content =
+            layout::Node::with_children(Size::new(total_width, y), rows);
+
+        let size = limits.resolve(Size::new(total_width, header_height + y));
+
+        let mut body = layout::Node::with_children(
+            Size::new(size.width, (size.height - header_height).max(0.0)),
+            vec![content],
+        );
+        body.move_to(Point::new(0.0, header_height));
+
+        layout::Node::with_children(size, vec![header, body])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+        let content = body.children().next().unwrap();
+
+        let body_bounds = body.bounds();
+        let content_bounds = content.bounds();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            self.state.keyboard_modifiers = modifiers;
+        }
+
+        if let Some(resizing) = self.state.resizing {
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) => {
+                    self.state.resizing = None;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(on_resize) = &self.on_resize {
+                        let width = (resizing.width + cursor_position.x
+                            - resizing.grabbed_at)
+                            .max(MIN_COLUMN_WIDTH);
+
+                        messages.push(on_resize(ResizeEvent {
+                            column: resizing.column,
+                            width,
+                        }));
+                    }
+                }
+                _ => {}
+            }
+
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if self.on_resize.is_some() {
+                if let Some((column, bounds)) =
+                    resize_handle(header, cursor_position)
+                {
+                    self.state.resizing = Some(Resizing {
+                        column,
+                        grabbed_at: cursor_position.x,
+                        width: bounds.width,
+                    });
+
+                    return;
+                }
+            }
+
+            let clicked_header = header
+                .children()
+                .position(|layout| layout.bounds().contains(cursor_position));
+
+            if let Some(column) = clicked_header {
+                match &self.on_sort {
+                    Some(on_sort) if self.columns[column].is_sortable => {
+                        messages
+                            .push(on_sort(column, self.toggled_order(column)));
+                    }
+                    _ => {}
+                }
+
+                return;
+            }
+        }
+
+        let is_mouse_over_scrollbar = self.state.scrollable.update(
+            &event,
+            body_bounds,
+            content_bounds,
+            cursor_position,
+            scrollable::Direction::Vertical,
+            f32::from(self.line_height),
+            false,
+            renderer,
+        );
+
+        let cursor_position = if body_bounds.contains(cursor_position)
+            && !is_mouse_over_scrollbar
+        {
+            let offset =
+                self.state.scrollable.offset(body_bounds, content_bounds);
+
+            Point::new(cursor_position.x, cursor_position.y + offset.y as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let clicked_row = content
+                .children()
+                .position(|layout| layout.bounds().contains(cursor_position));
+
+            if let Some(row) = clicked_row {
+                let previous = self.state.selection.clone();
+
+                self.state.select(row, self.selection);
+
+                if let Some(on_select) = &self.on_select {
+                    if self.state.selection != previous {
+                        messages.push(on_select(self.state.selection.clone()));
+                    }
+                }
+            }
+        }
+
+        for (row, layout) in self.rows.iter_mut().zip(content.children()) {
+            for (cell, layout) in row.iter_mut().zip(layout.children()) {
+                cell.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                );
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+        let content = body.children().next().unwrap();
+
+        let body_bounds = body.bounds();
+        let content_bounds = content.bounds();
+
+        let offset = self.state.scrollable.offset(body_bounds, content_bounds);
+        let scrollbars = renderer.scrollbars(
+            scrollable::Direction::Vertical,
+            body_bounds,
+            content_bounds,
+            offset,
+        );
+
+        let is_mouse_over_body = body_bounds.contains(cursor_position);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        let rows = {
+            let cursor_position =
+                if is_mouse_over_body && !is_mouse_over_scrollbar {
+                    Point::new(
+                        cursor_position.x,
+                        cursor_position.y + offset.y as f32,
+                    )
+                } else {
+                    Point::new(cursor_position.x, -1.0)
+                };
+
+            let hovered_row = content
+                .children()
+                .position(|layout| layout.bounds().contains(cursor_position));
+
+            self::Renderer::draw_rows(
+                renderer,
+                defaults,
+                &self.rows,
+                content,
+                cursor_position,
+                &self.state.selection,
+                hovered_row,
+                &self.style,
+            )
+        };
+
+        let body = scrollable::Renderer::draw(
+            renderer,
+            &self.state.scrollable,
+            body_bounds,
+            content_bounds,
+            is_mouse_over_body,
+            is_mouse_over_scrollbar,
+            scrollbars,
+            offset,
+            &self.scrollbar_style,
+            rows,
+        );
+
+        let resizing = match self.state.resizing {
+            Some(resizing) => Some(resizing.column),
+            None if self.on_resize.is_some() => {
+                resize_handle(header, cursor_position).map(|(column, _)| column)
+            }
+            None => None,
+        };
+
+        let headers: Vec<_> = self
+            .columns
+            .iter()
+            .zip(header.children())
+            .enumerate()
+            .map(|(index, (column, layout))| Header {
+                title: &column.title,
+                bounds: layout.bounds(),
+                sort_order: self
+                    .sorted_by
+                    .filter(|(sorted, _)| *sorted == index)
+                    .map(|(_, order)| order),
+                is_sortable: column.is_sortable && self.on_sort.is_some(),
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            Rectangle {
+                width: layout.bounds().width,
+                ..header.bounds()
+            },
+            cursor_position,
+            &headers,
+            resizing,
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            body,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.columns.hash(state);
+
+        for (column, width) in &self.state.widths {
+            column.hash(state);
+            width.to_bits().hash(state);
+        }
+
+        for row in &self.rows {
+            row.len().hash(state);
+
+            for cell in row {
+                cell.hash_layout(state);
+            }
+        }
+    }
+}
+
+/// Returns the index and bounds of the header whose right edge is under the
+/// cursor, if any.
+fn resize_handle(
+    header: Layout<'_>,
+    cursor_position: Point,
+) -> Option<(usize, Rectangle)> {
+    let bounds = header.bounds();
+
+    if cursor_position.y < bounds.y
+        || cursor_position.y > bounds.y + bounds.height
+    {
+        return None;
+    }
+
+    header
+        .children()
+        .map(|layout| layout.bounds())
+        .enumerate()
+        .find(|(_, bounds)| {
+            (bounds.x + bounds.width - cursor_position.x).abs() <= RESIZE_LEEWAY
+        })
+}
+
+/// A column of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Column {
+    title: String,
+    width: Length,
+    is_sortable: bool,
+}
+
+impl Column {
+    /// Creates a new [`Column`] with the given title.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn new(title: impl Into<String>) -> Self {
+        Column {
+            title: title.into(),
+            width: Length::Fill,
+            is_sortable: false,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets whether the [`Column`] can be sorted by clicking its header.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+}
+
+/// The order in which a column of a [`Table`] is sorted.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// From the smallest to the largest value.
+    Ascending,
+
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+/// How the rows of a [`Table`] can be selected.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Selection {
+    /// Rows cannot be selected.
+    None,
+
+    /// A single row can be selected at a time.
+    Single,
+
+    /// Many rows can be selected, holding the control key to toggle a row or
+    /// the shift key to select a range of rows.
+    Multiple,
+}
+
+/// A request to change the width of a column of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResizeEvent {
+    /// The index of the column being resized.
+    pub column: usize,
+
+    /// The new width of the column, in pixels.
+    pub width: f32,
+}
+
+/// The header of a column of a [`Table`], as drawn by a [`Renderer`].
+///
+/// [`Table`]: struct.Table.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    /// The title of the column.
+    pub title: &'a str,
+
+    /// The bounds of the header.
+    pub bounds: Rectangle,
+
+    /// The order in which the column is sorted, if it is.
+    pub sort_order: Option<SortOrder>,
+
+    /// Whether the column can be sorted by clicking the header.
+    pub is_sortable: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    grabbed_at: f32,
+    width: f32,
+}
+
+/// The local state of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    widths: BTreeMap<usize, f32>,
+    resizing: Option<Resizing>,
+    selection: Vec<usize>,
+    anchor: Option<usize>,
+    keyboard_modifiers: keyboard::ModifiersState,
+}
+
+impl State {
+    /// Creates a new [`State`], with no rows selected.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Sets the width of a column, overriding its [`Length`].
+    ///
+    /// [`Length`]: ../../enum.Length.html
+    pub fn resize(&mut self, column: usize, width: f32) {
+        let _ = self.widths.insert(column, width.max(MIN_COLUMN_WIDTH));
+    }
+
+    /// Returns the indices of the selected rows, in ascending order.
+    pub fn selection(&self) -> &[usize] {
+        &self.selection
+    }
+
+    /// Selects the rows with the given indices.
+    pub fn set_selection(&mut self, rows: impl IntoIterator<Item = usize>) {
+        self.selection = rows.into_iter().collect();
+        self.selection.sort_unstable();
+        self.selection.dedup();
+        self.anchor = self.selection.first().copied();
+    }
+
+    fn select(&mut self, row: usize, mode: Selection) {
+        match mode {
+            Selection::None => {}
+            Selection::Multiple if self.keyboard_modifiers.shift => {
+                let anchor = self.anchor.unwrap_or(row);
+
+                self.selection = (anchor.min(row)..=anchor.max(row)).collect();
+            }
+            Selection::Multiple
+                if platform::is_toggle_modifier_pressed(
+                    self.keyboard_modifiers,
+                ) =>
+            {
+                match self.selection.binary_search(&row) {
+                    Ok(index) => {
+                        let _ = self.selection.remove(index);
+                    }
+                    Err(index) => self.selection.insert(index, row),
+                }
+
+                self.anchor = Some(row);
+            }
+            Selection::Single | Selection::Multiple => {
+                self.selection = vec![row];
+                self.anchor = Some(row);
+            }
+        }
+    }
+}
+
+/// The renderer of a [`Table`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Table`] in your user interface.
+///
+/// [`Table`]: struct.Table.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: scrollable::Renderer + text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of the cells of a [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    const DEFAULT_PADDING: u16;
+
+    /// Draws the rows of a [`Table`], before they are clipped and scrolled.
+    ///
+    /// It receives:
+    ///   * the cells of every row
+    ///   * the layout of the rows, with a child for every cell
+    ///   * the cursor position, relative to the rows
+    ///   * the indices of the selected rows
+    ///   * the index of the hovered row, if any
+    ///   * the style of the [`Table`]
+    ///
+    /// [`Table`]: struct.Table.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw_rows<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        rows: &[Vec<Element<'_, Message, Self>>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        selection: &[usize],
+        hovered_row: Option<usize>,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+
+    /// Draws a [`Table`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Table`]
+    ///   * the bounds of its header
+    ///   * the cursor position
+    ///   * the [`Header`] of every column
+    ///   * the index of the column being resized, if any
+    ///   * the padding, text size and font of the headers
+    ///   * the drawn rows, already scrolled
+    ///   * the style of the [`Table`]
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Header`]: struct.Header.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        header_bounds: Rectangle,
+        cursor_position: Point,
+        headers: &[Header<'_>],
+        resizing: Option<usize>,
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        body: Self::Output,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        table: Table<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(table)
+    }
+}
+
+mod platform {
+    use crate::keyboard;
+
+    pub fn is_toggle_modifier_pressed(
+        modifiers: keyboard::ModifiersState,
+    ) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.logo
+        } else {
+            modifiers.control
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Limits, renderer::Null};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Sorted(usize, SortOrder),
+        Resized(ResizeEvent),
+    }
+
+    fn table(
+        state: &mut State,
+        sorted_by: Option<(usize, SortOrder)>,
+    ) -> Table<'_, Message, Null> {
+        let columns = vec![
+            Column::new("Name").width(Length::Units(100)).sortable(true),
+            Column::new("Size").width(Length::Units(100)).sortable(true),
+            Column::new("Kind").width(Length::Units(100)),
+        ];
+
+        let mut table = Table::new(state, columns)
+            .on_sort(Message::Sorted)
+            .on_resize(Message::Resized);
+
+        if let Some((column, order)) = sorted_by {
+            table = table.sorted_by(column, order);
+        }
+
+        table
+    }
+
+    fn send(
+        mut table: Table<'_, Message, Null>,
+        events: Vec<(Event, Point)>,
+    ) -> Vec<Message> {
+        let node = Widget::<Message, Null>::layout(
+            &table,
+            &Null,
+            &Limits::new(Size::ZERO, Size::new(400.0, 300.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        for (event, cursor_position) in events {
+            table.on_event(
+                event,
+                Layout::new(&node),
+                cursor_position,
+                &mut messages,
+                &Null,
+                None,
+            );
+        }
+
+        messages
+    }
+
+    fn press(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(x, y),
+        )
+    }
+
+    fn move_to(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::CursorMoved { x, y }),
+            Point::new(x, y),
+        )
+    }
+
+    fn release(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            Point::new(x, y),
+        )
+    }
+
+    #[test]
+    fn clicking_a_sortable_header_sorts_its_column() {
+        let mut state = State::new();
+
+        assert_eq!(
+            send(table(&mut state, None), vec![press(150.0, 10.0)]),
+            vec![Message::Sorted(1, SortOrder::Ascending)]
+        );
+
+        assert_eq!(
+            send(table(&mut state, None), vec![press(250.0, 10.0)]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn clicking_the_sorted_header_toggles_the_order() {
+        let mut state = State::new();
+
+        assert_eq!(
+            send(
+                table(&mut state, Some((0, SortOrder::Ascending))),
+                vec![press(50.0, 10.0)]
+            ),
+            vec![Message::Sorted(0, SortOrder::Descending)]
+        );
+
+        assert_eq!(
+            send(
+                table(&mut state, Some((0, SortOrder::Descending))),
+                vec![press(50.0, 10.0)]
+            ),
+            vec![Message::Sorted(0, SortOrder::Ascending)]
+        );
+
+        assert_eq!(
+            send(
+                table(&mut state, Some((0, SortOrder::Ascending))),
+                vec![press(150.0, 10.0)]
+            ),
+            vec![Message::Sorted(1, SortOrder::Ascending)]
+        );
+    }
+
+    #[test]
+    fn dragging_a_header_edge_resizes_its_column() {
+        let mut state = State::new();
+
+        let messages = send(
+            table(&mut state, None),
+            vec![
+                press(102.0, 10.0),
+                move_to(132.0, 10.0),
+                move_to(0.0, 10.0),
+                release(0.0, 10.0),
+                move_to(50.0, 10.0),
+            ],
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Resized(ResizeEvent {
+                    column: 0,
+                    width: 130.0
+                }),
+                Message::Resized(ResizeEvent {
+                    column: 0,
+                    width: MIN_COLUMN_WIDTH
+                }),
+            ]
+        );
+
+        state.resize(0, 130.0);
+
+        assert_eq!(
+            send(table(&mut state, None), vec![press(120.0, 10.0)]),
+            vec![Message::Sorted(0, SortOrder::Ascending)]
+        );
+    }
+
+    #[test]
+    fn multiple_selection() {
+        let mut state = State::new();
+
+        state.select(2, Selection::Multiple);
+        assert_eq!(state.selection(), &[2]);
+
+        state.keyboard_modifiers.shift = true;
+        state.select(5, Selection::Multiple);
+        assert_eq!(state.selection(), &[2, 3, 4, 5]);
+
+        state.keyboard_modifiers.shift = false;
+        if cfg!(target_os = "macos") {
+            state.keyboard_modifiers.logo = true;
+        } else {
+            state.keyboard_modifiers.control = true;
+        }
+
+        state.select(3, Selection::Multiple);
+        state.select(8, Selection::Multiple);
+        assert_eq!(state.selection(), &[2, 4, 5, 8]);
+
+        state.select(1, Selection::Single);
+        assert_eq!(state.selection(), &[1]);
+    }
+}
@@ -0,0 +1,757 @@
+//! Switch between views with a bar of tabs.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: struct.Tabs.html
+//! [`State`]: struct.State.html
+use crate::{
+    layout, mouse, text, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A bar of tabs above the content of the active one.
+///
+/// Tabs can be selected, closed and reordered by dragging them. When there
+/// are too many tabs to fit, the bar can be scrolled with the mouse wheel.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{tabs, Text};
+/// # type Tabs<'a, Message> =
+/// #     iced_native::Tabs<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(usize),
+///     Closed(usize),
+///     Reordered(tabs::ReorderEvent),
+/// }
+///
+/// let mut state = tabs::State::new();
+///
+/// let tabs = Tabs::new(
+///     &mut state,
+///     vec![tabs::Tab::new("main.rs"), tabs::Tab::new("lib.rs")],
+///     0,
+///     Text::new("fn main() {}"),
+///     Message::Selected,
+/// )
+/// .on_close(Message::Closed)
+/// .on_reorder(Message::Reordered);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    tabs: Vec<Tab>,
+    active: usize,
+    content: Element<'a, Message, Renderer>,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(ReorderEvent) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    icon_font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Tabs`] widget with the given [`State`], list of
+    /// [`Tab`]s, index of the active tab, its content, and a function that
+    /// produces a message when a tab is selected.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    /// [`Tab`]: struct.Tab.html
+    pub fn new<F>(
+        state: &'a mut State,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        on_select: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Tabs {
+            state,
+            tabs,
+            active,
+            content: content.into(),
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            icon_font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Shows a close button in every tab, producing the given message when
+    /// it is pressed.
+    pub fn on_close<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the reordering of the tabs by dragging them, producing the
+    /// given message when a tab is dropped.
+    pub fn on_reorder<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(ReorderEvent) -> Message,
+    {
+        self.on_reorder = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of every tab.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the labels of the tabs.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the labels of the tabs.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font of the icons of the tabs.
+    pub fn icon_font(mut self, font: Renderer::Font) -> Self {
+        self.icon_font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the close button of a tab.
+    fn close_bounds(&self, tab: Rectangle, text_size: f32) -> Rectangle {
+        let padding = f32::from(self.padding);
+
+        Rectangle {
+            x: tab.x + tab.width - padding - text_size,
+            y: tab.y + padding,
+            width: text_size,
+            height: text_size,
+        }
+    }
+
+    /// Returns the index of the tab that a dragged tab should be moved to,
+    /// given the horizontal position of the cursor in the bar.
+    fn drop_target(&self, bar: Layout<'_>, x: f32) -> usize {
+        let last = self.tabs.len().saturating_sub(1);
+
+        bar.children()
+            .position(|tab| {
+                let bounds = tab.bounds();
+
+                x < bounds.x + bounds.width
+            })
+            .unwrap_or(last)
+    }
+}
+
+/// Returns how far the given tab bar can be scrolled.
+fn max_offset(bar: Layout<'_>) -> f32 {
+    let bounds = bar.bounds();
+    let tabs_width = bar
+        .children()
+        .last()
+        .map(|tab| tab.bounds().x + tab.bounds().width - bounds.x)
+        .unwrap_or(0.0);
+
+    (tabs_width - bounds.width).max(0.0)
+}
+
+/// The distance the cursor needs to travel before a tab is dragged.
+const DRAG_DEADBAND_DISTANCE: f32 = 4.0;
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let padding = f32::from(self.padding);
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let size = f32::from(text_size);
+        let bar_height = size + padding * 2.0;
+
+        let mut x = 0.0;
+
+        let tabs = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let (label_width, _) = renderer.measure(
+                    &tab.label,
+                    text_size,
+                    self.font,
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                );
+
+                let icon_width = if tab.icon.is_some() {
+                    size + padding
+                } else {
+                    0.0
+                };
+
+                let close_width = if self.on_close.is_some() {
+                    size + padding
+                } else {
+                    0.0
+                };
+
+                let width =
+                    padding * 2.0 + icon_width + label_width + close_width;
+
+                let mut node = layout::Node::new(Size::new(width, bar_height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+                node
+            })
+            .collect();
+
+        let content_limits = limits.shrink(Size::new(0.0, bar_height));
+        let mut content = self.content.layout(renderer, &content_limits);
+        content.move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(Size::new(
+            x.max(content.size().width),
+            bar_height + content.size().height,
+        ));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let bar_bounds = bar.bounds();
+        let max_offset = max_offset(bar);
+
+        // Tabs may have been closed or the bar widened since the last event
+        self.state.offset = self.state.offset.min(max_offset);
+
+        let text_size =
+            f32::from(self.text_size.unwrap_or(renderer.default_size()));
+
+        // The cursor position relative to the unscrolled tabs
+        let bar_position = Point::new(
+            cursor_position.x + self.state.offset,
+            cursor_position.y,
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bar_bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        // Vertical wheels scroll the bar as well
+                        (if x == 0.0 { y } else { x }) * text_size * 3.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                self.state.offset =
+                    (self.state.offset - delta).max(0.0).min(max_offset);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bar_bounds.contains(cursor_position) =>
+            {
+                let pressed = bar
+                    .children()
+                    .position(|tab| tab.bounds().contains(bar_position));
+
+                if let Some(index) = pressed {
+                    let tab = bar.children().nth(index).unwrap().bounds();
+
+                    match &self.on_close {
+                        Some(on_close)
+                            if self
+                                .close_bounds(tab, text_size)
+                                .contains(bar_position) =>
+                        {
+                            messages.push(on_close(index));
+                        }
+                        _ => {
+                            if index != self.active {
+                                messages.push((self.on_select)(index));
+                            }
+
+                            if self.on_reorder.is_some() {
+                                self.state.drag = Some(Drag {
+                                    index,
+                                    origin: cursor_position.x,
+                                    position: cursor_position.x,
+                                });
+                            }
+                        }
+                    }
+                }
+
+                return;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    drag.position = cursor_position.x;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(drag) = self.state.drag.take() {
+                    if let Some(on_reorder) = &self.on_reorder {
+                        let to = self.drop_target(bar, bar_position.x);
+
+                        if drag.is_moving() && to != drag.index {
+                            messages.push(on_reorder(ReorderEvent {
+                                from: drag.index,
+                                to,
+                            }));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.content.on_event(
+            event,
+            content,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let offset = self.state.offset.min(max_offset(bar));
+        let drag = self.state.drag.filter(Drag::is_moving);
+
+        let bar_position =
+            Point::new(cursor_position.x + offset, cursor_position.y);
+        let is_mouse_over_bar = bar.bounds().contains(cursor_position);
+
+        let tabs: Vec<_> = self
+            .tabs
+            .iter()
+            .zip(bar.children())
+            .enumerate()
+            .map(|(index, (tab, layout))| {
+                let unscrolled = layout.bounds();
+
+                let dragged_by = match drag {
+                    Some(drag) if drag.index == index => {
+                        drag.position - drag.origin
+                    }
+                    _ => 0.0,
+                };
+
+                let is_hovered = drag.is_none()
+                    && is_mouse_over_bar
+                    && unscrolled.contains(bar_position);
+
+                let close_bounds = if self.on_close.is_some() {
+                    Some(self.close_bounds(unscrolled, f32::from(text_size)))
+                } else {
+                    None
+                };
+
+                let scroll = |bounds: Rectangle| Rectangle {
+                    x: bounds.x - offset + dragged_by,
+                    ..bounds
+                };
+
+                Item {
+                    label: &tab.label,
+                    icon: tab.icon,
+                    bounds: scroll(unscrolled),
+                    close_bounds: close_bounds.map(scroll),
+                    is_active: index == self.active,
+                    is_hovered,
+                    is_close_hovered: is_hovered
+                        && matches!(
+                            close_bounds,
+                            Some(close) if close.contains(bar_position)
+                        ),
+                    is_dragged: dragged_by != 0.0,
+                }
+            })
+            .collect();
+
+        let content = self.content.draw(
+            renderer,
+            defaults,
+            content_layout,
+            cursor_position,
+        );
+
+        self::Renderer::draw(
+            renderer,
+            bar.bounds(),
+            &tabs,
+            self.padding,
+            text_size,
+            self.font,
+            self.icon_font,
+            content,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.tabs.hash(state);
+        self.on_close.is_some().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+/// A tab of a [`Tabs`] widget.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tab {
+    label: String,
+    icon: Option<char>,
+}
+
+impl Tab {
+    /// Creates a new [`Tab`] with the given label.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn new(label: impl Into<String>) -> Self {
+        Tab {
+            label: label.into(),
+            icon: None,
+        }
+    }
+
+    /// Sets the icon of the [`Tab`], shown before its label using the icon
+    /// font of the [`Tabs`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+/// A request to move a tab of a [`Tabs`] widget to a new position.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorderEvent {
+    /// The index of the dragged tab.
+    pub from: usize,
+
+    /// The index where the tab was dropped.
+    pub to: usize,
+}
+
+/// A tab of a [`Tabs`] widget, as drawn by a [`Renderer`].
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Clone, Copy)]
+pub struct Item<'a> {
+    /// The label of the tab.
+    pub label: &'a str,
+
+    /// The icon of the tab, if any.
+    pub icon: Option<char>,
+
+    /// The bounds of the tab, already scrolled and dragged.
+    pub bounds: Rectangle,
+
+    /// The bounds of the close button of the tab, if any.
+    pub close_bounds: Option<Rectangle>,
+
+    /// Whether the tab is the active one.
+    pub is_active: bool,
+
+    /// Whether the cursor is over the tab.
+    pub is_hovered: bool,
+
+    /// Whether the cursor is over the close button of the tab.
+    pub is_close_hovered: bool,
+
+    /// Whether the tab is being dragged.
+    pub is_dragged: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    position: f32,
+}
+
+impl Drag {
+    fn is_moving(&self) -> bool {
+        (self.position - self.origin).abs() > DRAG_DEADBAND_DISTANCE
+    }
+}
+
+/// The local state of a [`Tabs`] widget.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    offset: f32,
+    drag: Option<Drag>,
+}
+
+impl State {
+    /// Creates a new [`State`], with the tab bar scrolled to the start.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+/// The renderer of a [`Tabs`] widget.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Tabs`] widget in your user interface.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer + Sized {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default padding of the tabs of a [`Tabs`] widget.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    const DEFAULT_PADDING: u16;
+
+    /// Draws a [`Tabs`] widget.
+    ///
+    /// It receives:
+    ///   * the bounds of the tab bar
+    ///   * the [`Item`] of every tab
+    ///   * the padding, text size, font and icon font of the tabs
+    ///   * the drawn content of the active tab
+    ///   * the style of the tabs
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`Item`]: struct.Item.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bar_bounds: Rectangle,
+        tabs: &[Item<'_>],
+        padding: u16,
+        text_size: u16,
+        font: Self::Font,
+        icon_font: Self::Font,
+        content: Self::Output,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tabs: Tabs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::Text;
+
+    fn tabs(state: &mut State) -> Tabs<'_, (), Null> {
+        Tabs::new(
+            state,
+            vec![Tab::new("a"), Tab::new("b"), Tab::new("c")],
+            0,
+            Text::new("content"),
+            |_| (),
+        )
+    }
+
+    // A bar of the given width with tabs of 50, 30 and 40 pixels
+    fn bar(width: f32) -> layout::Node {
+        let mut x = 0.0;
+
+        let tabs = [50.0, 30.0, 40.0]
+            .iter()
+            .map(|tab_width| {
+                let mut node = layout::Node::new(Size::new(*tab_width, 20.0));
+                node.move_to(Point::new(x, 0.0));
+
+                x += tab_width;
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, 20.0), tabs)
+    }
+
+    #[test]
+    fn drop_target_is_the_tab_under_the_cursor() {
+        let mut state = State::new();
+        let tabs = tabs(&mut state);
+        let bar = bar(200.0);
+
+        assert_eq!(tabs.drop_target(Layout::new(&bar), -10.0), 0);
+        assert_eq!(tabs.drop_target(Layout::new(&bar), 49.0), 0);
+        assert_eq!(tabs.drop_target(Layout::new(&bar), 50.0), 1);
+        assert_eq!(tabs.drop_target(Layout::new(&bar), 79.0), 1);
+        assert_eq!(tabs.drop_target(Layout::new(&bar), 100.0), 2);
+    }
+
+    #[test]
+    fn drop_target_past_the_last_tab_is_the_last_tab() {
+        let mut state = State::new();
+        let tabs = tabs(&mut state);
+        let bar = bar(200.0);
+
+        assert_eq!(tabs.drop_target(Layout::new(&bar), 150.0), 2);
+    }
+
+    #[test]
+    fn offset_is_clamped_when_the_bar_no_longer_overflows() {
+        let mut state = State {
+            offset: 40.0,
+            drag: None,
+        };
+
+        let mut content = layout::Node::new(Size::new(100.0, 80.0));
+        content.move_to(Point::new(0.0, 20.0));
+
+        let root = layout::Node::with_children(
+            Size::new(100.0, 100.0),
+            vec![bar(100.0), content],
+        );
+
+        assert_eq!(
+            max_offset(Layout::new(&root).children().next().unwrap()),
+            20.0
+        );
+
+        tabs(&mut state).on_event(
+            Event::Mouse(mouse::Event::CursorMoved { x: 0.0, y: 0.0 }),
+            Layout::new(&root),
+            Point::ORIGIN,
+            &mut Vec::new(),
+            &Null,
+            None,
+        );
+
+        assert_eq!(state.offset, 20.0);
+    }
+}
@@ -4,7 +4,9 @@ use crate::{
     Rectangle, Size, VerticalAlignment, Widget,
 };
 
+use std::borrow::Cow;
 use std::hash::Hash;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// A paragraph of text.
 ///
@@ -29,6 +31,9 @@ pub struct Text<Renderer: self::Renderer> {
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    wrap: Wrap,
+    max_lines: Option<u16>,
+    ellipsis: Ellipsis,
 }
 
 impl<Renderer: self::Renderer> Text<Renderer> {
@@ -45,6 +50,9 @@ impl<Renderer: self::Renderer> Text<Renderer> {
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            wrap: Wrap::Word,
+            max_lines: None,
+            ellipsis: Ellipsis::None,
         }
     }
 
@@ -110,6 +118,109 @@ impl<Renderer: self::Renderer> Text<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`Wrap`] mode of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrap`]: enum.Wrap.html
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Text`].
+    ///
+    /// Any lines past the limit are hidden, and the last visible one is
+    /// truncated with the [`Ellipsis`] of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Ellipsis`]: enum.Ellipsis.html
+    pub fn max_lines(mut self, max_lines: u16) -> Self {
+        self.max_lines = Some(max_lines.max(1));
+        self
+    }
+
+    /// Sets the [`Ellipsis`] used to truncate the lines of the [`Text`] that
+    /// do not fit.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Ellipsis`]: enum.Ellipsis.html
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Breaks the contents of the [`Text`] into lines that fit the given
+    /// width, following its [`Wrap`] mode, line limit and [`Ellipsis`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrap`]: enum.Wrap.html
+    /// [`Ellipsis`]: enum.Ellipsis.html
+    fn lines(
+        &self,
+        renderer: &Renderer,
+        size: u16,
+        max_width: f32,
+    ) -> Cow<'_, str> {
+        // The renderer already wraps words
+        if self.wrap == Wrap::Word
+            && self.max_lines.is_none()
+            && self.ellipsis == Ellipsis::None
+        {
+            return Cow::Borrowed(&self.content);
+        }
+
+        let measure = |content: &str| {
+            renderer.measure(content, size, self.font, Size::INFINITY).0
+        };
+
+        let mut lines = Vec::new();
+
+        for paragraph in self.content.split('\n') {
+            match self.wrap {
+                Wrap::Word => {
+                    wrap_words(paragraph, max_width, &measure, &mut lines)
+                }
+                Wrap::Character => {
+                    let mut line = Line::default();
+
+                    wrap_characters(
+                        paragraph, max_width, &measure, &mut line, &mut lines,
+                    );
+
+                    lines.push(line.content);
+                }
+                Wrap::None => lines.push(paragraph.to_string()),
+            }
+        }
+
+        let is_truncated = match self.max_lines {
+            Some(max_lines) if lines.len() > usize::from(max_lines) => {
+                lines.truncate(usize::from(max_lines));
+                true
+            }
+            _ => false,
+        };
+
+        let last = lines.len() - 1;
+
+        for (i, line) in lines.iter_mut().enumerate() {
+            if is_truncated && i == last {
+                // Hidden lines follow, so the ellipsis goes at the end
+                let ellipsis = match self.ellipsis {
+                    Ellipsis::None => Ellipsis::None,
+                    _ => Ellipsis::End,
+                };
+
+                *line = truncate(line, max_width, ellipsis, true, &measure);
+            } else if measure(line) > max_width {
+                *line =
+                    truncate(line, max_width, self.ellipsis, false, &measure);
+            }
+        }
+
+        Cow::Owned(lines.join("\n"))
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text<Renderer>
@@ -135,12 +246,25 @@ where
 
         let bounds = limits.max();
 
+        let content = self.lines(renderer, size, bounds.width);
+
         let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+            renderer.measure(&content, size, self.font, bounds);
+
+        let resolved = limits.resolve(Size::new(width, height));
 
-        let size = limits.resolve(Size::new(width, height));
+        if let Cow::Borrowed(_) = content {
+            return layout::Node::new(resolved);
+        }
 
-        layout::Node::new(size)
+        // `draw` breaks the lines again at the resolved width, which may
+        // differ from the maximum width. Measure those same lines.
+        let bounds = Size::new(resolved.width, bounds.height);
+        let content = self.lines(renderer, size, bounds.width);
+
+        let (_, height) = renderer.measure(&content, size, self.font, bounds);
+
+        layout::Node::new(limits.resolve(Size::new(resolved.width, height)))
     }
 
     fn draw(
@@ -150,11 +274,15 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let size = self.size.unwrap_or(renderer.default_size());
+        let content = self.lines(renderer, size, bounds.width);
+
         renderer.draw(
             defaults,
-            layout.bounds(),
-            &self.content,
-            self.size.unwrap_or(renderer.default_size()),
+            bounds,
+            &content,
+            size,
             self.font,
             self.color,
             self.horizontal_alignment,
@@ -170,6 +298,9 @@ where
         self.size.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.wrap.hash(state);
+        self.max_lines.hash(state);
+        self.ellipsis.hash(state);
     }
 }
 
@@ -204,6 +335,39 @@ pub trait Renderer: crate::Renderer {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Finds the caret position closest to the given point in the [`Text`]
+    /// laid out in the given bounds.
+    ///
+    /// The point is relative to the top-left corner of the contents, and the
+    /// position is the index of the character right after the caret.
+    ///
+    /// [`Text`]: struct.Text.html
+    fn hit_test(
+        &self,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        bounds: Size,
+        point: Point,
+    ) -> Option<usize>;
+
+    /// Returns the bounds covering the characters of the [`Text`] in the
+    /// given range, once laid out in the given bounds.
+    ///
+    /// There is one rectangle per line, relative to the top-left corner of the
+    /// contents.
+    ///
+    /// [`Text`]: struct.Text.html
+    fn selection_bounds(
+        &self,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        bounds: Size,
+        start: usize,
+        end: usize,
+    ) -> Vec<Rectangle>;
+
     /// Draws a [`Text`] fragment.
     ///
     /// It receives:
@@ -251,6 +415,381 @@ impl<Renderer: self::Renderer> Clone for Text<Renderer> {
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            wrap: self.wrap,
+            max_lines: self.max_lines,
+            ellipsis: self.ellipsis,
         }
     }
 }
+
+/// The way a [`Text`] breaks into lines when it does not fit its bounds.
+///
+/// [`Text`]: struct.Text.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Wrap {
+    /// Lines are broken between words.
+    ///
+    /// Words that do not fit in a line on their own are not broken.
+    Word,
+
+    /// Lines are broken between any characters.
+    Character,
+
+    /// Lines are only broken on new line characters.
+    None,
+}
+
+/// The way a line of [`Text`] is truncated when it does not fit its bounds.
+///
+/// [`Text`]: struct.Text.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ellipsis {
+    /// The line is cut off without any indication.
+    None,
+
+    /// The end of the line is replaced by an ellipsis.
+    End,
+
+    /// The middle of the line is replaced by an ellipsis, keeping both ends
+    /// visible. This is useful for file paths.
+    Middle,
+}
+
+const ELLIPSIS: &str = "…";
+
+/// A line being filled with words, along with its measured width.
+#[derive(Debug, Default)]
+struct Line {
+    content: String,
+    width: f32,
+}
+
+impl Line {
+    fn push(&mut self, text: &str, width: f32) {
+        self.content.push_str(text);
+        self.width += width;
+    }
+
+    fn take(&mut self) -> String {
+        self.width = 0.0;
+
+        std::mem::take(&mut self.content)
+    }
+}
+
+/// Breaks a paragraph into lines between words, keeping the words that do
+/// not fit in a line on their own whole.
+///
+/// Every word is only measured once, and the width of a line is the sum of
+/// the widths of its words.
+fn wrap_words(
+    paragraph: &str,
+    max_width: f32,
+    measure: &dyn Fn(&str) -> f32,
+    lines: &mut Vec<String>,
+) {
+    let mut line = Line::default();
+    let mut is_wrapped = false;
+
+    for word in paragraph.split_word_bounds() {
+        let is_whitespace = word.trim().is_empty();
+        let width = measure(word);
+
+        if !line.content.is_empty()
+            && !is_whitespace
+            && line.width + width > max_width
+        {
+            lines.push(line.take().trim_end().to_string());
+            is_wrapped = true;
+        }
+
+        // Wrapped lines do not start with whitespace
+        if line.content.is_empty() && is_wrapped && is_whitespace {
+            continue;
+        }
+
+        line.push(word, width);
+    }
+
+    lines.push(line.content);
+}
+
+fn wrap_characters(
+    text: &str,
+    max_width: f32,
+    measure: &dyn Fn(&str) -> f32,
+    line: &mut Line,
+    lines: &mut Vec<String>,
+) {
+    for grapheme in text.graphemes(true) {
+        let width = measure(grapheme);
+
+        if !line.content.is_empty() && line.width + width > max_width {
+            lines.push(line.take());
+        }
+
+        line.push(grapheme, width);
+    }
+}
+
+/// Shortens a line until it fits the given width, adding an ellipsis if
+/// needed.
+///
+/// When `is_forced` is true, the ellipsis is added even if the line fits.
+fn truncate(
+    line: &str,
+    max_width: f32,
+    ellipsis: Ellipsis,
+    is_forced: bool,
+    measure: &dyn Fn(&str) -> f32,
+) -> String {
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+
+    let candidate = |length: usize| match ellipsis {
+        Ellipsis::None => graphemes[..length].concat(),
+        Ellipsis::End => {
+            let start = graphemes[..length].concat();
+
+            [start.trim_end(), ELLIPSIS].concat()
+        }
+        Ellipsis::Middle => {
+            let start = graphemes[..length - length / 2].concat();
+            let end = graphemes[graphemes.len() - length / 2..].concat();
+
+            [start.trim_end(), ELLIPSIS, end.trim_start()].concat()
+        }
+    };
+
+    let fits = |length: usize| measure(&candidate(length)) <= max_width;
+
+    let mut length = if is_forced {
+        graphemes.len()
+    } else {
+        // The line does not fit by itself
+        graphemes.len().saturating_sub(1)
+    };
+
+    if !fits(length) {
+        // Find the longest candidate that fits using a binary search
+        let (mut low, mut high) = (0, length);
+
+        while low < high {
+            let middle = high - (high - low) / 2;
+
+            if fits(middle) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        length = low;
+    }
+
+    candidate(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Limits, Font};
+    use std::cell::Cell;
+
+    fn measure(content: &str) -> f32 {
+        content.chars().count() as f32 * 10.0
+    }
+
+    #[test]
+    fn keeps_long_words_whole() {
+        let mut lines = Vec::new();
+
+        wrap_words("hello wonderful world", 60.0, &measure, &mut lines);
+
+        assert_eq!(lines, vec!["hello", "wonderful", "world"]);
+    }
+
+    #[test]
+    fn measures_every_word_once() {
+        let measured = Cell::new(0);
+        let measure = |content: &str| {
+            measured.set(measured.get() + 1);
+            measure(content)
+        };
+
+        let mut lines = Vec::new();
+
+        wrap_words("aa bb cc dd", 50.0, &measure, &mut lines);
+
+        assert_eq!(lines, vec!["aa bb", "cc dd"]);
+        assert_eq!(measured.get(), 7);
+    }
+
+    #[test]
+    fn truncates_with_ellipsis() {
+        let path = "/home/user/projects/iced";
+
+        assert_eq!(
+            truncate(path, 100.0, Ellipsis::End, false, &measure),
+            "/home/use…"
+        );
+        assert_eq!(
+            truncate(path, 100.0, Ellipsis::Middle, false, &measure),
+            "/home…iced"
+        );
+        assert_eq!(
+            truncate("fits", 100.0, Ellipsis::End, true, &measure),
+            "fits…"
+        );
+    }
+
+    /// A renderer whose lines are narrower than the sum of their words, like
+    /// kerned text, and which wraps words in the given bounds.
+    #[derive(Default)]
+    struct Wrapping {
+        drawn: Option<(String, Rectangle)>,
+    }
+
+    impl Wrapping {
+        fn line_width(line: &str) -> f32 {
+            let kerned = line.trim().matches(' ').count() as f32 * 5.0;
+
+            measure(line) - kerned
+        }
+    }
+
+    impl crate::Renderer for Wrapping {
+        type Output = ();
+        type Defaults = ();
+
+        fn overlay(&mut self, _base: (), _overlay: (), _bounds: Rectangle) {}
+    }
+
+    impl Renderer for Wrapping {
+        type Font = Font;
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        fn measure(
+            &self,
+            content: &str,
+            size: u16,
+            _font: Font,
+            bounds: Size,
+        ) -> (f32, f32) {
+            let mut lines = Vec::new();
+
+            for paragraph in content.split('\n') {
+                if Self::line_width(paragraph) <= bounds.width {
+                    lines.push(paragraph.to_string());
+                    continue;
+                }
+
+                let mut line = String::new();
+
+                for word in paragraph.split(' ') {
+                    let candidate = if line.is_empty() {
+                        word.to_string()
+                    } else {
+                        [line.as_str(), " ", word].concat()
+                    };
+
+                    if !line.is_empty()
+                        && Self::line_width(&candidate) > bounds.width
+                    {
+                        lines.push(std::mem::replace(
+                            &mut line,
+                            word.to_string(),
+                        ));
+                    } else {
+                        line = candidate;
+                    }
+                }
+
+                lines.push(line);
+            }
+
+            let width = lines
+                .iter()
+                .map(|line| Self::line_width(line))
+                .fold(0.0, f32::max);
+
+            (width, lines.len() as f32 * f32::from(size))
+        }
+
+        fn hit_test(
+            &self,
+            _content: &str,
+            _size: u16,
+            _font: Font,
+            _bounds: Size,
+            _point: Point,
+        ) -> Option<usize> {
+            None
+        }
+
+        fn selection_bounds(
+            &self,
+            _content: &str,
+            _size: u16,
+            _font: Font,
+            _bounds: Size,
+            _start: usize,
+            _end: usize,
+        ) -> Vec<Rectangle> {
+            Vec::new()
+        }
+
+        fn draw(
+            &mut self,
+            _defaults: &(),
+            bounds: Rectangle,
+            content: &str,
+            _size: u16,
+            _font: Font,
+            _color: Option<Color>,
+            _horizontal_alignment: HorizontalAlignment,
+            _vertical_alignment: VerticalAlignment,
+        ) {
+            self.drawn = Some((content.to_string(), bounds));
+        }
+    }
+
+    fn layout_and_draw(text: Text<Wrapping>, max_width: f32) -> (Size, f32) {
+        let mut renderer = Wrapping::default();
+
+        let node = Widget::<(), _>::layout(
+            &text,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(max_width, 1000.0)),
+        );
+
+        Widget::<(), _>::draw(
+            &text,
+            &mut renderer,
+            &(),
+            Layout::new(&node),
+            Point::ORIGIN,
+        );
+
+        let (content, bounds) = renderer.drawn.take().unwrap();
+        let (_, height) =
+            renderer.measure(&content, 20, Font::Default, bounds.size());
+
+        (node.size(), height)
+    }
+
+    #[test]
+    fn shrink_text_draws_the_lines_it_lays_out() {
+        let (size, height) =
+            layout_and_draw(Text::new("aa bb cc dd").max_lines(10), 50.0);
+
+        assert_eq!(size.height, height);
+
+        let (size, height) = layout_and_draw(Text::new("aa bb cc dd"), 50.0);
+
+        assert_eq!(size, Size::new(45.0, 40.0));
+        assert_eq!(size.height, height);
+    }
+}
@@ -0,0 +1,1237 @@
+//! Display a multi-line area that can be filled with text.
+//!
+//! A [`TextEditor`] has some local [`State`], which owns its [`Content`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+//! [`Content`]: struct.Content.html
+mod content;
+mod editor;
+mod wrap;
+
+pub mod cursor;
+
+pub use content::{Content, Position};
+pub use cursor::Cursor;
+
+use editor::Editor;
+use wrap::Row;
+
+use crate::{
+    keyboard, layout,
+    mouse::{self, click},
+    scrollable, text, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Vector, Widget,
+};
+
+use std::cell::RefCell;
+use std::hash::Hash;
+
+/// A multi-line area that can be filled with text.
+///
+/// Long lines are soft wrapped to fit the width of the [`TextEditor`], and its
+/// contents are scrolled vertically with a scrollbar when they do not fit its
+/// height.
+///
+/// # Example
+/// ```
+/// # use iced_native::{text_editor, renderer::Null, Length};
+/// #
+/// # pub type TextEditor<'a, Message> = iced_native::TextEditor<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NotesChanged(String),
+/// }
+///
+/// let mut state = text_editor::State::with_text("Some\nnotes");
+///
+/// let editor = TextEditor::new(&mut state, "Write your notes here...")
+///     .on_change(Message::NotesChanged)
+///     .height(Length::Units(300))
+///     .padding(10);
+/// ```
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    placeholder: String,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    padding: u16,
+    size: Option<u16>,
+    on_change: Option<Box<dyn Fn(String) -> Message>>,
+    style: <Renderer as self::Renderer>::Style,
+    scrollbar_style: <Renderer as scrollable::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> TextEditor<'a, Message, Renderer> {
+    /// Creates a new [`TextEditor`] with the given [`State`] and placeholder.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State, placeholder: &str) -> Self {
+        TextEditor {
+            state,
+            placeholder: String::from(placeholder),
+            font: Default::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            padding: 0,
+            size: None,
+            on_change: None,
+            style: Default::default(),
+            scrollbar_style: Default::default(),
+        }
+    }
+
+    /// Sets the function that produces a message when the contents of the
+    /// [`TextEditor`] change.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the padding of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn scrollbar_style(
+        mut self,
+        style: impl Into<<Renderer as scrollable::Renderer>::Style>,
+    ) -> Self {
+        self.scrollbar_style = style.into();
+        self
+    }
+
+    /// Returns the current [`State`] of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    pub fn state(&self) -> &State {
+        self.state
+    }
+
+    fn rows<'b>(
+        &self,
+        renderer: &Renderer,
+        cache: &'b mut wrap::Cache,
+        width: f32,
+    ) -> &'b [Row] {
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        cache.rows(&self.state.content, size, width, |text| {
+            renderer.measure_value(text, size, self.font)
+        })
+    }
+
+    fn position_at(
+        &self,
+        renderer: &Renderer,
+        rows: &[Row],
+        text_bounds: Rectangle,
+        offset: f32,
+        point: Point,
+    ) -> (Position, bool) {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let line_height = line_height(size);
+
+        let y = point.y - text_bounds.y + offset;
+        let index = (y.max(0.0) / line_height) as usize;
+
+        match rows.get(index) {
+            Some(row) => {
+                let column = find_column(
+                    renderer,
+                    &self.state.content,
+                    row,
+                    self.font,
+                    size,
+                    point.x - text_bounds.x,
+                );
+
+                (Position::new(row.line, column), column == row.end)
+            }
+            None => (self.state.content.end(), false),
+        }
+    }
+
+    fn move_vertically(
+        &mut self,
+        renderer: &Renderer,
+        rows: &[Row],
+        amount: isize,
+        select: bool,
+    ) {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let content = &self.state.content;
+
+        let position = self.state.cursor.position(content);
+        let current = wrap::find_row(rows, position, self.state.is_at_row_end);
+
+        let target = current as isize + amount;
+
+        self.state.is_at_row_end = false;
+
+        let position = if target < 0 {
+            Position::default()
+        } else if target as usize >= rows.len() {
+            content.end()
+        } else {
+            let x = self.state.preferred_x.unwrap_or_else(|| {
+                x_of(
+                    renderer,
+                    content,
+                    &rows[current],
+                    self.font,
+                    size,
+                    position,
+                )
+            });
+
+            let row = &rows[target as usize];
+            let column =
+                find_column(renderer, content, row, self.font, size, x);
+
+            self.state.preferred_x = Some(x);
+            self.state.is_at_row_end = column == row.end;
+
+            Position::new(row.line, column)
+        };
+
+        self.state.cursor.move_or_select(content, position, select);
+    }
+
+    fn scroll_to_cursor(
+        &mut self,
+        rows: &[Row],
+        line_height: f32,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+    ) {
+        let content_bounds =
+            content_bounds(bounds, text_bounds, rows.len(), line_height);
+        let offset = self.state.offset(bounds, content_bounds);
+
+        let position = self.state.cursor.position(&self.state.content);
+        let index = wrap::find_row(rows, position, self.state.is_at_row_end);
+        let top = index as f32 * line_height;
+        let bottom = top + line_height;
+
+        let target = if top < offset {
+            top
+        } else if bottom > offset + text_bounds.height {
+            (bottom - text_bounds.height).ceil()
+        } else {
+            return;
+        };
+
+        self.state.scrollable.scroll_to(
+            Vector::new(0.0, target),
+            bounds,
+            content_bounds,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.padding as f32;
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let limits = limits
+            .pad(padding)
+            .width(self.width)
+            .height(self.height)
+            .max_width(self.max_width)
+            .min_height(line_height(text_size).ceil() as u32);
+
+        let measure =
+            |text: &str| renderer.measure_value(text, text_size, self.font);
+
+        let mut cache = self.state.rows.borrow_mut();
+        let mut width =
+            cache.max_width(&self.state.content, text_size, measure);
+
+        if self.state.content.is_empty() {
+            width = width.max(measure(&self.placeholder));
+        }
+
+        // Lines are wrapped at the resolved width, like `draw` and `on_event`
+        // do, so the rows are only wrapped once
+        let resolved_width = limits.resolve(Size::new(width, 0.0)).width;
+        let rows = self.rows(renderer, &mut cache, resolved_width);
+        let intrinsic =
+            Size::new(width, rows.len() as f32 * line_height(text_size));
+
+        let mut text = layout::Node::new(limits.resolve(intrinsic));
+        text.move_to(Point::new(padding, padding));
+
+        layout::Node::with_children(text.size().pad(padding), vec![text])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let size = self.size.unwrap_or(renderer.default_size());
+        let line_height = line_height(size);
+
+        // The rows are taken out of the state, so they can be read while the
+        // rest of it is being edited
+        let mut cache = std::mem::take(self.state.rows.get_mut());
+        let rows = self.rows(renderer, &mut cache, text_bounds.width);
+
+        let content_bounds =
+            content_bounds(bounds, text_bounds, rows.len(), line_height);
+
+        let is_mouse_over_scrollbar = self.state.scrollable.update(
+            &event,
+            bounds,
+            content_bounds,
+            cursor_position,
+            scrollable::Direction::Vertical,
+            line_height * 3.0,
+            false,
+            renderer,
+        );
+
+        let offset = self.state.offset(bounds, content_bounds);
+
+        let mut is_edited = false;
+        let mut is_cursor_moved = false;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if is_mouse_over_scrollbar =>
+            {
+                self.state.is_focused = true;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_clicked = bounds.contains(cursor_position);
+
+                if is_clicked {
+                    let (position, is_at_row_end) = self.position_at(
+                        renderer,
+                        rows,
+                        text_bounds,
+                        offset,
+                        cursor_position,
+                    );
+
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    let content = &self.state.content;
+
+                    match click.kind() {
+                        click::Kind::Single => {
+                            self.state.cursor.move_to(position);
+                            self.state.is_at_row_end = is_at_row_end;
+                        }
+                        click::Kind::Double => {
+                            let (start, end) = content.word_at(position);
+
+                            self.state.cursor.select_range(start, end);
+                            self.state.is_at_row_end = false;
+                        }
+                        click::Kind::Triple => {
+                            self.state.cursor.select_range(
+                                Position::new(position.line, 0),
+                                Position::new(
+                                    position.line,
+                                    content.line_len(position.line),
+                                ),
+                            );
+                            self.state.is_at_row_end = false;
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+                    self.state.preferred_x = None;
+                }
+
+                self.state.is_dragging = is_clicked;
+                self.state.is_focused = is_clicked;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { x, y })
+                if self.state.is_dragging =>
+            {
+                let (position, is_at_row_end) = self.position_at(
+                    renderer,
+                    rows,
+                    text_bounds,
+                    offset,
+                    Point::new(x, y),
+                );
+
+                self.state.cursor.select_range(
+                    self.state.cursor.start(&self.state.content),
+                    position,
+                );
+                self.state.is_at_row_end = is_at_row_end;
+
+                is_cursor_moved = true;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.is_focused
+                    && self.state.is_pasting.is_none()
+                    && !c.is_control() =>
+            {
+                let mut editor = Editor::new(
+                    &mut self.state.content,
+                    &mut self.state.cursor,
+                    &mut cache,
+                );
+
+                editor.insert(c.encode_utf8(&mut [0; 4]));
+
+                is_edited = true;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused => {
+                let content = &self.state.content;
+                let cursor = &mut self.state.cursor;
+                let jump = platform::is_jump_modifier_pressed(modifiers);
+                let is_at_row_end = self.state.is_at_row_end;
+
+                is_cursor_moved = true;
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        let mut editor = Editor::new(
+                            &mut self.state.content,
+                            &mut self.state.cursor,
+                            &mut cache,
+                        );
+
+                        editor.insert("\n");
+
+                        is_edited = true;
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if jump && cursor.selection(content).is_none() {
+                            let position = cursor.position(content);
+
+                            cursor.select_range(
+                                position,
+                                content.previous_start_of_word(position),
+                            );
+                        }
+
+                        let mut editor = Editor::new(
+                            &mut self.state.content,
+                            &mut self.state.cursor,
+                            &mut cache,
+                        );
+
+                        editor.backspace();
+
+                        is_edited = true;
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if jump && cursor.selection(content).is_none() {
+                            let position = cursor.position(content);
+
+                            cursor.select_range(
+                                position,
+                                content.next_end_of_word(position),
+                            );
+                        }
+
+                        let mut editor = Editor::new(
+                            &mut self.state.content,
+                            &mut self.state.cursor,
+                            &mut cache,
+                        );
+
+                        editor.delete();
+
+                        is_edited = true;
+                    }
+                    keyboard::KeyCode::Left => {
+                        let position = cursor.position(content);
+
+                        let target = if jump {
+                            content.previous_start_of_word(position)
+                        } else if modifiers.shift
+                            || cursor.selection(content).is_none()
+                        {
+                            content.previous_position(position)
+                        } else {
+                            cursor.left(content)
+                        };
+
+                        cursor.move_or_select(content, target, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Right => {
+                        let position = cursor.position(content);
+
+                        let target = if jump {
+                            content.next_end_of_word(position)
+                        } else if modifiers.shift
+                            || cursor.selection(content).is_none()
+                        {
+                            content.next_position(position)
+                        } else {
+                            cursor.right(content)
+                        };
+
+                        cursor.move_or_select(content, target, modifiers.shift);
+                    }
+                    keyboard::KeyCode::Up => {
+                        self.move_vertically(
+                            renderer,
+                            rows,
+                            -1,
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::Down => {
+                        self.move_vertically(
+                            renderer,
+                            rows,
+                            1,
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::PageUp | keyboard::KeyCode::PageDown => {
+                        let page =
+                            (text_bounds.height / line_height).floor().max(1.0)
+                                as isize;
+
+                        let amount = if key_code == keyboard::KeyCode::PageUp {
+                            -page
+                        } else {
+                            page
+                        };
+
+                        self.move_vertically(
+                            renderer,
+                            rows,
+                            amount,
+                            modifiers.shift,
+                        );
+
+                        self.state.scrollable.scroll(
+                            Vector::new(0.0, -(amount as f32) * line_height),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+                    keyboard::KeyCode::Home => {
+                        let target = if jump {
+                            Position::default()
+                        } else {
+                            let position = cursor.position(content);
+                            let row = &rows
+                                [wrap::find_row(rows, position, is_at_row_end)];
+
+                            Position::new(row.line, row.start)
+                        };
+
+                        cursor.move_or_select(content, target, modifiers.shift);
+                        self.state.is_at_row_end = false;
+                    }
+                    keyboard::KeyCode::End => {
+                        let target = if jump {
+                            content.end()
+                        } else {
+                            let position = cursor.position(content);
+                            let row = &rows
+                                [wrap::find_row(rows, position, is_at_row_end)];
+
+                            Position::new(row.line, row.end)
+                        };
+
+                        cursor.move_or_select(content, target, modifiers.shift);
+                        self.state.is_at_row_end = !jump;
+                    }
+                    keyboard::KeyCode::V => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            if let Some(clipboard) = clipboard {
+                                let content = match self.state.is_pasting.take()
+                                {
+                                    Some(content) => content,
+                                    None => clipboard
+                                        .content()
+                                        .unwrap_or_default()
+                                        .chars()
+                                        .filter(|c| {
+                                            !c.is_control() || *c == '\n'
+                                        })
+                                        .collect(),
+                                };
+
+                                let mut editor = Editor::new(
+                                    &mut self.state.content,
+                                    &mut self.state.cursor,
+                                    &mut cache,
+                                );
+
+                                editor.insert(&content);
+
+                                self.state.is_pasting = Some(content);
+
+                                is_edited = true;
+                            }
+                        } else {
+                            self.state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::C => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            if let (Some(clipboard), Some((start, end))) =
+                                (clipboard, cursor.selection(content))
+                            {
+                                let _ =
+                                    clipboard.write(content.slice(start, end));
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::X => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            if let (Some(clipboard), Some((start, end))) =
+                                (clipboard, cursor.selection(content))
+                            {
+                                // Keep the selection if it could not be copied
+                                if clipboard.write(content.slice(start, end)) {
+                                    let mut editor = Editor::new(
+                                        &mut self.state.content,
+                                        &mut self.state.cursor,
+                                        &mut cache,
+                                    );
+
+                                    editor.delete();
+
+                                    is_edited = true;
+                                }
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::A => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            cursor.select_all(content);
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.state.is_focused = false;
+                        self.state.is_dragging = false;
+                        self.state.is_pasting = None;
+                    }
+                    _ => {
+                        is_cursor_moved = false;
+                    }
+                }
+
+                if !matches!(
+                    key_code,
+                    keyboard::KeyCode::Up
+                        | keyboard::KeyCode::Down
+                        | keyboard::KeyCode::PageUp
+                        | keyboard::KeyCode::PageDown
+                ) {
+                    self.state.preferred_x = None;
+                }
+
+                if is_cursor_moved
+                    && !matches!(
+                        key_code,
+                        keyboard::KeyCode::Up
+                            | keyboard::KeyCode::Down
+                            | keyboard::KeyCode::PageUp
+                            | keyboard::KeyCode::PageDown
+                            | keyboard::KeyCode::End
+                    )
+                {
+                    self.state.is_at_row_end = false;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code: keyboard::KeyCode::V,
+                ..
+            }) => {
+                self.state.is_pasting = None;
+            }
+            _ => {}
+        }
+
+        if is_edited {
+            self.state.preferred_x = None;
+            self.state.is_at_row_end = false;
+
+            if let Some(on_change) = &self.on_change {
+                messages.push(on_change(self.state.content.text()));
+            }
+        }
+
+        if is_edited || is_cursor_moved {
+            let rows = self.rows(renderer, &mut cache, text_bounds.width);
+
+            self.scroll_to_cursor(rows, line_height, bounds, text_bounds);
+        }
+
+        *self.state.rows.get_mut() = cache;
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let size = self.size.unwrap_or(renderer.default_size());
+        let line_height = line_height(size);
+        let content = &self.state.content;
+        let mut cache = self.state.rows.borrow_mut();
+        let rows = self.rows(renderer, &mut cache, text_bounds.width);
+
+        let content_bounds =
+            content_bounds(bounds, text_bounds, rows.len(), line_height);
+        let offset = self.state.offset(bounds, content_bounds);
+
+        let first = (offset / line_height) as usize;
+        let last =
+            ((offset + text_bounds.height) / line_height).ceil() as usize;
+
+        let row_bounds = |index: usize| Rectangle {
+            x: text_bounds.x,
+            y: text_bounds.y + index as f32 * line_height - offset,
+            width: text_bounds.width,
+            height: line_height,
+        };
+
+        let lines: Vec<Line> = rows
+            .iter()
+            .enumerate()
+            .take(last.min(rows.len()))
+            .skip(first)
+            .map(|(index, row)| Line {
+                content: wrap::text(content, row),
+                bounds: row_bounds(index),
+            })
+            .collect();
+
+        let mut selection = Vec::new();
+        let mut cursor = None;
+
+        if self.state.is_focused {
+            match self.state.cursor.selection(content) {
+                Some((start, end)) => {
+                    for (index, row) in rows
+                        .iter()
+                        .enumerate()
+                        .take(last.min(rows.len()))
+                        .skip(first)
+                    {
+                        let row_start = Position::new(row.line, row.start);
+                        let row_end = Position::new(row.line, row.end);
+
+                        if row_end < start || row_start > end {
+                            continue;
+                        }
+
+                        let left = if start > row_start {
+                            x_of(renderer, content, row, self.font, size, start)
+                        } else {
+                            0.0
+                        };
+
+                        let right = if end < row_end {
+                            x_of(renderer, content, row, self.font, size, end)
+                        } else {
+                            x_of(
+                                renderer, content, row, self.font, size,
+                                row_end,
+                            ) + if end.line > row.line {
+                                // Make line breaks visible
+                                f32::from(size) / 3.0
+                            } else {
+                                0.0
+                            }
+                        };
+
+                        let bounds = row_bounds(index);
+
+                        selection.push(Rectangle {
+                            x: bounds.x + left,
+                            width: right - left,
+                            ..bounds
+                        });
+                    }
+                }
+                None => {
+                    let position = self.state.cursor.position(content);
+                    let index = wrap::find_row(
+                        rows,
+                        position,
+                        self.state.is_at_row_end,
+                    );
+                    let bounds = row_bounds(index);
+
+                    cursor = Some(Rectangle {
+                        x: bounds.x
+                            + x_of(
+                                renderer,
+                                content,
+                                &rows[index],
+                                self.font,
+                                size,
+                                position,
+                            ),
+                        width: 1.0,
+                        ..bounds
+                    });
+                }
+            }
+        }
+
+        let scrollbars = renderer.scrollbars(
+            scrollable::Direction::Vertical,
+            bounds,
+            content_bounds,
+            Vector::new(0, offset as u32),
+        );
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        let editor = self::Renderer::draw(
+            renderer,
+            bounds,
+            text_bounds,
+            cursor_position,
+            self.font,
+            size,
+            if content.is_empty() {
+                Some(&self.placeholder)
+            } else {
+                None
+            },
+            &lines,
+            &selection,
+            cursor,
+            self.state.is_focused,
+            &self.style,
+        );
+
+        // The lines are already scrolled, so the editor is not offset again
+        scrollable::Renderer::draw(
+            renderer,
+            &self.state.scrollable,
+            bounds,
+            content_bounds,
+            bounds.contains(cursor_position),
+            is_mouse_over_scrollbar,
+            scrollbars,
+            Vector::new(0, 0),
+            &self.scrollbar_style,
+            editor,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::any::TypeId;
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+
+        if self.width == Length::Shrink || self.height == Length::Shrink {
+            self.state.content.hash(state);
+        }
+    }
+}
+
+/// A visual line of a [`TextEditor`], ready to be drawn.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    /// The text of the line.
+    pub content: String,
+
+    /// The bounds of the line.
+    pub bounds: Rectangle,
+}
+
+/// The renderer of a [`TextEditor`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TextEditor`] in your user interface.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: scrollable::Renderer + text::Renderer + Sized {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the width of some text of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn measure_value(&self, value: &str, size: u16, font: Self::Font) -> f32;
+
+    /// Draws a [`TextEditor`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`TextEditor`]
+    /// - the bounds of the text
+    /// - the cursor position
+    /// - the placeholder to show, if the [`TextEditor`] is empty
+    /// - the visible lines of text, already laid out
+    /// - the bounds of the selected text
+    /// - the bounds of the text cursor, if visible
+    /// - whether the [`TextEditor`] is focused or not
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Self::Font,
+        size: u16,
+        placeholder: Option<&str>,
+        lines: &[Line],
+        selection: &[Rectangle],
+        cursor: Option<Rectangle>,
+        is_focused: bool,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// The state of a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    content: Content,
+    cursor: Cursor,
+    scrollable: scrollable::State,
+    preferred_x: Option<f32>,
+    is_focused: bool,
+    is_dragging: bool,
+    is_pasting: Option<String>,
+    is_at_row_end: bool,
+    last_click: Option<mouse::Click>,
+    rows: RefCell<wrap::Cache>,
+}
+
+impl State {
+    /// Creates a new, empty [`State`], representing an unfocused
+    /// [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`] with the given text.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn with_text(text: &str) -> Self {
+        Self {
+            content: Content::new(text),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new, empty [`State`], representing a focused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn focused() -> Self {
+        Self {
+            is_focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the [`Content`] of the [`TextEditor`].
+    ///
+    /// [`Content`]: struct.Content.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Returns the text of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn text(&self) -> String {
+        self.content.text()
+    }
+
+    /// Replaces the text of the [`TextEditor`], moving the [`Cursor`] to the
+    /// start.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Cursor`]: struct.Cursor.html
+    pub fn set_text(&mut self, text: &str) {
+        self.content = Content::new(text);
+        self.cursor = Cursor::default();
+        self.scrollable = scrollable::State::new();
+        self.preferred_x = None;
+        self.is_at_row_end = false;
+        self.rows = RefCell::default();
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Cursor`] of the [`TextEditor`].
+    ///
+    /// [`Cursor`]: struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to the given [`Position`].
+    ///
+    /// [`Cursor`]: struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Position`]: struct.Position.html
+    pub fn move_cursor_to(&mut self, position: Position) {
+        self.cursor.move_to(self.content.clamp(position));
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to the end of its text.
+    ///
+    /// [`Cursor`]: struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor.move_to(self.content.end());
+    }
+
+    fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+        self.scrollable.offset(bounds, content_bounds).y as f32
+    }
+}
+
+/// Returns the bounds of the scrollable contents of a [`TextEditor`], given its
+/// bounds, the bounds of its text and its amount of rows.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+fn content_bounds(
+    bounds: Rectangle,
+    text_bounds: Rectangle,
+    rows: usize,
+    line_height: f32,
+) -> Rectangle {
+    Rectangle {
+        height: bounds.height - text_bounds.height + rows as f32 * line_height,
+        ..bounds
+    }
+}
+
+/// Returns the height of a line of text with the given size.
+fn line_height(size: u16) -> f32 {
+    f32::from(size) * 1.2
+}
+
+/// Returns the horizontal offset of the given [`Position`] inside a [`Row`].
+fn x_of<Renderer: self::Renderer>(
+    renderer: &Renderer,
+    content: &Content,
+    row: &Row,
+    font: Renderer::Font,
+    size: u16,
+    position: Position,
+) -> f32 {
+    let prefix = content.slice(
+        Position::new(row.line, row.start),
+        Position::new(row.line, position.column.max(row.start).min(row.end)),
+    );
+
+    renderer.measure_value(&prefix, size, font)
+}
+
+/// Finds the column of a [`Row`] closest to the given horizontal offset.
+fn find_column<Renderer: self::Renderer>(
+    renderer: &Renderer,
+    content: &Content,
+    row: &Row,
+    font: Renderer::Font,
+    size: u16,
+    x: f32,
+) -> usize {
+    let width = |column| {
+        x_of(
+            renderer,
+            content,
+            row,
+            font,
+            size,
+            Position::new(row.line, column),
+        )
+    };
+
+    let mut start = row.start;
+    let mut end = row.end;
+
+    // Find the first column whose offset is past the target
+    while start < end {
+        let middle = start + (end - start) / 2;
+
+        if width(middle) < x {
+            start = middle + 1;
+        } else {
+            end = middle;
+        }
+    }
+
+    if start > row.start && x - width(start - 1) < width(start) - x {
+        start - 1
+    } else {
+        start
+    }
+}
+
+mod platform {
+    use crate::keyboard;
+
+    pub fn is_jump_modifier_pressed(
+        modifiers: keyboard::ModifiersState,
+    ) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.alt
+        } else {
+            modifiers.control
+        }
+    }
+
+    pub fn is_copy_paste_modifier_pressed(
+        modifiers: keyboard::ModifiersState,
+    ) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.logo
+        } else {
+            modifiers.control
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use crate::text_input::Value;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The contents of a [`TextEditor`].
+///
+/// The text is stored as a list of lines, so edits only need to touch the
+/// lines they affect, no matter how long the whole text is.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Content {
+    lines: Vec<String>,
+}
+
+/// A location in some [`Content`].
+///
+/// [`Content`]: struct.Content.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+
+    /// The index of the grapheme in the line.
+    pub column: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`] with the given line and column.
+    ///
+    /// [`Position`]: struct.Position.html
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+impl Content {
+    /// Creates a new [`Content`] from a string slice.
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn new(text: &str) -> Self {
+        Self {
+            lines: split_lines(text).map(String::from).collect(),
+        }
+    }
+
+    /// Returns the amount of lines in the [`Content`].
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the line at the given index, if it exists.
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.lines.get(index).map(String::as_str)
+    }
+
+    /// Returns an iterator over the lines of the [`Content`].
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    /// Returns the amount of graphemes in the line at the given index.
+    pub fn line_len(&self, index: usize) -> usize {
+        self.line(index)
+            .map(|line| line.graphemes(true).count())
+            .unwrap_or(0)
+    }
+
+    /// Returns whether the [`Content`] is empty or not.
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    /// Returns the [`Position`] right after the last grapheme of the
+    /// [`Content`].
+    ///
+    /// [`Position`]: struct.Position.html
+    /// [`Content`]: struct.Content.html
+    pub fn end(&self) -> Position {
+        let line = self.lines.len() - 1;
+
+        Position::new(line, self.line_len(line))
+    }
+
+    /// Returns the text of the [`Content`], joining its lines with `\n`.
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the text between two positions.
+    pub fn slice(&self, start: Position, end: Position) -> String {
+        let (start, end) = self.order(start, end);
+
+        if start.line == end.line {
+            let line = &self.lines[start.line];
+
+            return line
+                [byte_index(line, start.column)..byte_index(line, end.column)]
+                .to_string();
+        }
+
+        let first = &self.lines[start.line];
+        let last = &self.lines[end.line];
+
+        let mut slice = first[byte_index(first, start.column)..].to_string();
+
+        for line in &self.lines[start.line + 1..end.line] {
+            slice.push('\n');
+            slice.push_str(line);
+        }
+
+        slice.push('\n');
+        slice.push_str(&last[..byte_index(last, end.column)]);
+        slice
+    }
+
+    /// Clamps a [`Position`] so it points inside the [`Content`].
+    ///
+    /// [`Position`]: struct.Position.html
+    /// [`Content`]: struct.Content.html
+    pub fn clamp(&self, position: Position) -> Position {
+        let line = position.line.min(self.lines.len() - 1);
+
+        Position::new(line, position.column.min(self.line_len(line)))
+    }
+
+    /// Returns the [`Position`] of the grapheme before the given one, moving
+    /// to the end of the previous line if needed.
+    ///
+    /// [`Position`]: struct.Position.html
+    pub fn previous_position(&self, position: Position) -> Position {
+        let position = self.clamp(position);
+
+        if position.column > 0 {
+            Position::new(position.line, position.column - 1)
+        } else if position.line > 0 {
+            Position::new(position.line - 1, self.line_len(position.line - 1))
+        } else {
+            position
+        }
+    }
+
+    /// Returns the [`Position`] of the grapheme after the given one, moving
+    /// to the start of the next line if needed.
+    ///
+    /// [`Position`]: struct.Position.html
+    pub fn next_position(&self, position: Position) -> Position {
+        let position = self.clamp(position);
+
+        if position.column < self.line_len(position.line) {
+            Position::new(position.line, position.column + 1)
+        } else if position.line + 1 < self.lines.len() {
+            Position::new(position.line + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    /// Returns the [`Position`] of the previous start of a word from the
+    /// given one.
+    ///
+    /// [`Position`]: struct.Position.html
+    pub fn previous_start_of_word(&self, position: Position) -> Position {
+        let position = self.clamp(position);
+
+        if position.column == 0 {
+            return self.previous_position(position);
+        }
+
+        let value = Value::new(&self.lines[position.line]);
+
+        Position::new(
+            position.line,
+            value.previous_start_of_word(position.column),
+        )
+    }
+
+    /// Returns the [`Position`] of the next end of a word from the given one.
+    ///
+    /// [`Position`]: struct.Position.html
+    pub fn next_end_of_word(&self, position: Position) -> Position {
+        let position = self.clamp(position);
+
+        if position.column == self.line_len(position.line) {
+            return self.next_position(position);
+        }
+
+        let value = Value::new(&self.lines[position.line]);
+
+        Position::new(position.line, value.next_end_of_word(position.column))
+    }
+
+    /// Returns the bounds of the word at the given [`Position`].
+    ///
+    /// [`Position`]: struct.Position.html
+    pub fn word_at(&self, position: Position) -> (Position, Position) {
+        let position = self.clamp(position);
+        let value = Value::new(&self.lines[position.line]);
+
+        (
+            Position::new(
+                position.line,
+                value.previous_start_of_word(position.column),
+            ),
+            Position::new(
+                position.line,
+                value.next_end_of_word(position.column),
+            ),
+        )
+    }
+
+    /// Inserts some text at the given [`Position`], returning the
+    /// [`Position`] right after the inserted text.
+    ///
+    /// [`Position`]: struct.Position.html
+    pub fn insert(&mut self, position: Position, text: &str) -> Position {
+        let position = self.clamp(position);
+
+        let line = &mut self.lines[position.line];
+        let tail = line.split_off(byte_index(line, position.column));
+
+        let mut new_lines = split_lines(text);
+        line.push_str(new_lines.next().unwrap_or(""));
+
+        let mut inserted: Vec<String> = new_lines.map(String::from).collect();
+
+        match inserted.last_mut() {
+            None => {
+                let column = line.graphemes(true).count();
+                line.push_str(&tail);
+
+                Position::new(position.line, column)
+            }
+            Some(last) => {
+                let column = last.graphemes(true).count();
+                last.push_str(&tail);
+
+                let count = inserted.len();
+                let index = position.line + 1;
+                let _ = self.lines.splice(index..index, inserted);
+
+                Position::new(position.line + count, column)
+            }
+        }
+    }
+
+    /// Removes the text between two positions.
+    pub fn remove(&mut self, start: Position, end: Position) {
+        let (start, end) = self.order(start, end);
+
+        if start.line == end.line {
+            let line = &mut self.lines[start.line];
+            let range =
+                byte_index(line, start.column)..byte_index(line, end.column);
+
+            let _ = line.drain(range);
+        } else {
+            let last = &self.lines[end.line];
+            let tail = last[byte_index(last, end.column)..].to_string();
+
+            let first = &mut self.lines[start.line];
+            first.truncate(byte_index(first, start.column));
+            first.push_str(&tail);
+
+            let _ = self.lines.drain(start.line + 1..=end.line);
+        }
+    }
+
+    fn order(&self, a: Position, b: Position) -> (Position, Position) {
+        let a = self.clamp(a);
+        let b = self.clamp(b);
+
+        (a.min(b), a.max(b))
+    }
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+fn split_lines(text: &str) -> impl Iterator<Item = &str> {
+    text.split('\n').map(|line| line.trim_end_matches('\r'))
+}
+
+fn byte_index(line: &str, column: usize) -> usize {
+    line.grapheme_indices(true)
+        .nth(column)
+        .map(|(index, _)| index)
+        .unwrap_or_else(|| line.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert() {
+        let mut content = Content::new("Hello\nworld");
+
+        let end = content.insert(Position::new(0, 5), ",\nbrave new");
+
+        assert_eq!(content.text(), "Hello,\nbrave new\nworld");
+        assert_eq!(end, Position::new(1, 9));
+
+        let end = content.insert(Position::new(2, 5), "!");
+
+        assert_eq!(content.text(), "Hello,\nbrave new\nworld!");
+        assert_eq!(end, Position::new(2, 6));
+    }
+
+    #[test]
+    fn remove() {
+        let mut content = Content::new("Hello,\nbrave new\nworld");
+
+        content.remove(Position::new(1, 5), Position::new(0, 5));
+        assert_eq!(content.text(), "Hello new\nworld");
+
+        content.remove(Position::new(0, 5), Position::new(1, 0));
+        assert_eq!(content.text(), "Helloworld");
+    }
+
+    #[test]
+    fn slice() {
+        let content = Content::new("Hello,\nbrave new\nworld");
+
+        assert_eq!(
+            content.slice(Position::new(0, 5), Position::new(2, 3)),
+            ",\nbrave new\nwor"
+        );
+        assert_eq!(
+            content.slice(Position::new(1, 6), Position::new(1, 9)),
+            "new"
+        );
+    }
+
+    #[test]
+    fn navigation() {
+        let content = Content::new("one two\nthree");
+
+        assert_eq!(
+            content.next_position(Position::new(0, 7)),
+            Position::new(1, 0)
+        );
+        assert_eq!(
+            content.previous_position(Position::new(1, 0)),
+            Position::new(0, 7)
+        );
+        assert_eq!(
+            content.next_end_of_word(Position::new(0, 0)),
+            Position::new(0, 3)
+        );
+        assert_eq!(
+            content.previous_start_of_word(Position::new(0, 7)),
+            Position::new(0, 4)
+        );
+        assert_eq!(content.clamp(Position::new(5, 5)), Position::new(1, 5));
+    }
+}
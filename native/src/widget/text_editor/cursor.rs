@@ -0,0 +1,130 @@
+//! Track the cursor of a text editor.
+use crate::widget::text_editor::{Content, Position};
+
+/// The cursor of a text editor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    state: State,
+}
+
+/// The state of a [`Cursor`].
+///
+/// [`Cursor`]: struct.Cursor.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum State {
+    /// Cursor without a selection
+    Index(Position),
+
+    /// Cursor selecting a range of text
+    Selection {
+        /// The start of the selection
+        start: Position,
+        /// The end of the selection
+        end: Position,
+    },
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Cursor {
+            state: State::Index(Position::default()),
+        }
+    }
+}
+
+impl Cursor {
+    /// Returns the [`State`] of the [`Cursor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Cursor`]: struct.Cursor.html
+    pub fn state(&self, content: &Content) -> State {
+        match self.state {
+            State::Index(index) => State::Index(content.clamp(index)),
+            State::Selection { start, end } => {
+                let start = content.clamp(start);
+                let end = content.clamp(end);
+
+                if start == end {
+                    State::Index(start)
+                } else {
+                    State::Selection { start, end }
+                }
+            }
+        }
+    }
+
+    /// Returns the [`Position`] where the [`Cursor`] is currently placed.
+    ///
+    /// When selecting, this is the end of the selection that moves.
+    ///
+    /// [`Position`]: struct.Position.html
+    /// [`Cursor`]: struct.Cursor.html
+    pub fn position(&self, content: &Content) -> Position {
+        match self.state(content) {
+            State::Index(index) => index,
+            State::Selection { end, .. } => end,
+        }
+    }
+
+    /// Returns the ordered bounds of the current selection, if any.
+    pub fn selection(&self, content: &Content) -> Option<(Position, Position)> {
+        match self.state(content) {
+            State::Selection { start, end } => {
+                Some((start.min(end), start.max(end)))
+            }
+            State::Index(_) => None,
+        }
+    }
+
+    pub(crate) fn move_to(&mut self, position: Position) {
+        self.state = State::Index(position);
+    }
+
+    pub(crate) fn select_range(&mut self, start: Position, end: Position) {
+        if start == end {
+            self.state = State::Index(start);
+        } else {
+            self.state = State::Selection { start, end };
+        }
+    }
+
+    /// Moves the cursor to the given position, extending the current
+    /// selection instead if `select` is true.
+    pub(crate) fn move_or_select(
+        &mut self,
+        content: &Content,
+        position: Position,
+        select: bool,
+    ) {
+        if select {
+            self.select_range(self.start(content), position);
+        } else {
+            self.move_to(position);
+        }
+    }
+
+    pub(crate) fn select_all(&mut self, content: &Content) {
+        self.select_range(Position::default(), content.end());
+    }
+
+    pub(crate) fn start(&self, content: &Content) -> Position {
+        match self.state(content) {
+            State::Index(index) => index,
+            State::Selection { start, .. } => start,
+        }
+    }
+
+    pub(crate) fn left(&self, content: &Content) -> Position {
+        match self.state(content) {
+            State::Index(index) => index,
+            State::Selection { start, end } => start.min(end),
+        }
+    }
+
+    pub(crate) fn right(&self, content: &Content) -> Position {
+        match self.state(content) {
+            State::Index(index) => index,
+            State::Selection { start, end } => start.max(end),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::text_editor::{wrap, Content, Cursor, Position};
+
+pub struct Editor<'a> {
+    content: &'a mut Content,
+    cursor: &'a mut Cursor,
+    rows: &'a mut wrap::Cache,
+}
+
+impl<'a> Editor<'a> {
+    pub fn new(
+        content: &'a mut Content,
+        cursor: &'a mut Cursor,
+        rows: &'a mut wrap::Cache,
+    ) -> Editor<'a> {
+        Editor {
+            content,
+            cursor,
+            rows,
+        }
+    }
+
+    pub fn insert(&mut self, text: &str) {
+        let _ = self.remove_selection();
+
+        let position = self.cursor.position(self.content);
+        let lines = self.content.line_count();
+        let end = self.content.insert(position, text);
+
+        self.invalidate(position, lines);
+        self.cursor.move_to(end);
+    }
+
+    pub fn backspace(&mut self) {
+        if self.remove_selection() {
+            return;
+        }
+
+        let position = self.cursor.position(self.content);
+        let previous = self.content.previous_position(position);
+
+        self.remove(previous, position);
+        self.cursor.move_to(previous);
+    }
+
+    pub fn delete(&mut self) {
+        if self.remove_selection() {
+            return;
+        }
+
+        let position = self.cursor.position(self.content);
+        let next = self.content.next_position(position);
+
+        self.remove(position, next);
+    }
+
+    fn remove_selection(&mut self) -> bool {
+        match self.cursor.selection(self.content) {
+            Some((start, end)) => {
+                self.remove(start, end);
+                self.cursor.move_to(start);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn remove(&mut self, start: Position, end: Position) {
+        let lines = self.content.line_count();
+
+        self.content.remove(start, end);
+        self.invalidate(start.min(end), lines);
+    }
+
+    /// Marks the lines touched by an edit at the given position as changed,
+    /// given the amount of lines before the edit.
+    fn invalidate(&mut self, position: Position, lines: usize) {
+        let after = self.content.line_count();
+
+        self.rows.splice(
+            position.line,
+            1 + lines.saturating_sub(after),
+            1 + after.saturating_sub(lines),
+        );
+    }
+}
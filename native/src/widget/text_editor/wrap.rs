@@ -0,0 +1,324 @@
+use crate::text_editor::{Content, Position};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A visual row of a [`Content`], after soft wrapping its lines.
+///
+/// [`Content`]: struct.Content.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Row {
+    pub fn contains(&self, position: Position) -> bool {
+        self.line == position.line
+            && self.start <= position.column
+            && position.column <= self.end
+    }
+}
+
+/// The soft wrapped rows of a [`Content`], kept between frames.
+///
+/// Every line is only wrapped again when it is edited, or when the width or
+/// the text size change. The unwrapped width of every line is kept as well,
+/// until the line is edited or the text size changes.
+///
+/// [`Content`]: struct.Content.html
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    key: Option<(u16, f32)>,
+    lines: Vec<Option<Vec<(usize, usize)>>>,
+    size: Option<u16>,
+    widths: Vec<Option<f32>>,
+    rows: Vec<Row>,
+}
+
+impl Cache {
+    /// Returns the rows of the [`Content`], wrapping the lines that changed
+    /// since the last call.
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn rows(
+        &mut self,
+        content: &Content,
+        size: u16,
+        width: f32,
+        measure: impl Fn(&str) -> f32,
+    ) -> &[Row] {
+        if self.key != Some((size, width))
+            || self.lines.len() != content.line_count()
+        {
+            self.key = Some((size, width));
+            self.lines = vec![None; content.line_count()];
+        }
+
+        self.invalidate_widths(content, size);
+
+        if self.lines.iter().any(Option::is_none) {
+            for ((line, rows), line_width) in content
+                .lines()
+                .zip(self.lines.iter_mut())
+                .zip(self.widths.iter_mut())
+            {
+                if rows.is_none() {
+                    let (wrapped, unwrapped_width) =
+                        wrap(line, width, &measure);
+
+                    *rows = Some(wrapped);
+                    *line_width = Some(unwrapped_width);
+                }
+            }
+
+            self.rows = self
+                .lines
+                .iter()
+                .enumerate()
+                .flat_map(|(line, rows)| {
+                    rows.iter().flatten().map(move |&(start, end)| Row {
+                        line,
+                        start,
+                        end,
+                    })
+                })
+                .collect();
+        }
+
+        &self.rows
+    }
+
+    /// Returns the width of the widest line of the [`Content`], without
+    /// wrapping it.
+    ///
+    /// [`Content`]: struct.Content.html
+    pub fn max_width(
+        &mut self,
+        content: &Content,
+        size: u16,
+        measure: impl Fn(&str) -> f32,
+    ) -> f32 {
+        self.invalidate_widths(content, size);
+
+        content
+            .lines()
+            .zip(self.widths.iter_mut())
+            .map(|(line, width)| {
+                *width.get_or_insert_with(|| {
+                    line.split_word_bounds().map(&measure).sum()
+                })
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// Marks the given amount of lines, starting at `line`, as replaced by
+    /// a new amount of lines that need to be wrapped.
+    pub fn splice(&mut self, line: usize, removed: usize, inserted: usize) {
+        if line + removed > self.lines.len()
+            || line + removed > self.widths.len()
+        {
+            self.lines.clear();
+            self.widths.clear();
+            return;
+        }
+
+        let _ = self
+            .lines
+            .splice(line..line + removed, vec![None; inserted]);
+        let _ = self
+            .widths
+            .splice(line..line + removed, vec![None; inserted]);
+    }
+
+    fn invalidate_widths(&mut self, content: &Content, size: u16) {
+        if self.size != Some(size) || self.widths.len() != content.line_count()
+        {
+            self.size = Some(size);
+            self.widths = vec![None; content.line_count()];
+        }
+    }
+}
+
+/// Breaks a line into the columns of rows that fit the given width, and
+/// returns them together with the width of the whole line.
+///
+/// Lines are broken between words whenever possible. Words that do not fit
+/// in a single row are broken between graphemes.
+fn wrap(
+    line: &str,
+    width: f32,
+    measure: impl Fn(&str) -> f32,
+) -> (Vec<(usize, usize)>, f32) {
+    let mut rows = Vec::new();
+    let mut line_width = 0.0;
+    let mut start = 0;
+    let mut column = 0;
+    let mut row_width = 0.0;
+
+    for word in line.split_word_bounds() {
+        let word_width = measure(word);
+        line_width += word_width;
+
+        let graphemes = word.graphemes(true).count();
+        let is_whitespace = word.trim().is_empty();
+
+        if row_width + word_width > width && column > start && !is_whitespace {
+            rows.push((start, column));
+
+            start = column;
+            row_width = 0.0;
+        }
+
+        if word_width > width && !is_whitespace {
+            // The word does not fit in a row on its own
+            for grapheme in word.graphemes(true) {
+                let grapheme_width = measure(grapheme);
+
+                if row_width + grapheme_width > width && column > start {
+                    rows.push((start, column));
+
+                    start = column;
+                    row_width = 0.0;
+                }
+
+                row_width += grapheme_width;
+                column += 1;
+            }
+        } else {
+            row_width += word_width;
+            column += graphemes;
+        }
+    }
+
+    rows.push((start, column));
+
+    (rows, line_width)
+}
+
+/// Returns the index of the [`Row`] containing the given [`Position`].
+///
+/// A [`Position`] at the boundary between two rows of the same line belongs
+/// to the first one when `is_at_row_end` is true, and to the second one
+/// otherwise.
+///
+/// [`Row`]: struct.Row.html
+/// [`Position`]: struct.Position.html
+pub fn find_row(
+    rows: &[Row],
+    position: Position,
+    is_at_row_end: bool,
+) -> usize {
+    let index = if is_at_row_end {
+        rows.iter().position(|row| row.contains(position))
+    } else {
+        rows.iter().rposition(|row| row.contains(position))
+    };
+
+    index.unwrap_or(0)
+}
+
+/// Returns the text of a [`Row`].
+///
+/// [`Row`]: struct.Row.html
+pub fn text(content: &Content, row: &Row) -> String {
+    content.slice(
+        Position::new(row.line, row.start),
+        Position::new(row.line, row.end),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn row(line: usize, start: usize, end: usize) -> Row {
+        Row { line, start, end }
+    }
+
+    #[test]
+    fn only_edited_lines_are_wrapped_again() {
+        let mut content = Content::new("aaa bbb\nccc\nddd eee");
+        let mut cache = Cache::default();
+
+        let measured = Cell::new(0);
+        let measure = |text: &str| {
+            measured.set(measured.get() + 1);
+            text.chars().count() as f32
+        };
+
+        assert_eq!(
+            cache.rows(&content, 20, 5.0, measure),
+            &[
+                row(0, 0, 4),
+                row(0, 4, 7),
+                row(1, 0, 3),
+                row(2, 0, 4),
+                row(2, 4, 7)
+            ]
+        );
+
+        measured.set(0);
+        let _ = cache.rows(&content, 20, 5.0, measure);
+        assert_eq!(measured.get(), 0);
+
+        // Splits the second line in two
+        let _ = content.insert(Position::new(1, 1), "\n");
+        cache.splice(1, 1, 2);
+
+        assert_eq!(
+            cache.rows(&content, 20, 5.0, measure),
+            &[
+                row(0, 0, 4),
+                row(0, 4, 7),
+                row(1, 0, 1),
+                row(2, 0, 2),
+                row(3, 0, 4),
+                row(3, 4, 7)
+            ]
+        );
+        assert_eq!(measured.get(), 2);
+
+        measured.set(0);
+        let _ = cache.rows(&content, 20, 10.0, measure);
+        assert_eq!(measured.get(), 8);
+    }
+
+    #[test]
+    fn line_widths_are_kept_across_wrapping_widths() {
+        let mut content = Content::new("aaa bbb\nccc");
+        let mut cache = Cache::default();
+
+        let measured = Cell::new(0);
+        let measure = |text: &str| {
+            measured.set(measured.get() + 1);
+            text.chars().count() as f32
+        };
+
+        assert_eq!(cache.max_width(&content, 20, measure), 7.0);
+
+        let _ = cache.rows(&content, 20, 7.0, measure);
+        let _ = cache.rows(&content, 20, 5.0, measure);
+
+        measured.set(0);
+        assert_eq!(cache.max_width(&content, 20, measure), 7.0);
+        assert_eq!(measured.get(), 0);
+
+        let _ = content.insert(Position::new(1, 3), " dddd");
+        cache.splice(1, 1, 1);
+
+        assert_eq!(cache.max_width(&content, 20, measure), 8.0);
+        assert_eq!(measured.get(), 3);
+    }
+
+    #[test]
+    fn row_ends_belong_to_the_earlier_row_only_when_requested() {
+        let rows = [row(0, 0, 4), row(0, 4, 7), row(1, 0, 3)];
+
+        assert_eq!(find_row(&rows, Position::new(0, 4), true), 0);
+        assert_eq!(find_row(&rows, Position::new(0, 4), false), 1);
+        assert_eq!(find_row(&rows, Position::new(0, 7), true), 1);
+        assert_eq!(find_row(&rows, Position::new(1, 0), true), 2);
+    }
+}
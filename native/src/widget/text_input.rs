@@ -5,20 +5,24 @@
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
+pub mod mask;
 
 pub use cursor::Cursor;
+pub use mask::Mask;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
 use crate::{
     keyboard, layout,
     mouse::{self, click},
-    text, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle,
-    Size, Widget,
+    scrollable, text, window, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
 };
 
 use std::u32;
@@ -60,6 +64,9 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
+    filter: Option<Box<dyn Fn(char) -> bool>>,
+    max_length: Option<usize>,
+    mask: Option<Mask>,
     style: Renderer::Style,
 }
 
@@ -95,6 +102,9 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
             size: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            filter: None,
+            max_length: None,
+            mask: None,
             style: Renderer::Style::default(),
         }
     }
@@ -156,6 +166,43 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self
     }
 
+    /// Sets a predicate that typed and pasted characters must satisfy to be
+    /// accepted by the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: 'static + Fn(char) -> bool,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Only accepts ASCII digits in the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn numeric(self) -> Self {
+        self.filter(|c| c.is_ascii_digit())
+    }
+
+    /// Sets the maximum amount of graphemes of the value of the
+    /// [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the [`Mask`] that the value of the [`TextInput`] must follow.
+    ///
+    /// [`Mask`]: mask/struct.Mask.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn mask(mut self, mask: Mask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -170,6 +217,210 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
     pub fn state(&self) -> &State {
         self.state
     }
+
+    pub(crate) fn state_mut(&mut self) -> &mut State {
+        self.state
+    }
+
+    /// Returns true if the given character passes the filter and the mask of
+    /// the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn accepts(&self, c: char) -> bool {
+        self.filter.as_ref().map(|filter| filter(c)).unwrap_or(true)
+            && self
+                .mask
+                .as_ref()
+                .map(|mask| mask.accepts(c))
+                .unwrap_or(true)
+    }
+
+    /// Returns the amount of graphemes that can still be added to the value
+    /// of the [`TextInput`], replacing the current selection.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn remaining_length(&self) -> usize {
+        let selected = self
+            .state
+            .cursor
+            .selection(&self.value)
+            .map(|(start, end)| end - start)
+            .unwrap_or(0);
+
+        let length = self.value.len() - selected;
+
+        let max_length = match (self.max_length, &self.mask) {
+            (Some(max_length), Some(mask)) => max_length.min(mask.len()),
+            (Some(max_length), None) => max_length,
+            (None, Some(mask)) => mask.len(),
+            (None, None) => usize::MAX,
+        };
+
+        max_length.saturating_sub(length)
+    }
+
+    /// Removes the characters that the [`TextInput`] does not accept from the
+    /// given content, truncating it to the remaining length.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn sanitize(&self, content: &str) -> Value {
+        let content: String = content
+            .chars()
+            .filter(|c| !c.is_control() && self.accepts(*c))
+            .collect();
+
+        Value::new(&content).until(self.remaining_length())
+    }
+
+    /// Fits the value of the [`TextInput`] into its [`Mask`], if any, and
+    /// produces a change message.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Mask`]: mask/struct.Mask.html
+    fn change(&mut self, messages: &mut Vec<Message>) {
+        if let Some(mask) = &self.mask {
+            let (value, cursor) = mask.apply(
+                &self.value.to_string(),
+                self.state.cursor.end(&self.value),
+            );
+
+            self.value = Value::new(&value);
+            self.state.cursor.move_to(cursor);
+        }
+
+        let message = (self.on_change)(self.value.to_string());
+        messages.push(message);
+    }
+
+    /// Returns the [`Value`] of the [`TextInput`] as it is displayed.
+    ///
+    /// [`Value`]: struct.Value.html
+    /// [`TextInput`]: struct.TextInput.html
+    fn displayed_value(&self) -> Value {
+        if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        }
+    }
+
+    /// Finds the grapheme index closest to the given horizontal offset,
+    /// relative to the text bounds of the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn find_cursor_position(
+        &self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        target: f32,
+    ) -> usize {
+        if target + self.state.offset <= 0.0 {
+            return 0;
+        }
+
+        let value = self.displayed_value();
+
+        renderer.find_cursor_position(
+            text_bounds,
+            self.font,
+            self.size,
+            &value,
+            &self.state,
+            target,
+        )
+    }
+
+    /// Extends the selection of the [`TextInput`] to the grapheme closest to
+    /// the given X coordinate, while dragging the mouse.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn select_to(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        x: f32,
+    ) {
+        let target = x - text_bounds.x;
+
+        let position = self.find_cursor_position(renderer, text_bounds, target);
+
+        match (
+            self.state.last_click.map(|click| click.kind()),
+            self.state.selected_word,
+        ) {
+            (Some(click::Kind::Triple), _) => {}
+            (Some(click::Kind::Double), Some(word)) => {
+                let (start, end) = select_words(&self.value, word, position);
+
+                self.state.cursor.select_range(start, end);
+            }
+            _ => {
+                self.state.cursor.select_range(
+                    self.state.cursor.start(&self.value),
+                    position,
+                );
+            }
+        }
+    }
+
+    /// Scrolls the [`TextInput`] horizontally by the given amount of pixels,
+    /// without going past the end of its value.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn scroll(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        delta: f32,
+    ) {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let value = self.displayed_value().to_string();
+
+        let text_width = renderer.measure_value(&value, size, self.font);
+        let max_offset = (text_width + 5.0 - text_bounds.width).max(0.0);
+
+        self.state.offset =
+            (self.state.offset + delta).max(0.0).min(max_offset);
+    }
+
+    /// Moves the horizontal offset of the [`TextInput`] just enough to keep
+    /// its [`Cursor`] visible.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Cursor`]: struct.Cursor.html
+    fn scroll_to_cursor(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+    ) {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let value = self.displayed_value();
+
+        let focus = match self.state.cursor.state(&value) {
+            cursor::State::Index(index) => index,
+            cursor::State::Selection { end, .. } => end,
+        };
+
+        let cursor_x = renderer.measure_value(
+            &value.until(focus).to_string(),
+            size,
+            self.font,
+        );
+
+        let offset = self.state.offset;
+
+        if cursor_x < offset {
+            self.scroll(renderer, text_bounds, cursor_x - offset);
+        } else if cursor_x + 5.0 > offset + text_bounds.width {
+            self.scroll(
+                renderer,
+                text_bounds,
+                cursor_x + 5.0 - text_bounds.width - offset,
+            );
+        } else {
+            self.scroll(renderer, text_bounds, 0.0);
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -215,6 +466,8 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
     ) {
+        let previous_cursor = self.state.cursor.state(&self.value);
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let is_clicked = layout.bounds().contains(cursor_position);
@@ -228,46 +481,36 @@ where
                         self.state.last_click,
                     );
 
+                    let position = self.find_cursor_position(
+                        renderer,
+                        text_layout.bounds(),
+                        target,
+                    );
+
+                    self.state.selected_word = None;
+
                     match click.kind() {
                         click::Kind::Single => {
-                            if target > 0.0 {
-                                let value = if self.is_secure {
-                                    self.value.secure()
-                                } else {
-                                    self.value.clone()
-                                };
-
-                                let position = renderer.find_cursor_position(
-                                    text_layout.bounds(),
-                                    self.font,
-                                    self.size,
-                                    &value,
-                                    &self.state,
-                                    target,
+                            if self.state.keyboard_modifiers.shift {
+                                self.state.cursor.select_range(
+                                    self.state.cursor.start(&self.value),
+                                    position,
                                 );
-
-                                self.state.cursor.move_to(position);
                             } else {
-                                self.state.cursor.move_to(0);
+                                self.state.cursor.move_to(position);
                             }
                         }
                         click::Kind::Double => {
                             if self.is_secure {
                                 self.state.cursor.select_all(&self.value);
                             } else {
-                                let position = renderer.find_cursor_position(
-                                    text_layout.bounds(),
-                                    self.font,
-                                    self.size,
-                                    &self.value,
-                                    &self.state,
-                                    target,
-                                );
-
-                                self.state.cursor.select_range(
+                                let word = (
                                     self.value.previous_start_of_word(position),
                                     self.value.next_end_of_word(position),
                                 );
+
+                                self.state.cursor.select_range(word.0, word.1);
+                                self.state.selected_word = Some(word);
                             }
                         }
                         click::Kind::Triple => {
@@ -278,52 +521,103 @@ where
                     self.state.last_click = Some(click);
                 }
 
+                if !is_clicked {
+                    self.state.offset = 0.0;
+                    self.state.preedit = None;
+                }
+
                 self.state.is_dragging = is_clicked;
                 self.state.is_focused = is_clicked;
             }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, .. } => {
+                        x * f32::from(scrollable::LINE_HEIGHT)
+                    }
+                    mouse::ScrollDelta::Pixels { x, .. } => x,
+                };
+
+                if delta != 0.0 {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+
+                    self.scroll(renderer, text_bounds, -delta);
+                }
+            }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
                 self.state.is_dragging = false;
             }
-            Event::Mouse(mouse::Event::CursorMoved { x, .. }) => {
-                if self.state.is_dragging {
-                    let text_layout = layout.children().next().unwrap();
-                    let target = x - text_layout.bounds().x;
+            Event::Mouse(mouse::Event::CursorMoved { x, .. })
+                if self.state.is_dragging =>
+            {
+                let text_bounds = layout.children().next().unwrap().bounds();
 
-                    if target > 0.0 {
-                        let value = if self.is_secure {
-                            self.value.secure()
-                        } else {
-                            self.value.clone()
-                        };
-
-                        let position = renderer.find_cursor_position(
-                            text_layout.bounds(),
-                            self.font,
-                            self.size,
-                            &value,
-                            &self.state,
-                            target,
-                        );
+                self.select_to(renderer, text_bounds, x);
+            }
+            Event::Window(window::Event::RedrawRequested)
+                if self.state.is_dragging =>
+            {
+                let text_bounds = layout.children().next().unwrap().bounds();
 
-                        self.state.cursor.select_range(
-                            self.state.cursor.start(&value),
-                            position,
-                        );
-                    }
+                // Keep scrolling while the mouse is held past an edge
+                if cursor_position.x < text_bounds.x
+                    || cursor_position.x > text_bounds.x + text_bounds.width
+                {
+                    self.select_to(renderer, text_bounds, cursor_position.x);
                 }
             }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.keyboard_modifiers = modifiers;
+            }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
                 if self.state.is_focused
                     && self.state.is_pasting.is_none()
-                    && !c.is_control() =>
+                    && self.state.preedit.is_none()
+                    && !c.is_control()
+                    && self.accepts(c)
+                    && self.remaining_length() > 0 =>
             {
-                let mut editor =
-                    Editor::new(&mut self.value, &mut self.state.cursor);
+                let mut editor = Editor::new(
+                    &mut self.value,
+                    &mut self.state.cursor,
+                    &mut self.state.history,
+                );
 
                 editor.insert(c);
 
-                let message = (self.on_change)(editor.contents());
-                messages.push(message);
+                self.change(messages);
+            }
+            Event::Keyboard(keyboard::Event::ImePreedit(preedit))
+                if self.state.is_focused =>
+            {
+                // Secure inputs never show the composition in clear text
+                self.state.preedit = if preedit.is_empty() || self.is_secure {
+                    None
+                } else {
+                    Some(preedit)
+                };
+            }
+            Event::Keyboard(keyboard::Event::ImeCommit(text))
+                if self.state.is_focused =>
+            {
+                self.state.preedit = None;
+
+                let content = self.sanitize(&text);
+                let remaining = self.remaining_length();
+
+                if content.len() > 0 && remaining > 0 {
+                    let mut editor = Editor::new(
+                        &mut self.value,
+                        &mut self.state.cursor,
+                        &mut self.state.history,
+                    );
+
+                    editor.paste(content.until(remaining));
+
+                    self.change(messages);
+                }
             }
             Event::Keyboard(keyboard::Event::KeyPressed {
                 key_code,
@@ -346,13 +640,15 @@ where
                         }
                     }
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
+                    let mut editor = Editor::new(
+                        &mut self.value,
+                        &mut self.state.cursor,
+                        &mut self.state.history,
+                    );
 
                     editor.backspace();
 
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
+                    self.change(messages);
                 }
                 keyboard::KeyCode::Delete => {
                     if platform::is_jump_modifier_pressed(modifiers)
@@ -370,13 +666,15 @@ where
                         }
                     }
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
+                    let mut editor = Editor::new(
+                        &mut self.value,
+                        &mut self.state.cursor,
+                        &mut self.state.history,
+                    );
 
                     editor.delete();
 
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
+                    self.change(messages);
                 }
                 keyboard::KeyCode::Left => {
                     if platform::is_jump_modifier_pressed(modifiers)
@@ -435,27 +733,22 @@ where
                         if let Some(clipboard) = clipboard {
                             let content = match self.state.is_pasting.take() {
                                 Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .content()
-                                        .unwrap_or(String::new())
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
-                                }
+                                None => self.sanitize(
+                                    &clipboard.content().unwrap_or_default(),
+                                ),
                             };
 
+                            let remaining = self.remaining_length();
+
                             let mut editor = Editor::new(
                                 &mut self.value,
                                 &mut self.state.cursor,
+                                &mut self.state.history,
                             );
 
-                            editor.paste(content.clone());
+                            editor.paste(content.until(remaining));
 
-                            let message = (self.on_change)(editor.contents());
-                            messages.push(message);
+                            self.change(messages);
 
                             self.state.is_pasting = Some(content);
                         }
@@ -463,6 +756,70 @@ where
                         self.state.is_pasting = None;
                     }
                 }
+                keyboard::KeyCode::C
+                    if platform::is_copy_paste_modifier_pressed(modifiers)
+                        && !self.is_secure =>
+                {
+                    if let (Some(clipboard), Some((start, end))) =
+                        (clipboard, self.state.cursor.selection(&self.value))
+                    {
+                        let _ = clipboard
+                            .write(self.value.select(start, end).to_string());
+                    }
+                }
+                keyboard::KeyCode::X
+                    if platform::is_copy_paste_modifier_pressed(modifiers)
+                        && !self.is_secure =>
+                {
+                    if let (Some(clipboard), Some((start, end))) =
+                        (clipboard, self.state.cursor.selection(&self.value))
+                    {
+                        // Keep the selection if it could not be copied
+                        if clipboard
+                            .write(self.value.select(start, end).to_string())
+                        {
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut self.state.cursor,
+                                &mut self.state.history,
+                            );
+
+                            editor.delete();
+
+                            self.change(messages);
+                        }
+                    }
+                }
+                keyboard::KeyCode::Z
+                    if platform::is_copy_paste_modifier_pressed(modifiers) =>
+                {
+                    let snapshot = if modifiers.shift {
+                        self.state.history.redo(&self.value, self.state.cursor)
+                    } else {
+                        self.state.history.undo(&self.value, self.state.cursor)
+                    };
+
+                    if let Some((value, cursor)) = snapshot {
+                        self.value = value;
+                        self.state.cursor = cursor;
+
+                        let message = (self.on_change)(self.value.to_string());
+                        messages.push(message);
+                    }
+                }
+                keyboard::KeyCode::Y
+                    if platform::is_copy_paste_modifier_pressed(modifiers) =>
+                {
+                    if let Some((value, cursor)) =
+                        self.state.history.redo(&self.value, self.state.cursor)
+                    {
+                        self.value = value;
+                        self.state.cursor = cursor;
+
+                        let message = (self.on_change)(self.value.to_string());
+                        messages.push(message);
+                    }
+                }
                 keyboard::KeyCode::A => {
                     if platform::is_copy_paste_modifier_pressed(modifiers) {
                         self.state.cursor.select_all(&self.value);
@@ -472,6 +829,8 @@ where
                     self.state.is_focused = false;
                     self.state.is_dragging = false;
                     self.state.is_pasting = None;
+                    self.state.offset = 0.0;
+                    self.state.preedit = None;
                 }
                 _ => {}
             },
@@ -485,6 +844,14 @@ where
             },
             _ => {}
         }
+
+        if self.state.is_focused
+            && self.state.cursor.state(&self.value) != previous_cursor
+        {
+            let text_bounds = layout.children().next().unwrap().bounds();
+
+            self.scroll_to_cursor(renderer, text_bounds);
+        }
     }
 
     fn draw(
@@ -497,6 +864,13 @@ where
         let bounds = layout.bounds();
         let text_bounds = layout.children().next().unwrap().bounds();
 
+        if self.state.is_dragging
+            && (cursor_position.x < text_bounds.x
+                || cursor_position.x > text_bounds.x + text_bounds.width)
+        {
+            renderer.set_redraw_requested(true);
+        }
+
         if self.is_secure {
             self::Renderer::draw(
                 renderer,
@@ -648,8 +1022,12 @@ pub struct State {
     is_dragging: bool,
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
+    selected_word: Option<(usize, usize)>,
+    keyboard_modifiers: keyboard::ModifiersState,
     cursor: Cursor,
-    // TODO: Add stateful horizontal scrolling offset
+    history: History,
+    offset: f32,
+    preedit: Option<String>,
 }
 
 impl State {
@@ -669,7 +1047,12 @@ impl State {
             is_dragging: false,
             is_pasting: None,
             last_click: None,
+            selected_word: None,
+            keyboard_modifiers: keyboard::ModifiersState::default(),
             cursor: Cursor::default(),
+            history: History::default(),
+            offset: 0.0,
+            preedit: None,
         }
     }
 
@@ -688,6 +1071,21 @@ impl State {
         self.cursor
     }
 
+    /// Returns the horizontal scrolling offset of the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Returns the text being composed by an input method editor (IME) in
+    /// the [`TextInput`], if any.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
     ///
     /// [`Cursor`]: struct.Cursor.html
@@ -711,6 +1109,28 @@ impl State {
     pub fn move_cursor_to(&mut self, position: usize) {
         self.cursor.move_to(position);
     }
+
+    /// Selects all the content of the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn select_all(&mut self) {
+        self.cursor.select_range(0, usize::MAX);
+    }
+}
+
+/// Computes the selection of a drag that started with a double click on the
+/// given word, extending it by whole words up to `position` while keeping the
+/// word itself selected.
+fn select_words(
+    value: &Value,
+    (start, end): (usize, usize),
+    position: usize,
+) -> (usize, usize) {
+    if position < start {
+        (end, value.previous_start_of_word(position))
+    } else {
+        (start, value.next_end_of_word(position))
+    }
 }
 
 // TODO: Reduce allocations
@@ -792,3 +1212,212 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Limits, renderer::Null};
+
+    // The `Null` renderer measures every value as empty, so a click left of
+    // the text lands at its start and any click inside at its end.
+    const START: Point = Point::new(5.0, 15.0);
+    const END: Point = Point::new(150.0, 15.0);
+
+    fn text_input<'a>(
+        state: &'a mut State,
+        value: &str,
+    ) -> TextInput<'a, String, Null> {
+        TextInput::new(state, "", value, |value| value).padding(10)
+    }
+
+    fn send(state: &mut State, value: &str, events: Vec<Event>) -> Value {
+        send_to(text_input(state, value), events)
+    }
+
+    fn send_to(
+        mut text_input: TextInput<'_, String, Null>,
+        events: Vec<Event>,
+    ) -> Value {
+        let node = Widget::<String, Null>::layout(
+            &text_input,
+            &Null,
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut cursor_position = START;
+
+        for event in events {
+            if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event {
+                cursor_position = Point::new(x, y);
+            }
+
+            text_input.on_event(
+                event,
+                Layout::new(&node),
+                cursor_position,
+                &mut Vec::new(),
+                &Null,
+                None,
+            );
+        }
+
+        text_input.value
+    }
+
+    fn click(position: Point) -> Vec<Event> {
+        vec![
+            Event::Mouse(mouse::Event::CursorMoved {
+                x: position.x,
+                y: position.y,
+            }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]
+    }
+
+    fn key(
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+    ) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        })
+    }
+
+    fn shift() -> keyboard::ModifiersState {
+        keyboard::ModifiersState {
+            shift: true,
+            ..keyboard::ModifiersState::default()
+        }
+    }
+
+    #[test]
+    fn drag_after_double_click_selects_whole_words() {
+        let value = Value::new("one two three");
+
+        assert_eq!(select_words(&value, (4, 7), 5), (4, 7));
+        assert_eq!(select_words(&value, (4, 7), 10), (4, 13));
+        assert_eq!(select_words(&value, (4, 7), 1), (7, 0));
+    }
+
+    #[test]
+    fn shift_keys_extend_the_selection() {
+        let mut state = State::focused();
+
+        let value = send(
+            &mut state,
+            "one two",
+            vec![
+                key(keyboard::KeyCode::End, Default::default()),
+                key(keyboard::KeyCode::Left, shift()),
+                key(keyboard::KeyCode::Left, shift()),
+            ],
+        );
+
+        assert_eq!(state.cursor.selection(&value), Some((5, 7)));
+
+        let value = send(
+            &mut state,
+            "one two",
+            vec![key(keyboard::KeyCode::Home, shift())],
+        );
+
+        assert_eq!(state.cursor.selection(&value), Some((0, 7)));
+    }
+
+    #[test]
+    fn shift_click_extends_the_selection() {
+        let mut state = State::focused();
+
+        let mut events = vec![
+            key(keyboard::KeyCode::Right, Default::default()),
+            key(keyboard::KeyCode::Right, Default::default()),
+            Event::Keyboard(keyboard::Event::ModifiersChanged(shift())),
+        ];
+        events.extend(click(END));
+
+        let value = send(&mut state, "one two", events);
+
+        assert_eq!(state.cursor.selection(&value), Some((2, 7)));
+    }
+
+    #[test]
+    fn double_click_selects_a_word_and_triple_click_everything() {
+        let mut state = State::new();
+
+        let value =
+            send(&mut state, "one two", [click(START), click(START)].concat());
+
+        assert_eq!(state.cursor.selection(&value), Some((0, 3)));
+
+        let value = send(&mut state, "one two", click(START));
+
+        assert_eq!(state.cursor.selection(&value), Some((0, 7)));
+    }
+
+    #[test]
+    fn dragging_a_double_clicked_word_extends_it_by_words() {
+        let mut state = State::new();
+
+        let mut events = [click(START), click(START)].concat();
+        let _ = events.pop();
+        events.push(Event::Mouse(mouse::Event::CursorMoved {
+            x: END.x,
+            y: END.y,
+        }));
+
+        let value = send(&mut state, "one two", events);
+
+        assert_eq!(state.cursor.selection(&value), Some((0, 7)));
+    }
+
+    #[test]
+    fn ime_preedit_is_shown_until_committed() {
+        let mut state = State::focused();
+
+        let value = send(
+            &mut state,
+            "a",
+            vec![
+                key(keyboard::KeyCode::End, Default::default()),
+                Event::Keyboard(keyboard::Event::ImePreedit("に".into())),
+                Event::Keyboard(keyboard::Event::CharacterReceived('x')),
+            ],
+        );
+
+        assert_eq!(state.preedit(), Some("に"));
+        assert_eq!(value.to_string(), "a");
+
+        let value = send(
+            &mut state,
+            "a",
+            vec![Event::Keyboard(keyboard::Event::ImeCommit("日本".into()))],
+        );
+
+        assert_eq!(state.preedit(), None);
+        assert_eq!(value.to_string(), "a日本");
+    }
+
+    #[test]
+    fn secure_inputs_hide_the_ime_preedit() {
+        let mut state = State::focused();
+
+        let value = send_to(
+            text_input(&mut state, "").password(),
+            vec![
+                Event::Keyboard(keyboard::Event::ImePreedit("に".into())),
+                Event::Keyboard(keyboard::Event::ImeCommit("日".into())),
+            ],
+        );
+
+        assert_eq!(value.to_string(), "日");
+
+        let _ = send_to(
+            text_input(&mut state, "").password(),
+            vec![Event::Keyboard(keyboard::Event::ImePreedit("に".into()))],
+        );
+
+        assert_eq!(state.preedit(), None);
+    }
+}
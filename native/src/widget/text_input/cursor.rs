@@ -2,7 +2,7 @@
 use crate::widget::text_input::Value;
 
 /// The cursor of a text input.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Cursor {
     state: State,
 }
@@ -10,7 +10,7 @@ pub struct Cursor {
 /// The state of a [`Cursor`].
 ///
 /// [`Cursor`]: struct.Cursor.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum State {
     /// Cursor without a selection
     Index(usize),
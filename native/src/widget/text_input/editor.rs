@@ -1,20 +1,28 @@
-use crate::text_input::{Cursor, Value};
+use crate::text_input::{Cursor, History, Value};
 
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    history: &'a mut History,
 }
 
 impl<'a> Editor<'a> {
-    pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
-    }
-
-    pub fn contents(&self) -> String {
-        self.value.to_string()
+    pub fn new(
+        value: &'a mut Value,
+        cursor: &'a mut Cursor,
+        history: &'a mut History,
+    ) -> Editor<'a> {
+        Editor {
+            value,
+            cursor,
+            history,
+        }
     }
 
     pub fn insert(&mut self, character: char) {
+        self.history
+            .record_typing(self.value, *self.cursor, character);
+
         match self.cursor.selection(self.value) {
             Some((left, right)) => {
                 self.cursor.move_left(self.value);
@@ -25,11 +33,15 @@ impl<'a> Editor<'a> {
 
         self.value.insert(self.cursor.end(self.value), character);
         self.cursor.move_right(self.value);
+
+        self.history.typed(self.cursor.end(self.value));
     }
 
     pub fn paste(&mut self, content: Value) {
         let length = content.len();
 
+        self.history.record(self.value, *self.cursor);
+
         match self.cursor.selection(self.value) {
             Some((left, right)) => {
                 self.cursor.move_left(self.value);
@@ -46,6 +58,8 @@ impl<'a> Editor<'a> {
     pub fn backspace(&mut self) {
         match self.cursor.selection(self.value) {
             Some((start, end)) => {
+                self.history.record(self.value, *self.cursor);
+
                 self.cursor.move_left(self.value);
                 self.value.remove_many(start, end);
             }
@@ -53,6 +67,8 @@ impl<'a> Editor<'a> {
                 let start = self.cursor.start(self.value);
 
                 if start > 0 {
+                    self.history.record(self.value, *self.cursor);
+
                     self.cursor.move_left(self.value);
                     self.value.remove(start - 1);
                 }
@@ -69,6 +85,8 @@ impl<'a> Editor<'a> {
                 let end = self.cursor.end(self.value);
 
                 if end < self.value.len() {
+                    self.history.record(self.value, *self.cursor);
+
                     self.value.remove(end);
                 }
             }
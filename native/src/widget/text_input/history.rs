@@ -0,0 +1,186 @@
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The maximum amount of steps that can be undone.
+const MAX_UNDO_STEPS: usize = 100;
+
+/// The edit history of a text input.
+///
+/// Consecutive characters typed in a row are coalesced into a single step.
+/// Only the last `MAX_UNDO_STEPS` steps are kept.
+#[derive(Debug, Default, Clone)]
+pub struct History {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    typing: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Records the current value and cursor as a new undo step.
+    pub fn record(&mut self, value: &Value, cursor: Cursor) {
+        self.push_undo(value, cursor);
+        self.redo.clear();
+        self.typing = None;
+    }
+
+    /// Records the current value and cursor before typing the given
+    /// character, unless it continues the last typed word.
+    pub fn record_typing(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+        character: char,
+    ) {
+        let is_continuation = match (self.typing, cursor.selection(value)) {
+            (Some(index), None) => {
+                index == cursor.end(value) && !character.is_whitespace()
+            }
+            _ => false,
+        };
+
+        if !is_continuation {
+            self.record(value, cursor);
+        }
+    }
+
+    /// Marks the given index as the position where typing can continue
+    /// without creating a new undo step.
+    pub fn typed(&mut self, index: usize) {
+        self.typing = Some(index);
+    }
+
+    /// Reverts the last undo step, returning the previous value and cursor.
+    pub fn undo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        let snapshot = self.undo.pop_back()?;
+
+        self.redo.push(Snapshot {
+            value: value.clone(),
+            cursor,
+        });
+        self.typing = None;
+
+        Some((snapshot.value, snapshot.cursor))
+    }
+
+    /// Reapplies the last undone step, returning the next value and cursor.
+    pub fn redo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        let snapshot = self.redo.pop()?;
+
+        self.push_undo(value, cursor);
+        self.typing = None;
+
+        Some((snapshot.value, snapshot.cursor))
+    }
+
+    fn push_undo(&mut self, value: &Value, cursor: Cursor) {
+        if self.undo.len() == MAX_UNDO_STEPS {
+            let _ = self.undo.pop_front();
+        }
+
+        self.undo.push_back(Snapshot {
+            value: value.clone(),
+            cursor,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text_input::Editor;
+
+    fn type_str(editor: &mut Editor<'_>, text: &str) {
+        for c in text.chars() {
+            editor.insert(c);
+        }
+    }
+
+    #[test]
+    fn typing_is_coalesced_by_word() {
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let mut history = History::default();
+
+        let mut editor = Editor::new(&mut value, &mut cursor, &mut history);
+        type_str(&mut editor, "hello world");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "hello");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "");
+        assert!(history.undo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn redo_reapplies_undone_steps_until_a_new_edit() {
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let mut history = History::default();
+
+        let mut editor = Editor::new(&mut value, &mut cursor, &mut history);
+        type_str(&mut editor, "one two");
+
+        let (mut value, mut cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "one");
+
+        let (redone, redone_cursor) = history.redo(&value, cursor).unwrap();
+        assert_eq!(redone.to_string(), "one two");
+        let mut end = Cursor::default();
+        end.move_to(7);
+        assert_eq!(redone_cursor, end);
+
+        let (undone, undone_cursor) =
+            history.undo(&redone, redone_cursor).unwrap();
+        value = undone;
+        cursor = undone_cursor;
+
+        let mut editor = Editor::new(&mut value, &mut cursor, &mut history);
+        editor.insert('!');
+
+        assert_eq!(value.to_string(), "one!");
+        assert!(history.redo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn undo_steps_are_capped() {
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let mut history = History::default();
+
+        for _ in 0..MAX_UNDO_STEPS + 10 {
+            history.record(&value, cursor);
+
+            value.insert(value.len(), 'a');
+            cursor.move_to(value.len());
+        }
+
+        let mut steps = 0;
+
+        while let Some((previous, previous_cursor)) =
+            history.undo(&value, cursor)
+        {
+            value = previous;
+            cursor = previous_cursor;
+            steps += 1;
+        }
+
+        assert_eq!(steps, MAX_UNDO_STEPS);
+        assert_eq!(value.len(), 10);
+    }
+}
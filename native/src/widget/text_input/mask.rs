@@ -0,0 +1,206 @@
+//! Constrain the value of a text input to a fixed pattern.
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A fixed pattern that the value of a text input must follow.
+///
+/// A pattern is made of placeholders and literals. The following characters
+/// are placeholders:
+/// - `#` accepts any ASCII digit
+/// - `@` accepts any alphabetic character
+/// - `*` accepts any alphanumeric character
+///
+/// Any other character is a literal and is inserted automatically while
+/// typing. For instance, `####-##-##` accepts dates like `2020-07-15`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Alphabetic,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Alphabetic => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Literal(_) => false,
+        }
+    }
+
+    fn accepts_grapheme(self, grapheme: &str) -> bool {
+        let mut chars = grapheme.chars();
+
+        match (chars.next(), self) {
+            (Some(c), Slot::Digit) => self.accepts(c) && chars.next().is_none(),
+            (Some(c), _) => self.accepts(c),
+            (None, _) => false,
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn new(pattern: &str) -> Self {
+        let slots = pattern
+            .chars()
+            .map(|c| match c {
+                '#' => Slot::Digit,
+                '@' => Slot::Alphabetic,
+                '*' => Slot::Alphanumeric,
+                _ => Slot::Literal(c),
+            })
+            .collect();
+
+        Self { slots }
+    }
+
+    /// Returns the maximum amount of characters of a masked value.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns true if the [`Mask`] has no slots.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns true if the given character can be typed in the [`Mask`],
+    /// either filling a placeholder or matching a literal.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn accepts(&self, c: char) -> bool {
+        self.slots.iter().any(|slot| match slot {
+            Slot::Literal(literal) => *literal == c,
+            _ => slot.accepts(c),
+        })
+    }
+
+    /// Fits the given value into the [`Mask`].
+    ///
+    /// Graphemes that do not fit the next placeholder are dropped, and
+    /// literals are inserted where needed. The given `cursor` grapheme index
+    /// is mapped to the resulting value, which is returned along with it.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn apply(&self, value: &str, cursor: usize) -> (String, usize) {
+        let mut input = value.graphemes(true).enumerate().peekable();
+        let mut output = String::new();
+        let mut length = 0;
+        let mut committed = (0, 0);
+        let mut new_cursor = if cursor == 0 { Some(0) } else { None };
+
+        'slots: for slot in &self.slots {
+            match slot {
+                Slot::Literal(literal) => {
+                    if input.peek().is_none() {
+                        break;
+                    }
+
+                    output.push(*literal);
+                    length += 1;
+
+                    if let Some(&(index, grapheme)) = input.peek() {
+                        if grapheme.chars().eq(Some(*literal)) {
+                            let _ = input.next();
+
+                            if index + 1 == cursor {
+                                new_cursor = Some(length);
+                            }
+                        }
+                    }
+                }
+                _ => loop {
+                    match input.next() {
+                        Some((index, grapheme)) => {
+                            let is_accepted = slot.accepts_grapheme(grapheme);
+
+                            if is_accepted {
+                                output.push_str(grapheme);
+                                length += 1;
+                                committed = (output.len(), length);
+                            }
+
+                            if index + 1 == cursor {
+                                new_cursor = Some(length);
+                            }
+
+                            if is_accepted {
+                                break;
+                            }
+                        }
+                        None => break 'slots,
+                    }
+                },
+            }
+        }
+
+        // Literals are only kept when followed by some input
+        let (committed, length) = committed;
+        output.truncate(committed);
+
+        let cursor = new_cursor.unwrap_or(length).min(length);
+
+        (output, cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mask;
+
+    #[test]
+    fn inserts_literals() {
+        let mask = Mask::new("####-##-##");
+
+        assert_eq!(mask.apply("20200715", 8), (String::from("2020-07-15"), 10));
+        assert_eq!(mask.apply("2020", 4), (String::from("2020"), 4));
+        assert_eq!(mask.apply("20200", 5), (String::from("2020-0"), 6));
+    }
+
+    #[test]
+    fn drops_invalid_characters() {
+        let mask = Mask::new("####-##-##");
+
+        assert_eq!(mask.apply("20a20", 3), (String::from("2020"), 2));
+        assert_eq!(
+            mask.apply("2020-07-159", 11),
+            (String::from("2020-07-15"), 10)
+        );
+    }
+
+    #[test]
+    fn keeps_typed_literals() {
+        let mask = Mask::new("(###) ###");
+
+        assert_eq!(mask.apply("1234", 4), (String::from("(123) 4"), 7));
+        assert_eq!(mask.apply("(123) 4", 7), (String::from("(123) 4"), 7));
+    }
+
+    #[test]
+    fn maps_the_cursor_by_graphemes() {
+        let mask = Mask::new("@@-##");
+
+        let decomposed = "e\u{301}a12";
+
+        assert_eq!(
+            mask.apply(decomposed, 2),
+            (String::from("e\u{301}a-12"), 2)
+        );
+        assert_eq!(
+            mask.apply(decomposed, 3),
+            (String::from("e\u{301}a-12"), 4)
+        );
+        assert_eq!(mask.apply("ab1\u{20e3}2", 3), (String::from("ab-2"), 3));
+    }
+}
@@ -84,6 +84,17 @@ impl Value {
         Self { graphemes }
     }
 
+    /// Returns a new [`Value`] containing the graphemes from `start` until the
+    /// given `end`.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn select(&self, start: usize, end: usize) -> Self {
+        let graphemes =
+            self.graphemes[start.min(self.len())..end.min(self.len())].to_vec();
+
+        Self { graphemes }
+    }
+
     /// Converts the [`Value`] into a `String`.
     ///
     /// [`Value`]: struct.Value.html
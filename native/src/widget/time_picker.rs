@@ -0,0 +1,754 @@
+//! Pick a time of the day.
+use crate::{
+    date_picker::{self, cell_bounds, field_layout, popup_position, Cell},
+    keyboard, layout, mouse, overlay, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Widget,
+};
+use std::fmt;
+
+/// A field that opens a grid of hours and minutes to pick a [`Time`].
+///
+/// Picking an hour keeps the grid open, and picking a minute closes it. With
+/// the grid open, the `Up` and `Down` keys change the focused hour, `Left` and
+/// `Right` change the focused minute, and `Enter` picks the focused time.
+///
+/// A [`TimePicker`] shares the style of a [`DatePicker`].
+///
+/// [`Time`]: struct.Time.html
+/// [`TimePicker`]: struct.TimePicker.html
+/// [`DatePicker`]: ../date_picker/struct.DatePicker.html
+///
+/// # Example
+/// ```
+/// # use iced_native::{time_picker, renderer::Null};
+/// #
+/// # pub type TimePicker<'a, Message> =
+/// #     iced_native::TimePicker<'a, Message, Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     TimePicked(time_picker::Time),
+/// }
+///
+/// let mut state = time_picker::State::new();
+/// let value = time_picker::Time::new(9, 30);
+///
+/// let time_picker = TimePicker::new(&mut state, value, Message::TimePicked)
+///     .step(15);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    value: Option<Time>,
+    on_change: Box<dyn Fn(Time) -> Message>,
+    step: u8,
+    placeholder: Option<String>,
+    width: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as date_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> TimePicker<'a, Message, Renderer> {
+    /// Creates a new [`TimePicker`] with the given [`State`], the current
+    /// [`Time`], if any, and the message to produce when a [`Time`] is picked.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    /// [`State`]: struct.State.html
+    /// [`Time`]: struct.Time.html
+    pub fn new<F>(
+        state: &'a mut State,
+        value: Option<Time>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(Time) -> Message,
+    {
+        TimePicker {
+            state,
+            value,
+            on_change: Box::new(on_change),
+            step: 5,
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minutes between the options of the [`TimePicker`].
+    ///
+    /// By default, minutes can be picked in steps of 5.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn step(mut self, minutes: u8) -> Self {
+        self.step = minutes.clamp(1, 60);
+        self
+    }
+
+    /// Sets the text shown by the [`TimePicker`] when no time is picked.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as date_picker::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`TimePicker`].
+///
+/// [`TimePicker`]: struct.TimePicker.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+    focused: Time,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a closed [`TimePicker`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the grid of the [`TimePicker`] is open.
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+/// A time of the day, with a precision of minutes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// Creates a new [`Time`], if the given hour and minute are valid.
+    ///
+    /// Hours go from 0 to 23.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        if hour < 24 && minute < 60 {
+            Some(Time { hour, minute })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`], from 0 to 59.
+    ///
+    /// [`Time`]: struct.Time.html
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TimePicker<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + 'a,
+    Message: 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        field_layout(
+            renderer,
+            limits,
+            "00:00",
+            self.width,
+            self.padding,
+            self.text_size,
+            self.font,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if self.state.is_open {
+                    // TODO: Encode cursor availability in the type system
+                    self.state.is_open =
+                        cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                } else if layout.bounds().contains(cursor_position) {
+                    let focused = self.value.unwrap_or_default();
+
+                    // Minutes outside of the steps cannot be focused
+                    self.state.is_open = true;
+                    self.state.focused = Time {
+                        minute: focused.minute - focused.minute % self.step,
+                        ..focused
+                    };
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.is_open = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        date_picker::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            self.value.map(|value| value.to_string()),
+            self.placeholder.as_deref(),
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        let State { is_open, focused } = &mut self.state;
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(Clock {
+                is_open,
+                focused,
+                value: self.value,
+                on_change: &self.on_change,
+                step: self.step,
+                target_height: layout.bounds().height,
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+}
+
+/// The columns of the grid of hours.
+const HOUR_COLUMNS: usize = 4;
+
+struct Clock<'a, Message, Renderer: self::Renderer> {
+    is_open: &'a mut bool,
+    focused: &'a mut Time,
+    value: Option<Time>,
+    on_change: &'a dyn Fn(Time) -> Message,
+    step: u8,
+    target_height: f32,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: &'a <Renderer as date_picker::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> Clock<'a, Message, Renderer> {
+    fn minutes(&self) -> Vec<u8> {
+        (0..60).step_by(usize::from(self.step)).collect()
+    }
+
+    fn minute_columns(&self) -> usize {
+        match self.minutes().len() {
+            count if count > 24 => 6,
+            count => count.min(4),
+        }
+    }
+
+    fn cell_size(&self, renderer: &Renderer) -> f32 {
+        f32::from(self.text_size.unwrap_or(renderer.default_size()) * 2)
+    }
+
+    /// Returns the top left corners of the grids of hours and minutes.
+    fn origins(&self, bounds: Rectangle, cell: f32) -> (Point, Point) {
+        let padding = f32::from(self.padding);
+        let hours = Point::new(bounds.x + padding, bounds.y + padding);
+
+        (
+            hours,
+            Point::new(hours.x + cell * HOUR_COLUMNS as f32 + padding, hours.y),
+        )
+    }
+
+    /// Returns the index of the cell of a grid under the cursor, if any.
+    fn cell_at(
+        origin: Point,
+        cell: f32,
+        columns: usize,
+        count: usize,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        let relative = cursor_position - origin;
+
+        if relative.x < 0.0 || relative.y < 0.0 {
+            return None;
+        }
+
+        let row = (relative.y / cell) as usize;
+        let column = (relative.x / cell) as usize;
+        let index = row * columns + column;
+
+        if column < columns && index < count {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    fn pick(&mut self, time: Time, close: bool, messages: &mut Vec<Message>) {
+        messages.push((self.on_change)(time));
+
+        *self.focused = time;
+        *self.is_open = !close;
+    }
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Clock<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let cell = self.cell_size(renderer);
+        let padding = f32::from(self.padding);
+
+        let columns = self.minute_columns();
+        let rows = self.minutes().len().div_ceil(columns);
+
+        let size = Size::new(
+            (HOUR_COLUMNS + columns) as f32 * cell + padding * 3.0,
+            (24 / HOUR_COLUMNS).max(rows) as f32 * cell + padding * 2.0,
+        );
+
+        let mut node = layout::Node::new(size);
+        node.move_to(popup_position(
+            bounds,
+            position,
+            self.target_height,
+            size,
+        ));
+
+        node
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.target_height.to_bits().hash(state);
+        self.step.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        let focused = *self.focused;
+        let minutes = self.minutes();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let cell = self.cell_size(renderer);
+                let (hours_origin, minutes_origin) =
+                    self.origins(layout.bounds(), cell);
+
+                if let Some(hour) = Self::cell_at(
+                    hours_origin,
+                    cell,
+                    HOUR_COLUMNS,
+                    24,
+                    cursor_position,
+                ) {
+                    let time = Time {
+                        hour: hour as u8,
+                        ..focused
+                    };
+
+                    self.pick(time, false, messages);
+                } else if let Some(index) = Self::cell_at(
+                    minutes_origin,
+                    cell,
+                    self.minute_columns(),
+                    minutes.len(),
+                    cursor_position,
+                ) {
+                    let time = Time {
+                        minute: minutes[index],
+                        ..focused
+                    };
+
+                    self.pick(time, true, messages);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let minute = focused.minute / self.step;
+                let steps = minutes.len() as u8;
+
+                match key_code {
+                    keyboard::KeyCode::Up => {
+                        self.focused.hour = (focused.hour + 23) % 24;
+                    }
+                    keyboard::KeyCode::Down => {
+                        self.focused.hour = (focused.hour + 1) % 24;
+                    }
+                    keyboard::KeyCode::Left => {
+                        self.focused.minute =
+                            (minute + steps - 1) % steps * self.step;
+                    }
+                    keyboard::KeyCode::Right => {
+                        self.focused.minute = (minute + 1) % steps * self.step;
+                    }
+                    keyboard::KeyCode::Enter => {
+                        self.pick(focused, true, messages);
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let cell = self.cell_size(renderer);
+        let (hours_origin, minutes_origin) = self.origins(bounds, cell);
+        let focused = *self.focused;
+
+        let hours: Vec<Cell> = (0..24)
+            .map(|hour| Cell {
+                label: format!("{:02}", hour),
+                bounds: cell_bounds(
+                    hours_origin,
+                    cell,
+                    usize::from(hour) / HOUR_COLUMNS,
+                    usize::from(hour) % HOUR_COLUMNS,
+                ),
+                is_selected: self.value.map(Time::hour) == Some(hour),
+                is_focused: focused.hour == hour,
+                is_disabled: false,
+                is_secondary: false,
+            })
+            .collect();
+
+        let columns = self.minute_columns();
+
+        let minutes: Vec<Cell> = self
+            .minutes()
+            .into_iter()
+            .enumerate()
+            .map(|(index, minute)| Cell {
+                label: format!(":{:02}", minute),
+                bounds: cell_bounds(
+                    minutes_origin,
+                    cell,
+                    index / columns,
+                    index % columns,
+                ),
+                is_selected: self.value.map(Time::minute) == Some(minute),
+                is_focused: focused.minute == minute,
+                is_disabled: false,
+                is_secondary: false,
+            })
+            .collect();
+
+        renderer.draw_clock(
+            bounds,
+            cursor_position,
+            &hours,
+            &minutes,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            self.style,
+        )
+    }
+}
+
+/// The renderer of a [`TimePicker`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TimePicker`] in your user interface.
+///
+/// [`TimePicker`]: struct.TimePicker.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: date_picker::Renderer {
+    /// Draws the grid of hours and minutes of a [`TimePicker`].
+    ///
+    /// [`TimePicker`]: struct.TimePicker.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw_clock(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        hours: &[Cell],
+        minutes: &[Cell],
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as date_picker::Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TimePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        time_picker: TimePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(time_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Font, Overlay};
+
+    fn key(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: Default::default(),
+        })
+    }
+
+    fn click(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(x, y),
+        )
+    }
+
+    /// Sends the given events to an open clock with a padding of 5 and cells
+    /// of 40, returning the picked times.
+    fn send(
+        state: &mut State,
+        step: u8,
+        events: impl IntoIterator<Item = (Event, Point)>,
+    ) -> Vec<Time> {
+        let State { is_open, focused } = state;
+        let mut messages = Vec::new();
+
+        let mut clock = Clock::<Time, Null> {
+            is_open,
+            focused,
+            value: None,
+            on_change: &|time| time,
+            step,
+            target_height: 0.0,
+            padding: 5,
+            text_size: None,
+            font: Font::Default,
+            style: &(),
+        };
+
+        let node = clock.layout(&Null::new(), Size::INFINITY, Point::ORIGIN);
+
+        for (event, cursor_position) in events {
+            clock.on_event(
+                event,
+                Layout::new(&node),
+                cursor_position,
+                &mut messages,
+                &Null::new(),
+                None,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn arrows_step_the_focused_hour_and_minute() {
+        let mut state = State {
+            is_open: true,
+            focused: Time::new(23, 45).unwrap(),
+        };
+
+        let mut press = |key_code| {
+            let picked =
+                send(&mut state, 15, vec![(key(key_code), Point::ORIGIN)]);
+
+            (picked, state.focused)
+        };
+
+        assert_eq!(press(keyboard::KeyCode::Down).1, Time::new(0, 45).unwrap());
+        assert_eq!(press(keyboard::KeyCode::Right).1, Time::new(0, 0).unwrap());
+        assert_eq!(press(keyboard::KeyCode::Left).1, Time::new(0, 45).unwrap());
+        assert_eq!(press(keyboard::KeyCode::Up).1, Time::new(23, 45).unwrap());
+        assert_eq!(
+            press(keyboard::KeyCode::Enter).0,
+            vec![Time::new(23, 45).unwrap()]
+        );
+        assert!(!state.is_open);
+    }
+
+    #[test]
+    fn cell_at_hits_the_cells_of_a_grid() {
+        let cell_at = |x, y| {
+            Clock::<(), Null>::cell_at(
+                Point::new(10.0, 10.0),
+                40.0,
+                4,
+                6,
+                Point::new(x, y),
+            )
+        };
+
+        assert_eq!(cell_at(10.0, 10.0), Some(0));
+        assert_eq!(cell_at(129.0, 15.0), Some(2));
+        assert_eq!(cell_at(55.0, 55.0), Some(5));
+        assert_eq!(cell_at(5.0, 15.0), None);
+        assert_eq!(cell_at(175.0, 15.0), None);
+        assert_eq!(cell_at(95.0, 55.0), None);
+    }
+
+    #[test]
+    fn picking_a_minute_closes_the_clock() {
+        let mut state = State {
+            is_open: true,
+            focused: Time::new(9, 15).unwrap(),
+        };
+
+        // Hours start at (5, 5) and minutes at (170, 5), in cells of 40
+        let picked = send(&mut state, 15, vec![click(105.0, 65.0)]);
+
+        assert_eq!(picked, vec![Time::new(6, 15).unwrap()]);
+        assert!(state.is_open);
+
+        let picked = send(&mut state, 15, vec![click(270.0, 25.0)]);
+
+        assert_eq!(picked, vec![Time::new(6, 30).unwrap()]);
+        assert!(!state.is_open);
+    }
+}
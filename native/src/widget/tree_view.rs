@@ -0,0 +1,769 @@
+//! Display hierarchical data with expandable nodes.
+//!
+//! A [`TreeView`] has some local [`State`].
+//!
+//! [`TreeView`]: struct.TreeView.html
+//! [`State`]: struct.State.html
+use crate::{
+    keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A widget displaying a hierarchy of nodes that can be expanded and
+/// collapsed.
+///
+/// Which nodes are expanded and selected is decided by your application,
+/// which is notified through messages. This allows the children of a node to
+/// be loaded lazily, the first time it is expanded.
+///
+/// Once focused, the selection can be moved with the arrow keys. The right
+/// and left arrows expand and collapse the selected node.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{tree_view, Text};
+/// # type TreeView<'a, Message> =
+/// #     iced_native::TreeView<'a, u32, Message, iced_native::renderer::Null>;
+/// # type Node<'a, Message> =
+/// #     tree_view::Node<'a, u32, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(u32, bool),
+///     Selected(u32),
+/// }
+///
+/// let mut state = tree_view::State::new();
+///
+/// let tree_view = TreeView::new(
+///     &mut state,
+///     vec![Node::new(0, Text::new("src"))
+///         .expanded(true)
+///         .push(Node::new(1, Text::new("main.rs")))
+///         .push(Node::new(2, Text::new("widget")).expandable(true))],
+/// )
+/// .selected(Some(1))
+/// .on_toggle(Message::Toggled)
+/// .on_select(Message::Selected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Id, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    nodes: Vec<Node<'a, Id, Message, Renderer>>,
+    selected: Option<Id>,
+    on_toggle: Option<Box<dyn Fn(Id, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    indent: u16,
+    style: Renderer::Style,
+}
+
+impl<'a, Id, Message, Renderer> TreeView<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`TreeView`] with the given [`State`] and root nodes.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State,
+        nodes: Vec<Node<'a, Id, Message, Renderer>>,
+    ) -> Self {
+        TreeView {
+            state,
+            nodes,
+            selected: None,
+            on_toggle: None,
+            on_select: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            indent: Renderer::DEFAULT_INDENT,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the identifier of the selected [`Node`], if any.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn selected(mut self, selected: Option<Id>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is expanded
+    /// or collapsed.
+    ///
+    /// It receives the identifier of the [`Node`] and whether it should be
+    /// expanded.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_toggle<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Id, bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is selected.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`], in pixels.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the visible nodes, in order, together with their depth.
+    fn visible(&self) -> Vec<(usize, &Node<'a, Id, Message, Renderer>)> {
+        fn collect<'a, 'b, Id, Message, Renderer>(
+            nodes: &'b [Node<'a, Id, Message, Renderer>],
+            depth: usize,
+            visible: &mut Vec<(usize, &'b Node<'a, Id, Message, Renderer>)>,
+        ) {
+            for node in nodes {
+                visible.push((depth, node));
+
+                if node.is_expanded {
+                    collect(&node.children, depth + 1, visible);
+                }
+            }
+        }
+
+        let mut visible = Vec::new();
+        collect(&self.nodes, 0, &mut visible);
+
+        visible
+    }
+
+    /// Returns the contents of the visible nodes, in order.
+    fn visible_contents_mut(
+        &mut self,
+    ) -> Vec<&mut Element<'a, Message, Renderer>> {
+        fn collect<'a, 'b, Id, Message, Renderer>(
+            nodes: &'b mut [Node<'a, Id, Message, Renderer>],
+            contents: &mut Vec<&'b mut Element<'a, Message, Renderer>>,
+        ) {
+            for node in nodes {
+                contents.push(&mut node.content);
+
+                if node.is_expanded {
+                    collect(&mut node.children, contents);
+                }
+            }
+        }
+
+        let mut contents = Vec::new();
+        collect(&mut self.nodes, &mut contents);
+
+        contents
+    }
+
+    fn toggle(&self, entry: &Entry<Id>, messages: &mut Vec<Message>) {
+        if let (Some(on_toggle), Some(is_expanded)) =
+            (&self.on_toggle, entry.is_expanded)
+        {
+            messages.push(on_toggle(entry.id.clone(), !is_expanded));
+        }
+    }
+
+    fn select(&self, entry: &Entry<Id>, messages: &mut Vec<Message>) {
+        if let Some(on_select) = &self.on_select {
+            messages.push(on_select(entry.id.clone()));
+        }
+    }
+}
+
+impl<'a, Id, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max_width = limits.max().width;
+        let indent = f32::from(self.indent);
+
+        let contents: Vec<_> = self
+            .visible()
+            .into_iter()
+            .map(|(depth, node)| {
+                let x = (depth + 1) as f32 * indent;
+
+                let mut content = node.content.layout(
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new((max_width - x).max(0.0), f32::INFINITY),
+                    ),
+                );
+
+                let height = content.size().height.max(indent);
+
+                content.move_to(Point::new(
+                    x,
+                    (height - content.size().height) / 2.0,
+                ));
+
+                (content, height)
+            })
+            .collect();
+
+        let intrinsic =
+            contents.iter().fold(Size::ZERO, |size, (content, height)| {
+                Size::new(
+                    size.width.max(content.bounds().x + content.size().width),
+                    size.height + height,
+                )
+            });
+
+        let size = limits.resolve(intrinsic);
+        let mut y = 0.0;
+
+        let rows = contents
+            .into_iter()
+            .map(|(content, height)| {
+                let mut row = layout::Node::with_children(
+                    Size::new(size.width, height),
+                    vec![content],
+                );
+                row.move_to(Point::new(0.0, y));
+
+                y += height;
+                row
+            })
+            .collect();
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let entries: Vec<_> = self
+            .visible()
+            .into_iter()
+            .map(|(depth, node)| Entry {
+                id: node.id.clone(),
+                depth,
+                is_expanded: node.toggle(),
+            })
+            .collect();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                self.state.is_focused =
+                    layout.bounds().contains(cursor_position);
+
+                let indent = f32::from(self.indent);
+
+                let clicked = entries.iter().zip(layout.children()).find(
+                    |(_, layout)| layout.bounds().contains(cursor_position),
+                );
+
+                if let Some((entry, layout)) = clicked {
+                    let toggle_x =
+                        layout.bounds().x + entry.depth as f32 * indent;
+
+                    if entry.is_expanded.is_some()
+                        && cursor_position.x >= toggle_x
+                        && cursor_position.x < toggle_x + indent
+                    {
+                        self.toggle(entry, messages);
+                    } else {
+                        self.select(entry, messages);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                let selected = self.selected.as_ref().and_then(|selected| {
+                    entries.iter().position(|entry| &entry.id == selected)
+                });
+
+                match navigate(&entries, selected, key_code) {
+                    Some(Navigation::Select(index)) => {
+                        self.select(&entries[index], messages);
+                    }
+                    Some(Navigation::Toggle(index)) => {
+                        self.toggle(&entries[index], messages);
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+
+        for (content, layout) in self
+            .visible_contents_mut()
+            .into_iter()
+            .zip(layout.children())
+        {
+            content.on_event(
+                event.clone(),
+                layout.children().next().unwrap(),
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut items = Vec::new();
+        let mut contents = Vec::new();
+
+        for ((depth, node), layout) in
+            self.visible().into_iter().zip(layout.children())
+        {
+            items.push(Item {
+                bounds: layout.bounds(),
+                depth,
+                is_expanded: node.toggle(),
+                is_selected: self.selected.as_ref() == Some(&node.id),
+            });
+
+            contents.push(node.content.draw(
+                renderer,
+                defaults,
+                layout.children().next().unwrap(),
+                cursor_position,
+            ));
+        }
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            &items,
+            contents,
+            self.indent,
+            self.state.is_focused,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.indent.hash(state);
+
+        for (depth, node) in self.visible() {
+            depth.hash(state);
+            node.content.hash_layout(state);
+        }
+    }
+}
+
+/// A node of a [`TreeView`].
+///
+/// [`TreeView`]: struct.TreeView.html
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, Id, Message, Renderer> {
+    id: Id,
+    content: Element<'a, Message, Renderer>,
+    children: Vec<Node<'a, Id, Message, Renderer>>,
+    is_expanded: bool,
+    is_expandable: bool,
+}
+
+impl<'a, Id, Message, Renderer> Node<'a, Id, Message, Renderer> {
+    /// Creates a new [`Node`] with the given identifier and content.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn new(
+        id: Id,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Node {
+            id,
+            content: content.into(),
+            children: Vec::new(),
+            is_expanded: false,
+            is_expandable: false,
+        }
+    }
+
+    /// Adds a child to the [`Node`].
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn push(mut self, child: Node<'a, Id, Message, Renderer>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Sets whether the children of the [`Node`] are visible.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded even if it has no children.
+    ///
+    /// This is useful to load the children lazily, once the [`Node`] is
+    /// expanded.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+
+    /// Returns whether the [`Node`] is expanded, or `None` if it cannot be.
+    fn toggle(&self) -> Option<bool> {
+        if self.is_expandable || !self.children.is_empty() {
+            Some(self.is_expanded)
+        } else {
+            None
+        }
+    }
+}
+
+/// A visible node, as seen when processing events.
+struct Entry<Id> {
+    id: Id,
+    depth: usize,
+    is_expanded: Option<bool>,
+}
+
+/// What a key press does to the visible nodes of a [`TreeView`].
+///
+/// [`TreeView`]: struct.TreeView.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Navigation {
+    Select(usize),
+    Toggle(usize),
+}
+
+/// Finds what a key press does, given the visible nodes and the index of the
+/// selected one.
+fn navigate<Id>(
+    entries: &[Entry<Id>],
+    selected: Option<usize>,
+    key_code: keyboard::KeyCode,
+) -> Option<Navigation> {
+    use keyboard::KeyCode;
+
+    match (key_code, selected) {
+        (KeyCode::Up, Some(index)) => {
+            index.checked_sub(1).map(Navigation::Select)
+        }
+        (KeyCode::Down, Some(index)) => Some(index + 1)
+            .filter(|next| *next < entries.len())
+            .map(Navigation::Select),
+        (KeyCode::Up, None) | (KeyCode::Down, None) | (KeyCode::Home, _) => {
+            Some(0)
+                .filter(|_| !entries.is_empty())
+                .map(Navigation::Select)
+        }
+        (KeyCode::End, _) => {
+            entries.len().checked_sub(1).map(Navigation::Select)
+        }
+        (KeyCode::Right, Some(index)) => {
+            let entry = &entries[index];
+
+            match entry.is_expanded {
+                Some(false) => Some(Navigation::Toggle(index)),
+                // Move to the first child, if it is loaded
+                Some(true) => Some(index + 1)
+                    .filter(|next| {
+                        matches!(
+                            entries.get(*next),
+                            Some(child) if child.depth > entry.depth
+                        )
+                    })
+                    .map(Navigation::Select),
+                None => None,
+            }
+        }
+        (KeyCode::Left, Some(index)) => {
+            let entry = &entries[index];
+
+            if entry.is_expanded == Some(true) {
+                Some(Navigation::Toggle(index))
+            } else {
+                // Move to the parent
+                entries[..index]
+                    .iter()
+                    .rposition(|parent| parent.depth < entry.depth)
+                    .map(Navigation::Select)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A visible node of a [`TreeView`], as drawn by a [`Renderer`].
+///
+/// [`TreeView`]: struct.TreeView.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Item {
+    /// The bounds of the row of the node.
+    pub bounds: Rectangle,
+
+    /// The depth of the node, starting at `0` for the roots.
+    pub depth: usize,
+
+    /// Whether the node is expanded, or `None` if it cannot be.
+    pub is_expanded: Option<bool>,
+
+    /// Whether the node is selected.
+    pub is_selected: bool,
+}
+
+/// The local state of a [`TreeView`].
+///
+/// [`TreeView`]: struct.TreeView.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], without focus.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`TreeView`] has keyboard focus.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+/// The renderer of a [`TreeView`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TreeView`] in your user interface.
+///
+/// [`TreeView`]: struct.TreeView.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default indentation of every level of a [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    const DEFAULT_INDENT: u16;
+
+    /// Draws a [`TreeView`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`TreeView`]
+    ///   * the cursor position
+    ///   * the [`Item`] of every visible node
+    ///   * the drawn content of every visible node
+    ///   * the indentation of every level
+    ///   * whether the [`TreeView`] has keyboard focus
+    ///   * the style of the [`TreeView`]
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    /// [`Item`]: struct.Item.html
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        items: &[Item],
+        contents: Vec<Self::Output>,
+        indent: u16,
+        is_focused: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Id, Message, Renderer> From<TreeView<'a, Id, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Id: 'a + Clone + PartialEq,
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tree_view: TreeView<'a, Id, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tree_view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keyboard::KeyCode;
+
+    fn entry(depth: usize, is_expanded: Option<bool>) -> Entry<()> {
+        Entry {
+            id: (),
+            depth,
+            is_expanded,
+        }
+    }
+
+    // 0 root (expanded)
+    // 1   folder (expanded, children still loading)
+    // 2   folder (collapsed)
+    // 3   folder (expanded)
+    // 4     file
+    // 5 file
+    fn entries() -> Vec<Entry<()>> {
+        vec![
+            entry(0, Some(true)),
+            entry(1, Some(true)),
+            entry(1, Some(false)),
+            entry(1, Some(true)),
+            entry(2, None),
+            entry(0, None),
+        ]
+    }
+
+    #[test]
+    fn up_down_home_and_end_move_the_selection() {
+        let entries = entries();
+
+        assert_eq!(
+            navigate(&entries, Some(2), KeyCode::Up),
+            Some(Navigation::Select(1))
+        );
+        assert_eq!(navigate(&entries, Some(0), KeyCode::Up), None);
+        assert_eq!(
+            navigate(&entries, Some(2), KeyCode::Down),
+            Some(Navigation::Select(3))
+        );
+        assert_eq!(navigate(&entries, Some(5), KeyCode::Down), None);
+        assert_eq!(
+            navigate(&entries, None, KeyCode::Down),
+            Some(Navigation::Select(0))
+        );
+        assert_eq!(
+            navigate(&entries, Some(4), KeyCode::Home),
+            Some(Navigation::Select(0))
+        );
+        assert_eq!(
+            navigate(&entries, None, KeyCode::End),
+            Some(Navigation::Select(5))
+        );
+        assert_eq!(navigate::<()>(&[], None, KeyCode::Home), None);
+        assert_eq!(navigate::<()>(&[], None, KeyCode::End), None);
+    }
+
+    #[test]
+    fn right_expands_or_moves_to_the_first_child() {
+        let entries = entries();
+
+        assert_eq!(
+            navigate(&entries, Some(2), KeyCode::Right),
+            Some(Navigation::Toggle(2))
+        );
+        assert_eq!(
+            navigate(&entries, Some(3), KeyCode::Right),
+            Some(Navigation::Select(4))
+        );
+        assert_eq!(navigate(&entries, Some(4), KeyCode::Right), None);
+        assert_eq!(navigate(&entries, None, KeyCode::Right), None);
+    }
+
+    #[test]
+    fn right_stays_on_expanded_nodes_whose_children_are_loading() {
+        let entries = entries();
+
+        // The next row is a sibling, not a child
+        assert_eq!(navigate(&entries, Some(1), KeyCode::Right), None);
+    }
+
+    #[test]
+    fn left_collapses_or_moves_to_the_parent() {
+        let entries = entries();
+
+        assert_eq!(
+            navigate(&entries, Some(3), KeyCode::Left),
+            Some(Navigation::Toggle(3))
+        );
+        assert_eq!(
+            navigate(&entries, Some(4), KeyCode::Left),
+            Some(Navigation::Select(3))
+        );
+        assert_eq!(
+            navigate(&entries, Some(2), KeyCode::Left),
+            Some(Navigation::Select(0))
+        );
+        assert_eq!(navigate(&entries, Some(5), KeyCode::Left), None);
+    }
+}
@@ -0,0 +1,669 @@
+//! Display a very large list of rows, building only the visible ones.
+//!
+//! A [`VirtualList`] has some local [`State`].
+//!
+//! [`VirtualList`]: struct.VirtualList.html
+//! [`State`]: struct.State.html
+use crate::{
+    column, layout, overlay, scrollable, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A vertical list that only creates, lays out and draws the rows around its
+/// viewport.
+///
+/// Rows are built on demand by a closure, which makes it possible to scroll
+/// through hundreds of thousands of them. Use [`with_rows`] to give every row
+/// its own state, so rows can contain stateful widgets like buttons.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{virtual_list, Text};
+/// # type VirtualList<'a, Message> =
+/// #     iced_native::VirtualList<'a, Message, iced_native::renderer::Null>;
+/// #
+/// # #[derive(Debug, Clone)]
+/// # enum Message {}
+/// #
+/// let lines: Vec<String> = (0..100_000).map(|i| format!("Line {}", i)).collect();
+/// let mut state = virtual_list::State::new();
+///
+/// let list: VirtualList<'_, Message> = VirtualList::new(
+///     &mut state,
+///     lines.len(),
+///     virtual_list::RowHeight::Fixed(20),
+///     |i| Text::new(&lines[i]).into(),
+/// );
+/// ```
+///
+/// [`with_rows`]: #method.with_rows
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer: scrollable::Renderer> {
+    state: &'a mut State,
+    count: usize,
+    row_height: RowHeight,
+    first: usize,
+    rows: Vec<Element<'a, Message, Renderer>>,
+    width: Length,
+    height: Length,
+    line_height: u16,
+    is_smooth: bool,
+    style: <Renderer as scrollable::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer>
+where
+    Renderer: scrollable::Renderer,
+{
+    /// Creates a new [`VirtualList`] with the given [`State`], the amount of
+    /// rows, their [`RowHeight`] and a function that builds the row at a
+    /// given index.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    /// [`State`]: struct.State.html
+    /// [`RowHeight`]: enum.RowHeight.html
+    pub fn new<F>(
+        state: &'a mut State,
+        count: usize,
+        row_height: RowHeight,
+        mut view: F,
+    ) -> Self
+    where
+        F: FnMut(usize) -> Element<'a, Message, Renderer>,
+    {
+        let range = state.range_to_build(count, row_height);
+        let rows = range.clone().map(&mut view).collect();
+
+        Self::with_built_rows(state, count, row_height, range.start, rows)
+    }
+
+    /// Creates a new [`VirtualList`] with the given [`State`], the local state
+    /// of every row, their [`RowHeight`] and a function that builds the row
+    /// at a given index with its state.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    /// [`State`]: struct.State.html
+    /// [`RowHeight`]: enum.RowHeight.html
+    pub fn with_rows<S, F>(
+        state: &'a mut State,
+        rows: &'a mut [S],
+        row_height: RowHeight,
+        mut view: F,
+    ) -> Self
+    where
+        F: FnMut(usize, &'a mut S) -> Element<'a, Message, Renderer>,
+    {
+        let count = rows.len();
+        let range = state.range_to_build(count, row_height);
+
+        let rows = range
+            .clone()
+            .zip(&mut rows[range.clone()])
+            .map(|(index, row)| view(index, row))
+            .collect();
+
+        Self::with_built_rows(state, count, row_height, range.start, rows)
+    }
+
+    fn with_built_rows(
+        state: &'a mut State,
+        count: usize,
+        row_height: RowHeight,
+        first: usize,
+        rows: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        VirtualList {
+            state,
+            count,
+            row_height,
+            first,
+            rows,
+            width: Length::Fill,
+            height: Length::Fill,
+            line_height: scrollable::LINE_HEIGHT,
+            is_smooth: false,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the distance, in pixels, that the [`VirtualList`] scrolls for
+    /// every line of a mouse wheel.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn line_height(mut self, line_height: u16) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Enables smooth scrolling in the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn smooth(mut self, is_smooth: bool) -> Self {
+        self.is_smooth = is_smooth;
+        self
+    }
+
+    /// Sets the style of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as scrollable::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn content_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            height: self.state.total_height(self.count, self.row_height),
+            ..bounds
+        }
+    }
+
+    fn cursor_position(
+        &self,
+        bounds: Rectangle,
+        offset: Vector<u32>,
+        cursor_position: Point,
+        is_mouse_over_scrollbar: bool,
+    ) -> Point {
+        if bounds.contains(cursor_position) && !is_mouse_over_scrollbar {
+            Point::new(cursor_position.x, cursor_position.y + offset.y as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualList<'a, Message, Renderer>
+where
+    Renderer: scrollable::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        let row_limits = match self.row_height {
+            RowHeight::Fixed(height) => {
+                let size = Size::new(size.width, f32::from(height));
+
+                layout::Limits::new(size, size)
+            }
+            RowHeight::Estimated(_) => layout::Limits::new(
+                Size::new(size.width, 0.0),
+                Size::new(size.width, f32::INFINITY),
+            ),
+        };
+
+        // Rows are laid out in the coordinates of the whole content
+        let mut y = self.state.total_height(self.first, self.row_height);
+
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut node = row.layout(renderer, &row_limits);
+                node.move_to(Point::new(0.0, y));
+
+                y += node.size().height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let content_bounds = self.content_bounds(bounds);
+
+        let is_mouse_over_scrollbar = self.state.scrollable.update(
+            &event,
+            bounds,
+            content_bounds,
+            cursor_position,
+            scrollable::Direction::Vertical,
+            f32::from(self.line_height),
+            self.is_smooth,
+            renderer,
+        );
+
+        if let RowHeight::Estimated(_) = self.row_height {
+            for (index, row) in (self.first..).zip(layout.children()) {
+                let _ = self.state.heights.insert(index, row.bounds().height);
+            }
+        }
+
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+        let cursor_position = self.cursor_position(
+            bounds,
+            offset,
+            cursor_position,
+            is_mouse_over_scrollbar,
+        );
+
+        for (row, layout) in self.rows.iter_mut().zip(layout.children()) {
+            row.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let content_bounds = self.content_bounds(bounds);
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+        let scrollbars = renderer.scrollbars(
+            scrollable::Direction::Vertical,
+            bounds,
+            content_bounds,
+            offset,
+        );
+
+        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        if self.state.scrollable.is_animating() {
+            renderer.set_redraw_requested(true);
+        }
+
+        // Rows are built before the list is scrolled, so the list needs to be
+        // built again when rows that were not built become visible
+        let visible = self.state.range(
+            offset.y as f32,
+            offset.y as f32 + bounds.height,
+            self.count,
+            self.row_height,
+        );
+
+        let is_missing_rows = !visible.is_empty()
+            && (visible.start < self.first
+                || visible.end > self.first + self.rows.len());
+
+        if is_missing_rows || self.state.viewport.get() != Some(bounds.size()) {
+            self.state.viewport.set(Some(bounds.size()));
+
+            renderer.set_redraw_requested(true);
+        }
+
+        let content = {
+            let cursor_position = self.cursor_position(
+                bounds,
+                offset,
+                cursor_position,
+                is_mouse_over_scrollbar,
+            );
+
+            scrollable::draw_content(renderer, offset, |renderer| {
+                column::Renderer::draw(
+                    renderer,
+                    defaults,
+                    &self.rows,
+                    layout,
+                    cursor_position,
+                )
+            })
+        };
+
+        scrollable::Renderer::draw(
+            renderer,
+            &self.state.scrollable,
+            bounds,
+            content_bounds,
+            is_mouse_over,
+            is_mouse_over_scrollbar,
+            scrollbars,
+            offset,
+            &self.style,
+            content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.row_height.hash(state);
+        self.first.hash(state);
+        self.state
+            .total_height(self.first, self.row_height)
+            .to_bits()
+            .hash(state);
+
+        for row in &self.rows {
+            row.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let content_bounds = self.content_bounds(bounds);
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+
+        self.rows
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(row, layout)| row.overlay(layout))
+            .next()
+            .map(|overlay| {
+                overlay.translate(Vector::new(0.0, -(offset.y as f32)))
+            })
+    }
+}
+
+/// The height of the rows of a [`VirtualList`].
+///
+/// [`VirtualList`]: struct.VirtualList.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowHeight {
+    /// Every row has exactly the given height, in pixels.
+    Fixed(u16),
+
+    /// Rows have different heights, which are measured once they become
+    /// visible. Rows that have not been measured yet are assumed to have the
+    /// given height, in pixels.
+    Estimated(u16),
+}
+
+impl RowHeight {
+    fn estimate(self) -> f32 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Estimated(height) => {
+                f32::from(height)
+            }
+        }
+    }
+}
+
+/// The local state of a [`VirtualList`].
+///
+/// [`VirtualList`]: struct.VirtualList.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    heights: BTreeMap<usize, f32>,
+    viewport: Cell<Option<Size>>,
+}
+
+impl State {
+    /// Creates a new [`State`], scrolled to the top.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Forgets the measured heights of the rows.
+    ///
+    /// This should be called when the rows of an estimated [`RowHeight`]
+    /// list change.
+    ///
+    /// [`RowHeight`]: enum.RowHeight.html
+    pub fn invalidate(&mut self) {
+        self.heights.clear();
+    }
+
+    /// Returns the range of rows to build, given the last known size of the
+    /// viewport.
+    ///
+    /// Rows up to a viewport away are built too, so scrolling does not reveal
+    /// missing rows before the list is built again.
+    fn range_to_build(
+        &self,
+        count: usize,
+        row_height: RowHeight,
+    ) -> Range<usize> {
+        let viewport = match self.viewport.get() {
+            Some(viewport) => viewport,
+            None => return 0..0,
+        };
+
+        let offset = self
+            .scrollable
+            .offset(
+                Rectangle::with_size(viewport),
+                Rectangle::with_size(Size::new(
+                    viewport.width,
+                    self.total_height(count, row_height),
+                )),
+            )
+            .y as f32;
+
+        self.range(
+            offset - viewport.height,
+            offset + viewport.height * 2.0,
+            count,
+            row_height,
+        )
+    }
+
+    /// Returns the range of rows between the given vertical positions.
+    fn range(
+        &self,
+        top: f32,
+        bottom: f32,
+        count: usize,
+        row_height: RowHeight,
+    ) -> Range<usize> {
+        if count == 0 || bottom <= top {
+            return 0..0;
+        }
+
+        let (first, _) = self.locate(top, count, row_height);
+        let (last, _) = self.locate(bottom, count, row_height);
+
+        first..last + 1
+    }
+
+    /// Returns the total height of the first `count` rows, which is also
+    /// where the row at index `count` starts.
+    fn total_height(&self, count: usize, row_height: RowHeight) -> f32 {
+        let estimate = row_height.estimate();
+
+        match row_height {
+            RowHeight::Fixed(_) => count as f32 * estimate,
+            RowHeight::Estimated(_) => self
+                .heights
+                .range(..count)
+                .map(|(_, height)| height - estimate)
+                .fold(count as f32 * estimate, |total, delta| total + delta),
+        }
+    }
+
+    /// Returns the index of the row at the given vertical position, together
+    /// with the position where it starts.
+    fn locate(
+        &self,
+        y: f32,
+        count: usize,
+        row_height: RowHeight,
+    ) -> (usize, f32) {
+        let estimate = row_height.estimate();
+
+        if count == 0 || (estimate <= 0.0 && self.heights.is_empty()) {
+            return (0, 0.0);
+        }
+
+        let skip_estimated = |index: usize, start: f32, until: usize| {
+            if estimate <= 0.0 {
+                return (until, start);
+            }
+
+            let skipped = (((y - start) / estimate).floor().max(0.0) as usize)
+                .min(until - index);
+
+            (index + skipped, start + skipped as f32 * estimate)
+        };
+
+        if let RowHeight::Fixed(_) = row_height {
+            return skip_estimated(0, 0.0, count - 1);
+        }
+
+        let mut index = 0;
+        let mut start = 0.0;
+
+        for (&measured, &height) in self.heights.range(..count) {
+            // Jump over the rows between the last measured one and this one
+            let gap_end = start + (measured - index) as f32 * estimate;
+
+            if y < gap_end {
+                return skip_estimated(index, start, measured);
+            }
+
+            if y < gap_end + height {
+                return (measured, gap_end);
+            }
+
+            index = measured + 1;
+            start = gap_end + height;
+        }
+
+        if index >= count {
+            (count - 1, start - self.heights[&(count - 1)])
+        } else {
+            skip_estimated(index, start, count - 1)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + scrollable::Renderer,
+    Message: 'a,
+{
+    fn from(
+        virtual_list: VirtualList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(virtual_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Text};
+
+    #[test]
+    fn only_rows_around_the_viewport_are_built_with_their_state() {
+        let mut state = State::new();
+        let mut rows = vec![false; 1000];
+
+        let build = |state: &mut State, rows: &mut [bool]| {
+            let list = VirtualList::<(), Null>::with_rows(
+                state,
+                rows,
+                RowHeight::Fixed(20),
+                |index, is_built| {
+                    *is_built = true;
+                    Text::new(index.to_string()).into()
+                },
+            );
+
+            (list.first, list.rows.len())
+        };
+
+        // The size of the viewport is unknown until the list is drawn
+        assert_eq!(build(&mut state, &mut rows), (0, 0));
+
+        state.viewport.set(Some(Size::new(100.0, 100.0)));
+        state.scrollable.scroll_to(
+            Vector::new(0.0, 1000.0),
+            Rectangle::with_size(Size::new(100.0, 100.0)),
+            Rectangle::with_size(Size::new(100.0, 20_000.0)),
+        );
+
+        assert_eq!(build(&mut state, &mut rows), (45, 16));
+
+        let built: Vec<usize> = (0..rows.len()).filter(|&i| rows[i]).collect();
+        assert_eq!(built, (45..61).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn locate_fixed_rows() {
+        let state = State::new();
+
+        assert_eq!(state.locate(0.0, 100, RowHeight::Fixed(20)), (0, 0.0));
+        assert_eq!(state.locate(45.0, 100, RowHeight::Fixed(20)), (2, 40.0));
+        assert_eq!(
+            state.locate(10_000.0, 100, RowHeight::Fixed(20)),
+            (99, 1980.0)
+        );
+    }
+
+    #[test]
+    fn locate_estimated_rows() {
+        let mut state = State::new();
+        let _ = state.heights.insert(1, 50.0);
+        let _ = state.heights.insert(2, 10.0);
+
+        let row_height = RowHeight::Estimated(20);
+
+        assert_eq!(state.total_height(10, row_height), 220.0);
+        assert_eq!(state.locate(15.0, 10, row_height), (0, 0.0));
+        assert_eq!(state.locate(30.0, 10, row_height), (1, 20.0));
+        assert_eq!(state.locate(75.0, 10, row_height), (2, 70.0));
+        assert_eq!(state.locate(100.0, 10, row_height), (4, 100.0));
+    }
+}
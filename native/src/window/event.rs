@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "replay", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A window was resized
     Resized {
@@ -29,4 +30,10 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// The window is about to be redrawn because a widget requested it.
+    ///
+    /// Widgets request redraws while they are animating, and use this event
+    /// to advance their animations.
+    RedrawRequested,
 }
@@ -215,16 +215,7 @@ pub trait Application: Sized {
     {
         #[cfg(not(target_arch = "wasm32"))]
         {
-            let renderer_settings = crate::renderer::Settings {
-                default_font: settings.default_font,
-                default_text_size: settings.default_text_size,
-                antialiasing: if settings.antialiasing {
-                    Some(crate::renderer::settings::Antialiasing::MSAAx4)
-                } else {
-                    None
-                },
-                ..crate::renderer::Settings::default()
-            };
+            let renderer_settings = renderer_settings(&settings);
 
             Ok(crate::runtime::application::run::<
                 Instance<Self>,
@@ -240,6 +231,104 @@ pub trait Application: Sized {
             Ok(())
         }
     }
+
+    /// Runs the [`Application`], recording every message it handles with the
+    /// given [`Writer`].
+    ///
+    /// If `events` is true, the runtime events received by the [`Application`]
+    /// will be recorded as well.
+    ///
+    /// The resulting [`Recording`] can be loaded and fed back to the
+    /// [`Application`] with [`replay`].
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Writer`]: replay/struct.Writer.html
+    /// [`Recording`]: replay/struct.Recording.html
+    /// [`replay`]: #method.replay
+    #[cfg(all(feature = "replay", not(target_arch = "wasm32")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "replay")))]
+    fn record(
+        settings: Settings<Self::Flags>,
+        writer: crate::replay::Writer,
+        events: bool,
+    ) -> crate::Result
+    where
+        Self: 'static,
+        Self::Message: Clone + serde::Serialize,
+    {
+        use iced_winit::replay::{Recorder, RecorderFlags};
+
+        let renderer_settings = renderer_settings(&settings);
+        let settings: iced_winit::Settings<Self::Flags> = settings.into();
+
+        Ok(crate::runtime::application::run::<
+            Recorder<Instance<Self>>,
+            Self::Executor,
+            crate::renderer::window::Compositor,
+        >(
+            iced_winit::Settings {
+                window: settings.window,
+                flags: RecorderFlags {
+                    flags: settings.flags,
+                    writer,
+                    events,
+                },
+            },
+            renderer_settings,
+        )?)
+    }
+
+    /// Runs the [`Application`] with the given [`Recording`], instead of user
+    /// interactions, as its source of messages.
+    ///
+    /// Press `F10` to step forward and `F9` to step back through the
+    /// [`Recording`]. The debug view shows these controls together with the
+    /// current position.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Recording`]: replay/struct.Recording.html
+    #[cfg(all(feature = "replay", not(target_arch = "wasm32")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "replay")))]
+    fn replay(
+        settings: Settings<Self::Flags>,
+        recording: crate::replay::Recording<Self::Message>,
+    ) -> crate::Result
+    where
+        Self: 'static,
+        Self::Flags: Clone,
+        Self::Message: Clone,
+    {
+        let renderer_settings = renderer_settings(&settings);
+        let settings: iced_winit::Settings<Self::Flags> = settings.into();
+
+        Ok(crate::runtime::application::run::<
+            iced_winit::replay::Replay<Instance<Self>>,
+            Self::Executor,
+            crate::renderer::window::Compositor,
+        >(
+            iced_winit::Settings {
+                window: settings.window,
+                flags: (settings.flags, recording),
+            },
+            renderer_settings,
+        )?)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn renderer_settings<Flags>(
+    settings: &Settings<Flags>,
+) -> crate::renderer::Settings {
+    crate::renderer::Settings {
+        default_font: settings.default_font,
+        default_text_size: settings.default_text_size,
+        antialiasing: if settings.antialiasing {
+            Some(crate::renderer::settings::Antialiasing::MSAAx4)
+        } else {
+            None
+        },
+        ..crate::renderer::Settings::default()
+    }
 }
 
 struct Instance<A: Application>(A);
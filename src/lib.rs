@@ -199,6 +199,10 @@ pub mod window;
 #[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
 pub mod time;
 
+#[cfg(all(feature = "replay", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "replay")))]
+pub mod replay;
+
 #[cfg(all(
     not(target_arch = "wasm32"),
     not(feature = "glow"),
@@ -0,0 +1,10 @@
+//! Record application sessions and replay them step by step.
+//!
+//! Use [`Application::record`] to write a session to a file and
+//! [`Application::replay`] to feed it back to a fresh instance of the same
+//! [`Application`].
+//!
+//! [`Application`]: ../trait.Application.html
+//! [`Application::record`]: ../trait.Application.html#method.record
+//! [`Application::replay`]: ../trait.Application.html#method.replay
+pub use iced_winit::replay::{Entry, Record, Recording, Writer};
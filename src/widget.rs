@@ -19,8 +19,11 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, Column, Row, Space, Text,
+        button, checkbox, color_picker, combo_box, container, date_picker,
+        multi_pick_list, number_input, pane_grid, pick_list, progress_bar,
+        radio, range_slider, rich_text, rule, scrollable, selectable_text,
+        slider, table, tabs, text_editor, text_input, time_picker, toggler,
+        tree_view, virtual_list, Column, Row, Space,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -30,10 +33,22 @@ mod platform {
     )]
     pub use crate::renderer::widget::canvas;
 
+    pub mod text {
+        //! Write some text for your users to read.
+        pub use crate::renderer::widget::Text;
+        pub use crate::runtime::text::{Ellipsis, Wrap};
+    }
+
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub mod image {
         //! Display images in your user interface.
-        pub use crate::runtime::image::{Handle, Image};
+        pub use crate::runtime::image::{FilterMethod, Handle, Image};
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub mod image_viewer {
+        //! Inspect an image by zooming and panning it.
+        pub use crate::runtime::image_viewer::{ImageViewer, State};
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
@@ -44,10 +59,17 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
+        button::Button, checkbox::Checkbox, color_picker::ColorPicker,
+        combo_box::ComboBox, container::Container, date_picker::DatePicker,
+        image::Image, image_viewer::ImageViewer,
+        multi_pick_list::MultiPickList, number_input::NumberInput,
         pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput,
+        radio::Radio, range_slider::RangeSlider, rich_text::RichText,
+        rule::Rule, scrollable::Scrollable, selectable_text::SelectableText,
+        slider::Slider, svg::Svg, table::Table, tabs::Tabs, text::Text,
+        text_editor::TextEditor, text_input::TextInput,
+        time_picker::TimePicker, toggler::Toggler, tree_view::TreeView,
+        virtual_list::VirtualList,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -0,0 +1,68 @@
+use iced_core::{Background, Color};
+
+/// The appearance of a color picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub popup_background: Background,
+    pub popup_border_width: u16,
+    pub popup_border_color: Color,
+    pub marker_color: Color,
+    pub checkerboard_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            border_radius: 2,
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+            popup_background: Background::Color(Color::WHITE),
+            popup_border_width: 1,
+            popup_border_color: [0.7, 0.7, 0.7].into(),
+            marker_color: Color::WHITE,
+            checkerboard_color: [0.8, 0.8, 0.8].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a color picker.
+pub trait StyleSheet {
+    /// Produces the style of a color picker.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a color picker when its swatch is hovered.
+    fn hovered(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,84 @@
+use iced_core::{Background, Color};
+
+/// The appearance of a date or time picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub placeholder_color: Color,
+    pub background: Background,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub icon_size: f32,
+    pub popup_background: Background,
+    pub popup_border_width: u16,
+    pub popup_border_color: Color,
+    pub secondary_text_color: Color,
+    pub disabled_text_color: Color,
+    pub hovered_background: Background,
+    pub selected_background: Background,
+    pub selected_text_color: Color,
+    pub focused_border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            placeholder_color: [0.4, 0.4, 0.4].into(),
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: 0,
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+            icon_size: 0.7,
+            popup_background: Background::Color(Color::WHITE),
+            popup_border_width: 1,
+            popup_border_color: [0.7, 0.7, 0.7].into(),
+            secondary_text_color: [0.6, 0.6, 0.6].into(),
+            disabled_text_color: [0.8, 0.8, 0.8].into(),
+            hovered_background: Background::Color([0.92, 0.92, 0.92].into()),
+            selected_background: Background::Color([0.0, 0.47, 0.84].into()),
+            selected_text_color: Color::WHITE,
+            focused_border_color: [0.0, 0.47, 0.84].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a date or time picker.
+pub trait StyleSheet {
+    /// Produces the style of a picker.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a picker when its field is hovered.
+    fn hovered(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -6,12 +6,21 @@ pub use iced_core::{Background, Color};
 
 pub mod button;
 pub mod checkbox;
+pub mod color_picker;
 pub mod container;
+pub mod date_picker;
 pub mod menu;
+pub mod multi_pick_list;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
+pub mod table;
+pub mod tabs;
+pub mod text_editor;
 pub mod text_input;
+pub mod toggler;
+pub mod tree_view;
@@ -0,0 +1,78 @@
+use crate::menu;
+use iced_core::{Background, Color};
+
+/// The appearance of a multi pick list.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub placeholder_color: Color,
+    pub background: Background,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub icon_size: f32,
+    pub chip_background: Background,
+    pub chip_text_color: Color,
+    pub chip_border_radius: u16,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            placeholder_color: [0.4, 0.4, 0.4].into(),
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: 0,
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+            icon_size: 0.7,
+            chip_background: Background::Color(Color::WHITE),
+            chip_text_color: Color::BLACK,
+            chip_border_radius: 2,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a multi pick list.
+pub trait StyleSheet {
+    fn menu(&self) -> menu::Style;
+
+    fn active(&self) -> Style;
+
+    /// Produces the style of a multi pick list when hovered.
+    fn hovered(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn menu(&self) -> menu::Style {
+        menu::Style::default()
+    }
+
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,38 @@
+//! Let your users select and copy some text.
+use iced_core::Color;
+
+/// The appearance of a selectable text.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub selection: Color,
+}
+
+/// A set of rules that dictate the style of a selectable text.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            selection: Color::from_rgb(0.8, 0.8, 1.0),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
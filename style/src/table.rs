@@ -0,0 +1,64 @@
+//! Display data in rows and columns.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub header_background: Background,
+    pub header_text_color: Color,
+    pub hovered_header_background: Background,
+    pub separator_color: Color,
+    pub sort_indicator_color: Color,
+    pub row_background: Option<Background>,
+    pub alternate_row_background: Option<Background>,
+    pub hovered_row_background: Option<Background>,
+    pub selected_row_background: Background,
+    pub selected_text_color: Color,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            header_background: Background::Color([0.92, 0.92, 0.92].into()),
+            header_text_color: Color::BLACK,
+            hovered_header_background: Background::Color(
+                [0.87, 0.87, 0.87].into(),
+            ),
+            separator_color: [0.8, 0.8, 0.8].into(),
+            sort_indicator_color: [0.4, 0.4, 0.4].into(),
+            row_background: None,
+            alternate_row_background: Some(Background::Color(
+                [0.97, 0.97, 0.97].into(),
+            )),
+            hovered_row_background: Some(Background::Color(
+                [0.94, 0.94, 1.0].into(),
+            )),
+            selected_row_background: Background::Color(Color::from_rgb(
+                0.3, 0.5, 0.9,
+            )),
+            selected_text_color: Color::WHITE,
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
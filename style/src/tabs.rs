@@ -0,0 +1,78 @@
+//! Switch between views with a bar of tabs.
+use iced_core::{Background, Color};
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub text_color: Color,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub indicator_color: Option<Color>,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            text_color: [0.4, 0.4, 0.4].into(),
+            border_radius: 0,
+            border_width: 0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            indicator_color: None,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of the tabs of a tab bar.
+pub trait StyleSheet {
+    /// Produces the style of the active tab.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered inactive tab.
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of an inactive tab.
+    fn inactive(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: Some(Background::Color(Color::WHITE)),
+            text_color: Color::BLACK,
+            indicator_color: Some(Color::from_rgb(0.3, 0.5, 0.9)),
+            ..Style::default()
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            background: Some(Background::Color([0.92, 0.92, 0.92].into())),
+            text_color: Color::BLACK,
+            ..self.inactive()
+        }
+    }
+
+    fn inactive(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
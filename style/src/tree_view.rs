@@ -0,0 +1,59 @@
+//! Display hierarchical data with expandable nodes.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub toggle_color: Color,
+    pub guide_color: Color,
+    pub hovered_background: Option<Background>,
+    pub selected_background: Background,
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// Produces the style of a tree view.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a tree view with keyboard focus.
+    fn focused(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            toggle_color: [0.4, 0.4, 0.4].into(),
+            guide_color: [0.85, 0.85, 0.85].into(),
+            hovered_background: Some(Background::Color(
+                [0.94, 0.94, 0.94].into(),
+            )),
+            selected_background: Background::Color([0.87, 0.87, 0.87].into()),
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            selected_background: Background::Color(Color::from_rgb(
+                0.8, 0.85, 1.0,
+            )),
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
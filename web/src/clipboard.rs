@@ -0,0 +1,40 @@
+//! Access the clipboard of the browser.
+use wasm_bindgen::JsCast;
+
+/// Writes the given contents to the clipboard of the browser.
+///
+/// The contents are copied through a hidden `<textarea>`, as the
+/// asynchronous clipboard API is not available everywhere yet.
+pub fn write(document: &web_sys::Document, contents: &str) {
+    let result = (|| {
+        let body = document.body()?;
+        let textarea = document
+            .create_element("textarea")
+            .ok()?
+            .dyn_into::<web_sys::HtmlTextAreaElement>()
+            .ok()?;
+
+        textarea.set_value(contents);
+        textarea
+            .set_attribute(
+                "style",
+                "position: fixed; top: 0; left: 0; opacity: 0;",
+            )
+            .ok()?;
+
+        let _ = body.append_child(&textarea).ok()?;
+        textarea.select();
+
+        let copied = document
+            .dyn_ref::<web_sys::HtmlDocument>()
+            .and_then(|document| document.exec_command("copy").ok());
+
+        let _ = body.remove_child(&textarea).ok()?;
+
+        copied
+    })();
+
+    if result != Some(true) {
+        web_sys::console::warn_1(&"Failed to write to clipboard".into());
+    }
+}
@@ -0,0 +1,171 @@
+//! Run asynchronous work and ask the browser to perform actions.
+use iced_futures::futures::Future;
+
+/// A collection of async operations, along with the actions that the browser
+/// should perform on behalf of the application.
+///
+/// You should be able to turn a future easily into a [`Command`], either by
+/// using the `From` trait or [`Command::perform`].
+///
+/// [`Command`]: struct.Command.html
+/// [`Command::perform`]: #method.perform
+pub struct Command<T> {
+    futures: iced_futures::Command<T>,
+    actions: Vec<Action>,
+}
+
+/// An action that a [`Command`] asks the browser to perform.
+///
+/// [`Command`]: struct.Command.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Write the given text contents to the clipboard.
+    WriteClipboard(String),
+}
+
+impl<T> Command<T> {
+    /// Creates an empty [`Command`].
+    ///
+    /// In other words, a [`Command`] that does nothing.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn none() -> Self {
+        Self {
+            futures: iced_futures::Command::none(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that writes the given contents to the clipboard.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn write_clipboard(contents: impl Into<String>) -> Self {
+        Self {
+            futures: iced_futures::Command::none(),
+            actions: vec![Action::WriteClipboard(contents.into())],
+        }
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command {
+            futures: iced_futures::Command::perform(future, f),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(target_arch = "wasm32")]
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command {
+            futures: iced_futures::Command::perform(future, f),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: 'static,
+    {
+        Command {
+            futures: self.futures.map(f),
+            actions: self.actions,
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(target_arch = "wasm32")]
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static) -> Command<A>
+    where
+        T: 'static,
+    {
+        Command {
+            futures: self.futures.map(f),
+            actions: self.actions,
+        }
+    }
+
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands.
+    ///
+    /// Once this command is run, all the commands will be executed at once.
+    /// Their browser actions are performed in the given order.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        let mut futures = Vec::new();
+        let mut actions = Vec::new();
+
+        for command in commands {
+            futures.push(command.futures);
+            actions.extend(command.actions);
+        }
+
+        Self {
+            futures: iced_futures::Command::batch(futures),
+            actions,
+        }
+    }
+
+    /// Splits a [`Command`] into the futures to run in the runtime and the
+    /// actions that the browser should perform.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn split(self) -> (iced_futures::Command<T>, Vec<Action>) {
+        (self.futures, self.actions)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T, A> From<A> for Command<T>
+where
+    A: Future<Output = T> + 'static + Send,
+{
+    fn from(future: A) -> Self {
+        Self {
+            futures: iced_futures::Command::from(future),
+            actions: Vec::new(),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<T, A> From<A> for Command<T>
+where
+    A: Future<Output = T> + 'static,
+{
+    fn from(future: A) -> Self {
+        Self {
+            futures: iced_futures::Command::from(future),
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Command<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Command")
+            .field("actions", &self.actions)
+            .finish()
+    }
+}
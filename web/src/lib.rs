@@ -61,14 +61,17 @@ use dodrio::bumpalo;
 use std::{cell::RefCell, rc::Rc};
 
 mod bus;
+mod clipboard;
 mod element;
 mod hasher;
 
+pub mod command;
 pub mod css;
 pub mod subscription;
 pub mod widget;
 
 pub use bus::Bus;
+pub use command::Command;
 pub use css::Css;
 pub use dodrio;
 pub use element::Element;
@@ -77,7 +80,7 @@ pub use iced_core::{
     keyboard, mouse, Align, Background, Color, Font, HorizontalAlignment,
     Length, Point, Rectangle, Size, Vector, VerticalAlignment,
 };
-pub use iced_futures::{executor, futures, Command};
+pub use iced_futures::{executor, futures};
 pub use subscription::Subscription;
 
 #[doc(no_inline)]
@@ -190,10 +193,12 @@ pub trait Application {
         );
 
         let (app, command) = runtime.enter(|| Self::new(flags));
+        let (command, actions) = command.split();
 
         let mut title = app.title();
         document.set_title(&title);
 
+        perform_actions(&document, actions);
         runtime.spawn(command);
 
         let application = Rc::new(RefCell::new(app));
@@ -215,6 +220,9 @@ pub trait Application {
 
             let new_title = application.borrow().title();
 
+            let (command, actions) = command.split();
+
+            perform_actions(&document, actions);
             runtime.spawn(command);
             runtime.track(subscription);
 
@@ -260,3 +268,16 @@ where
             .finish()
     }
 }
+
+fn perform_actions(
+    document: &web_sys::Document,
+    actions: Vec<command::Action>,
+) {
+    for action in actions {
+        match action {
+            command::Action::WriteClipboard(contents) => {
+                clipboard::write(document, &contents);
+            }
+        }
+    }
+}
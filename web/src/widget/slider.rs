@@ -11,15 +11,16 @@ pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 use dodrio::bumpalo;
 use std::{ops::RangeInclusive, rc::Rc};
 
-/// An horizontal bar and a handle that selects a single value from a range of
-/// values.
+/// A bar and a handle that selects a single value from a range of values.
 ///
-/// A [`Slider`] will try to fill the horizontal space of its container.
+/// A [`Slider`] is horizontal by default and will try to fill the space of its
+/// container along its [`Orientation`].
 ///
 /// The [`Slider`] range of numeric values is generic and its step size defaults
 /// to 1 unit.
 ///
 /// [`Slider`]: struct.Slider.html
+/// [`Orientation`]: enum.Orientation.html
 ///
 /// # Example
 /// ```
@@ -43,6 +44,7 @@ pub struct Slider<'a, T, Message> {
     step: T,
     value: T,
     on_change: Rc<Box<dyn Fn(T) -> Message>>,
+    orientation: Orientation,
     width: Length,
     style: Box<dyn StyleSheet>,
 }
@@ -90,11 +92,21 @@ where
             range,
             step: T::from(1),
             on_change: Rc::new(Box::new(on_change)),
+            orientation: Orientation::Horizontal,
             width: Length::Fill,
             style: Default::default(),
         }
     }
 
+    /// Sets the [`Orientation`] of the [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    /// [`Orientation`]: enum.Orientation.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -151,7 +163,16 @@ where
             .attr("min", min.into_bump_str())
             .attr("max", max.into_bump_str())
             .attr("value", value.into_bump_str())
-            .attr("style", "width: 100%")
+            .attr(
+                "style",
+                match self.orientation {
+                    Orientation::Horizontal => "width: 100%",
+                    Orientation::Vertical => {
+                        "height: 100%; writing-mode: vertical-lr; \
+                         direction: rtl"
+                    }
+                },
+            )
             .on("input", move |_root, _vdom, event| {
                 let slider = match event.target().and_then(|t| {
                     t.dyn_into::<web_sys::HtmlInputElement>().ok()
@@ -180,6 +201,18 @@ where
     }
 }
 
+/// The direction along which the rail of a [`Slider`] lies.
+///
+/// [`Slider`]: struct.Slider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// A rail from left to right.
+    Horizontal,
+
+    /// A rail from bottom to top.
+    Vertical,
+}
+
 /// The local state of a [`Slider`].
 ///
 /// [`Slider`]: struct.Slider.html
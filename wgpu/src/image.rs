@@ -10,6 +10,7 @@ use crate::Transformation;
 use atlas::Atlas;
 
 use iced_graphics::layer;
+use iced_native::image::FilterMethod;
 use iced_native::Rectangle;
 use std::cell::RefCell;
 use std::mem;
@@ -33,7 +34,8 @@ pub struct Pipeline {
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    linear_constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
@@ -44,15 +46,20 @@ impl Pipeline {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
         use wgpu::util::DeviceExt;
 
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            ..Default::default()
-        });
+        let create_sampler = |filter: wgpu::FilterMode| {
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter,
+                min_filter: filter,
+                mipmap_filter: filter,
+                ..Default::default()
+            })
+        };
+
+        let linear_sampler = create_sampler(wgpu::FilterMode::Linear);
+        let nearest_sampler = create_sampler(wgpu::FilterMode::Nearest);
 
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -85,7 +92,7 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let constant_bind_group =
+        let create_constant_bind_group = |sampler: &wgpu::Sampler| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("iced_wgpu::image constants bind group"),
                 layout: &constant_layout,
@@ -98,10 +105,14 @@ impl Pipeline {
                     },
                     wgpu::BindGroupEntry {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let linear_constants = create_constant_bind_group(&linear_sampler);
+        let nearest_constants = create_constant_bind_group(&nearest_sampler);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -262,7 +273,8 @@ impl Pipeline {
             vertices,
             indices,
             instances,
-            constants: constant_bind_group,
+            linear_constants,
+            nearest_constants,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
@@ -297,7 +309,9 @@ impl Pipeline {
         target: &wgpu::TextureView,
         _scale: f32,
     ) {
-        let instances: &mut Vec<Instance> = &mut Vec::new();
+        // Consecutive instances sampled with the same filtering method are
+        // batched together, keeping the images in drawing order
+        let mut batches: Vec<(FilterMethod, Vec<Instance>)> = Vec::new();
 
         #[cfg(feature = "image")]
         let mut raster_cache = self.raster_cache.borrow_mut();
@@ -308,7 +322,11 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    bounds,
+                    filter_method,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
                         device,
@@ -319,7 +337,7 @@ impl Pipeline {
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
                             atlas_entry,
-                            instances,
+                            batch(&mut batches, *filter_method),
                         );
                     }
                 }
@@ -342,7 +360,7 @@ impl Pipeline {
                             [bounds.x, bounds.y],
                             size,
                             atlas_entry,
-                            instances,
+                            batch(&mut batches, FilterMethod::Linear),
                         );
                     }
                 }
@@ -351,7 +369,7 @@ impl Pipeline {
             }
         }
 
-        if instances.is_empty() {
+        if batches.is_empty() {
             return;
         }
 
@@ -393,6 +411,34 @@ impl Pipeline {
             );
         }
 
+        for (filter_method, instances) in &batches {
+            let constants = match filter_method {
+                FilterMethod::Linear => &self.linear_constants,
+                FilterMethod::Nearest => &self.nearest_constants,
+            };
+
+            self.render(
+                device,
+                staging_belt,
+                encoder,
+                instances,
+                constants,
+                bounds,
+                target,
+            );
+        }
+    }
+
+    fn render(
+        &self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        instances: &[Instance],
+        constants: &wgpu::BindGroup,
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
         let mut i = 0;
         let total = instances.len();
 
@@ -430,7 +476,7 @@ impl Pipeline {
                 });
 
             render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass.set_bind_group(0, constants, &[]);
             render_pass.set_bind_group(1, &self.texture, &[]);
             render_pass.set_index_buffer(self.indices.slice(..));
             render_pass.set_vertex_buffer(0, self.vertices.slice(..));
@@ -569,3 +615,17 @@ fn add_instance(
 
     instances.push(instance);
 }
+
+/// Returns the instances of the last batch, starting a new one if it is
+/// sampled with a different filtering method.
+fn batch(
+    batches: &mut Vec<(FilterMethod, Vec<Instance>)>,
+    filter_method: FilterMethod,
+) -> &mut Vec<Instance> {
+    match batches.last() {
+        Some((last, _)) if *last == filter_method => {}
+        _ => batches.push((filter_method, Vec::new())),
+    }
+
+    &mut batches.last_mut().unwrap().1
+}
@@ -115,6 +115,26 @@ impl Pipeline {
         }
     }
 
+    pub fn character_bounds(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> Vec<(usize, iced_native::Rectangle)> {
+        // Fonts must be found before borrowing the brush, as they may need
+        // to be loaded into it
+        let font_id = self.find_font(font);
+
+        iced_graphics::text::character_bounds(
+            &mut self.measure_brush.borrow_mut(),
+            font_id,
+            content,
+            size,
+            bounds,
+        )
+    }
+
     pub fn trim_measurement_cache(&mut self) {
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
@@ -11,23 +11,48 @@ use crate::Renderer;
 
 pub mod button;
 pub mod checkbox;
+pub mod color_picker;
+pub mod combo_box;
 pub mod container;
+pub mod date_picker;
+pub mod multi_pick_list;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
+pub mod table;
+pub mod tabs;
+pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
+pub mod toggler;
+pub mod tree_view;
+pub mod virtual_list;
 
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
+pub use combo_box::ComboBox;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use multi_pick_list::MultiPickList;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -36,13 +61,33 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
+pub use toggler::Toggler;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
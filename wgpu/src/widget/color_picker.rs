@@ -0,0 +1,6 @@
+//! Pick a color with a saturation and value area, sliders and text inputs.
+pub use iced_graphics::color_picker::{State, Style, StyleSheet};
+
+/// A swatch that opens an overlay to pick a color.
+pub type ColorPicker<'a, Message> =
+    iced_native::ColorPicker<'a, Message, crate::Renderer>;
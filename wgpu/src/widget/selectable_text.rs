@@ -0,0 +1,15 @@
+//! Let your users select and copy some text.
+//!
+//! A [`SelectableText`] has some local [`State`].
+//!
+//! [`SelectableText`]: type.SelectableText.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::selectable_text::{State, Style, StyleSheet};
+
+/// A paragraph of text that can be selected and copied.
+///
+/// This is an alias of an `iced_native` selectable text with an
+/// `iced_wgpu::Renderer`.
+pub type SelectableText<'a> = iced_native::SelectableText<'a, Renderer>;
@@ -0,0 +1,11 @@
+//! Display data in rows and columns, with sortable and resizable columns.
+use crate::Renderer;
+
+pub use iced_graphics::table::{
+    Column, ResizeEvent, Selection, SortOrder, State, Style, StyleSheet,
+};
+
+/// A grid of rows under a header of titled columns.
+///
+/// This is an alias of an `iced_native` table with a default `Renderer`.
+pub type Table<'a, Message> = iced_native::Table<'a, Message, Renderer>;
@@ -0,0 +1,10 @@
+//! Switch between views with a bar of tabs.
+use crate::Renderer;
+
+pub use iced_graphics::tabs::{ReorderEvent, State, Style, StyleSheet, Tab};
+
+/// A bar of tabs above the content of the active one.
+///
+/// This is an alias of an `iced_native` tabs widget with a default
+/// `Renderer`.
+pub type Tabs<'a, Message> = iced_native::Tabs<'a, Message, Renderer>;
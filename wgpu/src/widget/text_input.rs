@@ -7,7 +7,7 @@
 use crate::Renderer;
 
 pub use iced_graphics::text_input::{Style, StyleSheet};
-pub use iced_native::text_input::State;
+pub use iced_native::text_input::{Mask, State};
 
 /// A field that can be filled with text.
 ///
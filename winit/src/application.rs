@@ -2,8 +2,8 @@
 use crate::conversion;
 use crate::mouse;
 use crate::{
-    Clipboard, Color, Command, Debug, Error, Executor, Mode, Proxy, Runtime,
-    Settings, Size, Subscription,
+    command, Clipboard, Color, Command, Debug, Error, Executor, Mode, Proxy,
+    Runtime, Settings, Size, Subscription,
 };
 use iced_graphics::window;
 use iced_graphics::Viewport;
@@ -101,6 +101,18 @@ pub trait Application: Program {
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    /// Returns the amount of messages replayed so far and the total amount of
+    /// messages, if the [`Application`] is replaying a recorded session.
+    ///
+    /// The runtime shows them in the debug view.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn replay_position(&self) -> Option<(usize, usize)> {
+        None
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -134,6 +146,7 @@ where
 
     let flags = settings.flags;
     let (application, init_command) = runtime.enter(|| A::new(flags));
+    let (init_command, init_actions) = init_command.split();
     runtime.spawn(init_command);
 
     let subscription = application.subscription();
@@ -151,9 +164,13 @@ where
         .map_err(Error::WindowCreationFailed)?;
 
     let clipboard = Clipboard::new(&window);
+
+    perform_actions(init_actions, clipboard.as_ref());
+
     // TODO: Encode cursor availability in the type-system
     let mut cursor_position = winit::dpi::PhysicalPosition::new(-1.0, -1.0);
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut ime_caret = None;
     let mut modifiers = winit::event::ModifiersState::default();
 
     let physical_size = window.inner_size();
@@ -173,6 +190,11 @@ where
         physical_size.height,
     );
 
+    iced_native::Renderer::set_scale_factor(
+        &mut renderer,
+        viewport.scale_factor(),
+    );
+
     let mut state = program::State::new(
         application,
         viewport.logical_size(),
@@ -180,6 +202,10 @@ where
         &mut renderer,
         &mut debug,
     );
+    if let Some((position, total)) = state.program().replay_position() {
+        debug.replay_stepped(position, total);
+    }
+
     debug.startup_finished();
 
     event_loop.run(move |event, _, control_flow| match event {
@@ -188,6 +214,11 @@ where
                 return;
             }
 
+            iced_native::Renderer::set_scale_factor(
+                &mut renderer,
+                viewport.scale_factor(),
+            );
+
             let command = runtime.enter(|| {
                 state.update(
                     viewport.logical_size(),
@@ -203,6 +234,9 @@ where
 
             // If the application was updated
             if let Some(command) = command {
+                let (command, actions) = command.split();
+
+                perform_actions(actions, clipboard.as_ref());
                 runtime.spawn(command);
 
                 let program = state.program();
@@ -211,6 +245,11 @@ where
                 let subscription = program.subscription();
                 runtime.track(subscription);
 
+                // Update replay position
+                if let Some((position, total)) = program.replay_position() {
+                    debug.replay_stepped(position, total);
+                }
+
                 // Update window title
                 let new_title = program.title();
 
@@ -246,6 +285,11 @@ where
                         window.scale_factor() * new_scale_factor,
                     );
 
+                    iced_native::Renderer::set_scale_factor(
+                        &mut renderer,
+                        viewport.scale_factor(),
+                    );
+
                     // We relayout the UI with the new logical size.
                     // The queue is empty, therefore this will never produce
                     // a `Command`.
@@ -305,8 +349,25 @@ where
                 mouse_interaction = new_mouse_interaction;
             }
 
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
+            let new_ime_caret = iced_native::Renderer::ime_caret(&renderer);
+
+            if new_ime_caret != ime_caret {
+                if let Some(caret) = new_ime_caret {
+                    window.set_ime_position(conversion::ime_position(
+                        caret,
+                        viewport.scale_factor(),
+                    ));
+                }
+
+                ime_caret = new_ime_caret;
+            }
+
+            // Animated widgets keep requesting redraws until they settle
+            if iced_native::Renderer::is_redraw_requested(&renderer) {
+                state.queue_event(iced_native::Event::Window(
+                    iced_native::window::Event::RedrawRequested,
+                ));
+            }
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -334,11 +395,33 @@ where
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = if state.is_queue_empty() {
+                ControlFlow::Wait
+            } else {
+                ControlFlow::Poll
+            };
         }
     })
 }
 
+/// Performs the given [`command::Action`]s of an application.
+///
+/// [`command::Action`]: ../command/enum.Action.html
+pub fn perform_actions(
+    actions: Vec<command::Action>,
+    clipboard: Option<&Clipboard>,
+) {
+    for action in actions {
+        match action {
+            command::Action::WriteClipboard(contents) => {
+                if let Some(clipboard) = clipboard {
+                    let _ = clipboard.write(contents);
+                }
+            }
+        }
+    }
+}
+
 /// Handles a `WindowEvent` and mutates the provided control flow, keyboard
 /// modifiers, viewport, and resized flag accordingly.
 pub fn handle_window_event(
@@ -1,19 +1,44 @@
+use std::cell::RefCell;
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 #[allow(missing_debug_implementations)]
-pub struct Clipboard(window_clipboard::Clipboard);
+pub struct Clipboard(RefCell<window_clipboard::Clipboard>);
 
 impl Clipboard {
     /// Creates a new [`Clipboard`] for the given window.
     ///
     /// [`Clipboard`]: struct.Clipboard.html
     pub fn new(window: &winit::window::Window) -> Option<Clipboard> {
-        window_clipboard::Clipboard::new(window).map(Clipboard).ok()
+        window_clipboard::Clipboard::connect(window)
+            .map(RefCell::new)
+            .map(Clipboard)
+            .ok()
+    }
+
+    /// Writes the given text contents to the [`Clipboard`].
+    ///
+    /// Returns whether the contents were written successfully.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn write(&self, contents: String) -> bool {
+        match self.0.borrow_mut().write(contents) {
+            Ok(()) => true,
+            Err(error) => {
+                log::warn!("Failed to write to clipboard: {}", error);
+
+                false
+            }
+        }
     }
 }
 
 impl iced_native::Clipboard for Clipboard {
     fn content(&self) -> Option<String> {
-        self.0.read().ok()
+        self.0.borrow().read().ok()
+    }
+
+    fn write(&self, contents: String) -> bool {
+        Clipboard::write(self, contents)
     }
 }
@@ -4,7 +4,7 @@
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
 use crate::{
     keyboard::{self, KeyCode, ModifiersState},
-    mouse, window, Event, Mode, Point,
+    mouse, window, Event, Mode, Point, Rectangle,
 };
 
 /// Converts a winit window event into an iced event.
@@ -76,6 +76,9 @@ pub fn window_event(
                 }))
             }
         },
+        // `winit` 0.23 does not report IME compositions, so no
+        // `ImePreedit` or `ImeCommit` events are produced here. Composed text
+        // is received character by character once committed.
         WindowEvent::ReceivedCharacter(c) if !is_private_use_character(*c) => {
             Some(Event::Keyboard(keyboard::Event::CharacterReceived(*c)))
         }
@@ -201,6 +204,19 @@ pub fn cursor_position(
     Point::new(logical_position.x, logical_position.y)
 }
 
+/// Converts the logical bounds of a text caret to the physical position where
+/// the candidate window of an input method editor (IME) should be placed.
+pub fn ime_position(
+    caret: Rectangle,
+    scale_factor: f64,
+) -> winit::dpi::PhysicalPosition<f64> {
+    winit::dpi::LogicalPosition::new(
+        f64::from(caret.x),
+        f64::from(caret.y + caret.height),
+    )
+    .to_physical(scale_factor)
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced_native`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -29,6 +29,9 @@ pub mod application;
 pub mod conversion;
 pub mod settings;
 
+#[cfg(feature = "replay")]
+pub mod replay;
+
 mod clipboard;
 mod error;
 mod mode;
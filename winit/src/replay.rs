@@ -0,0 +1,369 @@
+//! Record application sessions and replay them step by step.
+//!
+//! A [`Recorder`] wraps an [`Application`] and writes every message it handles
+//! to a file. Later, a [`Replay`] can rebuild the same [`Application`] with the
+//! same flags and feed the recorded messages back to it, one at a time.
+//!
+//! While replaying, press `F10` to step forward and `F9` to step back. The
+//! window title shows the current position. When the `debug` feature is
+//! enabled, the debug view lists these controls as well.
+//!
+//! [`Recorder`]: struct.Recorder.html
+//! [`Replay`]: struct.Replay.html
+//! [`Application`]: ../trait.Application.html
+use crate::keyboard;
+use crate::{
+    Application, Color, Command, Element, Event, Mode, Program, Subscription,
+};
+
+pub use iced_native::replay::{Entry, Record, Recording, Writer};
+
+use serde::Serialize;
+
+/// A message of a [`Recorder`] or a [`Replay`].
+///
+/// [`Recorder`]: struct.Recorder.html
+/// [`Replay`]: struct.Replay.html
+#[derive(Debug, Clone)]
+pub enum Message<T> {
+    /// A message produced by the wrapped [`Application`].
+    ///
+    /// [`Application`]: ../trait.Application.html
+    Application(T),
+
+    /// A runtime event.
+    Event(Event),
+}
+
+/// The flags of a [`Recorder`].
+///
+/// [`Recorder`]: struct.Recorder.html
+#[derive(Debug)]
+pub struct RecorderFlags<Flags> {
+    /// The flags of the wrapped [`Application`].
+    ///
+    /// [`Application`]: ../trait.Application.html
+    pub flags: Flags,
+
+    /// The [`Writer`] where the session will be recorded.
+    ///
+    /// [`Writer`]: struct.Writer.html
+    pub writer: Writer,
+
+    /// Whether raw runtime events should be recorded as well.
+    pub events: bool,
+}
+
+/// An [`Application`] that records every message handled by another one.
+///
+/// [`Application`]: ../trait.Application.html
+#[allow(missing_debug_implementations)]
+pub struct Recorder<A> {
+    application: A,
+    writer: Writer,
+    events: bool,
+}
+
+impl<A> Recorder<A>
+where
+    A: Application,
+    A::Message: Serialize,
+{
+    fn record(&mut self, record: &Record<A::Message>) {
+        if let Err(error) = self.writer.write(record) {
+            log::warn!("Failed to record message: {}", error);
+        }
+    }
+}
+
+impl<A> Program for Recorder<A>
+where
+    A: Application,
+    A::Message: Clone + Serialize + 'static,
+{
+    type Renderer = A::Renderer;
+    type Message = Message<A::Message>;
+
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        match message {
+            Message::Application(message) => {
+                self.record(&Record::Message(message.clone()));
+
+                self.application.update(message).map(Message::Application)
+            }
+            Message::Event(event) => {
+                self.record(&Record::Event(event));
+
+                Command::none()
+            }
+        }
+    }
+
+    fn view(&mut self) -> Element<'_, Self::Message, Self::Renderer> {
+        self.application.view().map(Message::Application)
+    }
+}
+
+impl<A> Application for Recorder<A>
+where
+    A: Application,
+    A::Message: Clone + Serialize + 'static,
+{
+    type Flags = RecorderFlags<A::Flags>;
+
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
+        let (application, command) = A::new(flags.flags);
+
+        (
+            Recorder {
+                application,
+                writer: flags.writer,
+                events: flags.events,
+            },
+            command.map(Message::Application),
+        )
+    }
+
+    fn title(&self) -> String {
+        format!("{} [recording]", self.application.title())
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        let subscription =
+            self.application.subscription().map(Message::Application);
+
+        if self.events {
+            Subscription::batch(vec![
+                subscription,
+                iced_native::subscription::events().map(Message::Event),
+            ])
+        } else {
+            subscription
+        }
+    }
+
+    fn mode(&self) -> Mode {
+        self.application.mode()
+    }
+
+    fn background_color(&self) -> Color {
+        self.application.background_color()
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.application.scale_factor()
+    }
+}
+
+/// An [`Application`] that replays a [`Recording`] of another one.
+///
+/// Only the recorded messages are fed to the wrapped [`Application`]. Any
+/// [`Command`] or [`Subscription`] it returns is ignored, as its results were
+/// already recorded.
+///
+/// [`Application`]: ../trait.Application.html
+/// [`Recording`]: struct.Recording.html
+/// [`Command`]: ../struct.Command.html
+/// [`Subscription`]: ../type.Subscription.html
+#[allow(missing_debug_implementations)]
+pub struct Replay<A: Application> {
+    application: A,
+    flags: A::Flags,
+    messages: Vec<A::Message>,
+    position: usize,
+}
+
+impl<A> Replay<A>
+where
+    A: Application,
+    A::Flags: Clone,
+    A::Message: Clone,
+{
+    fn step_forward(&mut self) {
+        if let Some(message) = self.messages.get(self.position) {
+            let _ = self.application.update(message.clone());
+
+            self.position += 1;
+        }
+    }
+
+    fn step_back(&mut self) {
+        if self.position == 0 {
+            return;
+        }
+
+        // Messages cannot be undone, so we rebuild the application from
+        // scratch and replay everything up to the previous message.
+        let (application, _) = A::new(self.flags.clone());
+        let target = self.position - 1;
+
+        self.application = application;
+        self.position = 0;
+
+        while self.position < target {
+            self.step_forward();
+        }
+    }
+}
+
+impl<A> Program for Replay<A>
+where
+    A: Application,
+    A::Flags: Clone,
+    A::Message: Clone + 'static,
+{
+    type Renderer = A::Renderer;
+    type Message = Message<A::Message>;
+
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        if let Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            ..
+        })) = message
+        {
+            match key_code {
+                keyboard::KeyCode::F10 => self.step_forward(),
+                keyboard::KeyCode::F9 => self.step_back(),
+                _ => {}
+            }
+        }
+
+        Command::none()
+    }
+
+    fn view(&mut self) -> Element<'_, Self::Message, Self::Renderer> {
+        self.application.view().map(Message::Application)
+    }
+}
+
+impl<A> Application for Replay<A>
+where
+    A: Application,
+    A::Flags: Clone,
+    A::Message: Clone + 'static,
+{
+    type Flags = (A::Flags, Recording<A::Message>);
+
+    fn new((flags, recording): Self::Flags) -> (Self, Command<Self::Message>) {
+        let (application, _) = A::new(flags.clone());
+
+        (
+            Replay {
+                application,
+                flags,
+                messages: recording.messages().cloned().collect(),
+                position: 0,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "{} [replay {}/{}]",
+            self.application.title(),
+            self.position,
+            self.messages.len()
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        iced_native::subscription::events().map(Message::Event)
+    }
+
+    fn mode(&self) -> Mode {
+        self.application.mode()
+    }
+
+    fn background_color(&self) -> Color {
+        self.application.background_color()
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.application.scale_factor()
+    }
+
+    fn replay_position(&self) -> Option<(usize, usize)> {
+        Some((self.position, self.messages.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{renderer::Null, Column};
+
+    struct Counter(u32);
+
+    impl Program for Counter {
+        type Renderer = Null;
+        type Message = u32;
+
+        fn update(&mut self, amount: u32) -> Command<u32> {
+            self.0 += amount;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, u32, Null> {
+            Column::new().into()
+        }
+    }
+
+    impl Application for Counter {
+        type Flags = u32;
+
+        fn new(start: u32) -> (Self, Command<u32>) {
+            (Counter(start), Command::none())
+        }
+
+        fn title(&self) -> String {
+            String::from("Counter")
+        }
+    }
+
+    #[test]
+    fn recordings_round_trip_and_replay() {
+        let path = std::env::temp_dir()
+            .join(format!("iced_replay_{}.jsonl", std::process::id()));
+
+        let mut writer = Writer::create(&path).unwrap();
+
+        for amount in &[1, 2, 3] {
+            writer.write(&Record::Message(*amount)).unwrap();
+        }
+
+        writer
+            .write::<u32>(&Record::Event(Event::Keyboard(
+                keyboard::Event::CharacterReceived('a'),
+            )))
+            .unwrap();
+
+        let recording = Recording::<u32>::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(recording.entries().len(), 4);
+        assert_eq!(
+            recording.messages().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let (mut replay, _) = Replay::<Counter>::new((10, recording));
+        assert_eq!(replay.replay_position(), Some((0, 3)));
+
+        replay.step_forward();
+        replay.step_forward();
+        assert_eq!(replay.application.0, 13);
+
+        replay.step_back();
+        assert_eq!(replay.application.0, 11);
+        assert_eq!(replay.replay_position(), Some((1, 3)));
+
+        for _ in 0..5 {
+            replay.step_forward();
+        }
+
+        assert_eq!(replay.application.0, 16);
+        assert_eq!(replay.replay_position(), Some((3, 3)));
+    }
+}